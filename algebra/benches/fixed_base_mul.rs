@@ -0,0 +1,43 @@
+#[macro_use]
+extern crate criterion;
+
+use algebra::{
+    curves::mnt4753::G1Projective,
+    fields::mnt4753::Fr,
+    msm::WindowTable,
+    AffineCurve, ProjectiveCurve, UniformRand,
+};
+use criterion::Criterion;
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+const SAMPLES: usize = 1 << 8;
+
+// Repeated multiplications of a fixed base: plain double-and-add against
+// lookups in a precomputed `WindowTable`, for a few window sizes.
+fn bench_fixed_base_mul(c: &mut Criterion) {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let base = G1Projective::rand(&mut rng).into_affine();
+    let scalars = (0..SAMPLES).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+
+    let v = scalars.clone();
+    c.bench_function("MNT4-753 G1 mul", move |b| {
+        b.iter(|| v.iter().map(|s| base.mul(*s)).collect::<Vec<_>>())
+    });
+
+    for &window in [4usize, 8, 12].iter() {
+        let table = WindowTable::<G1Projective>::new(&base, window);
+        let v = scalars.clone();
+        c.bench_function(&format!("MNT4-753 G1 WindowTable mul, window {}", window), move |b| {
+            b.iter(|| v.iter().map(|s| table.mul(s)).collect::<Vec<_>>())
+        });
+    }
+}
+
+criterion_group! {
+    name = fixed_base_mul;
+    config = Criterion::default().sample_size(10);
+    targets = bench_fixed_base_mul
+}
+
+criterion_main!(fixed_base_mul);
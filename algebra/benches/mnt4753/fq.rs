@@ -272,6 +272,17 @@ fn bench_fq_into_repr(b: &mut ::test::Bencher) {
     });
 }
 
+#[bench]
+fn bench_fq_batch_into_repr(b: &mut ::test::Bencher) {
+    const SAMPLES: usize = 1000;
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let v: Vec<Fq> = (0..SAMPLES).map(|_| Fq::rand(&mut rng)).collect();
+
+    b.iter(|| Fq::batch_into_repr(v.as_slice()));
+}
+
 #[bench]
 fn bench_fq_from_repr(b: &mut ::test::Bencher) {
     const SAMPLES: usize = 1000;
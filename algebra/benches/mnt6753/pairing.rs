@@ -62,6 +62,50 @@ mod pairing {
         });
     }
 
+    // The hard part of the final exponentiation by the generic square and multiply,
+    // for comparison with the cyclotomic exponentiation used by `final_exponentiation`.
+    #[bench]
+    fn bench_pairing_final_exponentiation_hard_part_generic_pow(b: &mut ::test::Bencher) {
+        use algebra::{curves::models::mnt6::MNT6Parameters, Field};
+        use std::ops::MulAssign;
+        const SAMPLES: usize = 1000;
+
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        // Outputs of the easy part of the final exponentiation
+        let v: Vec<Fq6> = (0..SAMPLES)
+            .map(|_| {
+                (
+                    G1Affine::from(G1::rand(&mut rng)).prepare(),
+                    G2Affine::from(G2::rand(&mut rng)).prepare(),
+                )
+            })
+            .map(|(ref p, ref q)| {
+                let f = MNT6::miller_loop(&[(p, q)]);
+                let mut easy = f;
+                easy.frobenius_map(3);
+                easy *= &f.inverse().unwrap();
+                let mut easy_q = easy;
+                easy_q.frobenius_map(1);
+                easy * &easy_q
+            })
+            .collect();
+
+        let mut count = 0;
+        b.iter(|| {
+            let mut elt_q = v[count];
+            elt_q.frobenius_map(1);
+            let mut tmp = elt_q.pow(MNT6_753Parameters::FINAL_EXPONENT_LAST_CHUNK_1);
+            if MNT6_753Parameters::FINAL_EXPONENT_LAST_CHUNK_W0_IS_NEG {
+                tmp.mul_assign(&v[count].inverse().unwrap().pow(MNT6_753Parameters::FINAL_EXPONENT_LAST_CHUNK_ABS_OF_W0));
+            } else {
+                tmp.mul_assign(&v[count].pow(MNT6_753Parameters::FINAL_EXPONENT_LAST_CHUNK_ABS_OF_W0));
+            }
+            count = (count + 1) % SAMPLES;
+            tmp
+        });
+    }
+
     #[bench]
     fn bench_pairing_full(b: &mut ::test::Bencher) {
         const SAMPLES: usize = 1000;
@@ -0,0 +1,53 @@
+#[macro_use]
+extern crate criterion;
+
+use algebra::{
+    fields::{bls12_381::Fr, mnt4753::Fq, ReprCached},
+    PrimeField,
+};
+use criterion::Criterion;
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+const SAMPLES: usize = 1 << 14;
+
+// Sorting calls `cmp`, hence `into_repr`, O(n log n) times; wrapping the elements
+// into `ReprCached` performs only n Montgomery reductions.
+fn bench_sort<F: PrimeField>(c: &mut Criterion, name: &str) {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let v = (0..SAMPLES).map(|_| F::rand(&mut rng)).collect::<Vec<_>>();
+
+    let elements = v.clone();
+    c.bench_function(&format!("{} sort", name), move |b| {
+        b.iter(|| {
+            let mut elements = elements.clone();
+            elements.sort();
+            elements
+        })
+    });
+
+    let elements = v;
+    c.bench_function(&format!("{} sort ReprCached", name), move |b| {
+        b.iter(|| {
+            let mut elements = elements.iter().cloned().map(ReprCached::new).collect::<Vec<_>>();
+            elements.sort();
+            elements
+        })
+    });
+}
+
+fn bench_fp256(c: &mut Criterion) {
+    bench_sort::<Fr>(c, "Fp256");
+}
+
+fn bench_fp768(c: &mut Criterion) {
+    bench_sort::<Fq>(c, "Fp768");
+}
+
+criterion_group! {
+    name = sort_by_repr;
+    config = Criterion::default().sample_size(10);
+    targets = bench_fp256, bench_fp768
+}
+
+criterion_main!(sort_by_repr);
@@ -0,0 +1,67 @@
+#[macro_use]
+extern crate criterion;
+
+use algebra::{
+    fields::{bls12_381, mnt4753, mnt6, sw6},
+    Field,
+};
+use criterion::Criterion;
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+const SAMPLES: usize = 1000;
+
+// Compares the dedicated Montgomery squaring, which computes each cross product
+// `a_i * a_j` only once, with the generic multiplication of an element by itself.
+fn bench_square_vs_mul<F: Field>(c: &mut Criterion, name: &str) {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let v = (0..SAMPLES).map(|_| F::rand(&mut rng)).collect::<Vec<_>>();
+
+    let elements = v.clone();
+    let mut count = 0;
+    c.bench_function(&format!("{} square", name), move |b| {
+        b.iter(|| {
+            let tmp = elements[count].square();
+            count = (count + 1) % SAMPLES;
+            tmp
+        })
+    });
+
+    let elements = v;
+    let mut count = 0;
+    c.bench_function(&format!("{} mul by self", name), move |b| {
+        b.iter(|| {
+            let tmp = elements[count] * &elements[count];
+            count = (count + 1) % SAMPLES;
+            tmp
+        })
+    });
+}
+
+fn bench_fp256(c: &mut Criterion) {
+    bench_square_vs_mul::<bls12_381::Fr>(c, "Fp256");
+}
+
+fn bench_fp320(c: &mut Criterion) {
+    bench_square_vs_mul::<mnt6::Fq>(c, "Fp320");
+}
+
+fn bench_fp384(c: &mut Criterion) {
+    bench_square_vs_mul::<bls12_381::Fq>(c, "Fp384");
+}
+
+fn bench_fp768(c: &mut Criterion) {
+    bench_square_vs_mul::<mnt4753::Fq>(c, "Fp768");
+}
+
+fn bench_fp832(c: &mut Criterion) {
+    bench_square_vs_mul::<sw6::Fq>(c, "Fp832");
+}
+
+criterion_group! {
+    name = square_vs_mul;
+    config = Criterion::default().sample_size(50);
+    targets = bench_fp256, bench_fp320, bench_fp384, bench_fp768, bench_fp832
+}
+
+criterion_main!(square_vs_mul);
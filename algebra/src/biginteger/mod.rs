@@ -1,13 +1,14 @@
+use alloc::vec::Vec;
 use crate::{
     bytes::{FromBytes, ToBytes},
     fields::BitIterator,
     UniformRand,
 };
 use rand::{Rng, distributions::{Distribution, Standard}};
-use std::{
+use core::{
     fmt::{Debug, Display},
-    io::{Read, Result as IoResult, Write},
 };
+use crate::io::{Read, Result as IoResult, Write};
 
 #[macro_use]
 mod macros;
@@ -91,6 +92,59 @@ pub trait BigInteger:
     /// Returns a vector for wnaf.
     fn find_wnaf(&self) -> Vec<i64>;
 
+    /// Returns the width-`w` NAF of `self`, least significant digit first: every
+    /// digit is either zero or odd and in `(-2^(w-1), 2^(w-1))`, every non-zero digit
+    /// is followed by at least `w - 1` zeros, and `sum_i digits[i] * 2^i == self`.
+    /// The NAF of zero is empty, and `find_wnaf` is the NAF of width 2.
+    /// Panics if `w` is not in `2..64`.
+    fn find_wnaf_with_width(&self, w: usize) -> Vec<i64> {
+        assert!(w >= 2 && w < 64, "invalid NAF width {}", w);
+        let modulus = 1u64 << w;
+        let half = 1i64 << (w - 1);
+
+        let mut res = Vec::with_capacity(self.num_bits() as usize + 1);
+        let mut e = *self;
+        while !e.is_zero() {
+            // A negative digit may carry out of the most significant limb, in which
+            // case the carry is shifted back in below.
+            let mut carry = false;
+            let z = if e.is_odd() {
+                let m = (e.as_ref()[0] % modulus) as i64;
+                let z = if m >= half { m - (modulus as i64) } else { m };
+                if z >= 0 {
+                    e.sub_noborrow(&Self::from(z as u64));
+                } else {
+                    carry = e.add_nocarry(&Self::from((-z) as u64));
+                }
+                z
+            } else {
+                0
+            };
+            res.push(z);
+            e.div2();
+            if carry {
+                let last = e.as_ref().len() - 1;
+                e.as_mut()[last] |= 1 << 63;
+            }
+        }
+        res
+    }
+
+    /// Parses a non-empty string of hexadecimal digits, in either case and
+    /// without prefix. Returns `None` on any other character, or if the value
+    /// does not fit into this representation.
+    fn from_hex_str(s: &str) -> Option<Self> {
+        let mut repr = Self::default();
+        if s.is_empty() || s.len() > 16 * repr.as_ref().len() {
+            return None;
+        }
+        for (i, c) in s.chars().rev().enumerate() {
+            let digit = u64::from(c.to_digit(16)?);
+            repr.as_mut()[i / 16] |= digit << (4 * (i % 16));
+        }
+        Some(repr)
+    }
+
     /// Writes this `BigInteger` as a big endian integer. Always writes
     /// `(num_bits` / 8) bytes.
     fn write_le<W: Write>(&self, writer: &mut W) -> IoResult<()> {
@@ -123,11 +177,20 @@ pub mod arithmetic {
     pub(crate) fn sbb(a: u64, b: u64, borrow: &mut u64) -> u64 {
         let tmp = (1u128 << 64) + u128::from(a) - u128::from(b) - u128::from(*borrow);
 
-        *borrow = if tmp >> 64 == 0 { 1 } else { 0 };
+        // Branch-free, as relied upon by the constant-time field operations.
+        *borrow = 1 - (tmp >> 64) as u64;
 
         tmp as u64
     }
 
+    /// Returns `u64::max_value()` if all `limbs` are zero and `0` otherwise,
+    /// without branching on their values.
+    #[inline(always)]
+    pub fn ct_is_zero_mask(limbs: &[u64]) -> u64 {
+        let acc = limbs.iter().fold(0u64, |acc, limb| acc | limb);
+        ((acc | acc.wrapping_neg()) >> 63).wrapping_sub(1)
+    }
+
     /// Calculate a + (b * c) + carry, returning the least significant digit
     /// and setting carry to the most significant digit.
     #[inline(always)]
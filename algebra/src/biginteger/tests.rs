@@ -59,6 +59,46 @@ fn biginteger_bytes_test<B: BigInteger>() {
     assert_eq!(x, y);
 }
 
+// Reconstructs `sum_i naf[i] * 2^i` modulo `2^num_bits` by Horner's rule.
+fn reconstruct_wnaf<B: BigInteger>(naf: &[i64]) -> B {
+    let mut acc = B::from(0);
+    for &d in naf.iter().rev() {
+        acc.mul2();
+        if d > 0 {
+            acc.add_nocarry(&B::from(d as u64));
+        } else {
+            acc.sub_noborrow(&B::from((-d) as u64));
+        }
+    }
+    acc
+}
+
+fn biginteger_wnaf_test<B: BigInteger>(zero: B) {
+    assert!(zero.find_wnaf_with_width(4).is_empty());
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let mut max = zero;
+    max.as_mut().iter_mut().for_each(|l| *l = u64::max_value());
+    let scalars = (0..20)
+        .map(|_| B::rand(&mut rng))
+        .chain(vec![B::from(1u64), B::from(7u64), max]);
+
+    for x in scalars {
+        for w in 2..8 {
+            let naf = x.find_wnaf_with_width(w);
+            assert!(naf.len() <= x.num_bits() as usize + 1);
+            assert_eq!(reconstruct_wnaf::<B>(&naf), x);
+            assert_ne!(naf.last(), Some(&0));
+            for (i, &d) in naf.iter().enumerate() {
+                if d != 0 {
+                    assert!(d % 2 != 0 && d.abs() < (1 << (w - 1)));
+                    assert!(naf[i + 1..].iter().take(w - 1).all(|&d| d == 0));
+                }
+            }
+        }
+    }
+}
+
 fn test_biginteger<B: BigInteger>(zero: B) {
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
     let a: B = UniformRand::rand(&mut rng);
@@ -66,6 +106,7 @@ fn test_biginteger<B: BigInteger>(zero: B) {
     biginteger_arithmetic_test(a, b, zero);
     biginteger_bytes_test::<B>();
     biginteger_bits_test::<B>();
+    biginteger_wnaf_test(zero);
 }
 
 #[test]
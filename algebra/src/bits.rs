@@ -1,3 +1,4 @@
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
 use crate::Error;
 
 pub trait ToBits {
@@ -27,8 +28,8 @@ pub enum BitSerializationError {
     InvalidFlags,
 }
 
-impl std::fmt::Display for BitSerializationError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for BitSerializationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let msg = match self {
             BitSerializationError::InvalidFieldElement(s) => s.to_owned(),
             BitSerializationError::UndefinedSqrt => "square root doesn't exist in field".to_owned(),
@@ -40,9 +41,9 @@ impl std::fmt::Display for BitSerializationError {
     }
 }
 
-impl std::error::Error for BitSerializationError {
+impl crate::ErrorTrait for BitSerializationError {
     #[inline]
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    fn source(&self) -> Option<&(dyn crate::ErrorTrait + 'static)> {
         None
     }
 }
\ No newline at end of file
@@ -1,14 +1,41 @@
-use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
-use std::io::{Read, Result as IoResult, Write};
+use crate::io::{Error as IoError, ErrorKind, Read, Result as IoResult, Write};
+use alloc::vec::Vec;
 
 pub trait ToBytes {
     /// Serializes `self` into `writer`.
     fn write<W: Write>(&self, writer: W) -> IoResult<()>;
+
+    /// Serializes `elems` into `writer`, one after the other, preceded by their
+    /// number as a `u64`. The elements are streamed into `writer` as they are
+    /// serialized, so that large vectors don't need to be buffered in memory.
+    fn write_batch<W: Write>(elems: &[Self], mut writer: W) -> IoResult<()>
+    where
+        Self: Sized,
+    {
+        (elems.len() as u64).write(&mut writer)?;
+        for elem in elems {
+            elem.write(&mut writer)?;
+        }
+        Ok(())
+    }
 }
 
 pub trait FromBytes: Sized {
     /// Reads `Self` from `reader`.
     fn read<R: Read>(reader: R) -> IoResult<Self>;
+
+    /// Reads `count` elements written by `ToBytes::write_batch` from `reader`.
+    /// Fails if the length prefix is not `count`.
+    fn read_batch<R: Read>(mut reader: R, count: usize) -> IoResult<Vec<Self>> {
+        if u64::read(&mut reader)? != count as u64 {
+            return Err(IoError::new(ErrorKind::InvalidData, "unexpected batch length"));
+        }
+        let mut elems = Vec::with_capacity(count);
+        for _ in 0..count {
+            elems.push(Self::read(&mut reader)?);
+        }
+        Ok(elems)
+    }
 }
 
 macro_rules! array_bytes {
@@ -33,7 +60,7 @@ macro_rules! array_bytes {
             #[inline]
             fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
                 for num in self {
-                    writer.write_u16::<LittleEndian>(*num)?;
+                    num.write(&mut writer)?;
                 }
                 Ok(())
             }
@@ -43,7 +70,9 @@ macro_rules! array_bytes {
             #[inline]
             fn read<R: Read>(mut reader: R) -> IoResult<Self> {
                 let mut res = [0u16; $N];
-                reader.read_u16_into::<LittleEndian>(&mut res)?;
+                for num in res.iter_mut() {
+                    *num = u16::read(&mut reader)?;
+                }
                 Ok(res)
             }
         }
@@ -52,7 +81,7 @@ macro_rules! array_bytes {
             #[inline]
             fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
                 for num in self {
-                    writer.write_u32::<LittleEndian>(*num)?;
+                    num.write(&mut writer)?;
                 }
                 Ok(())
             }
@@ -62,7 +91,9 @@ macro_rules! array_bytes {
             #[inline]
             fn read<R: Read>(mut reader: R) -> IoResult<Self> {
                 let mut res = [0u32; $N];
-                reader.read_u32_into::<LittleEndian>(&mut res)?;
+                for num in res.iter_mut() {
+                    *num = u32::read(&mut reader)?;
+                }
                 Ok(res)
             }
         }
@@ -71,7 +102,7 @@ macro_rules! array_bytes {
             #[inline]
             fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
                 for num in self {
-                    writer.write_u64::<LittleEndian>(*num)?;
+                    num.write(&mut writer)?;
                 }
                 Ok(())
             }
@@ -81,7 +112,9 @@ macro_rules! array_bytes {
             #[inline]
             fn read<R: Read>(mut reader: R) -> IoResult<Self> {
                 let mut res = [0u64; $N];
-                reader.read_u64_into::<LittleEndian>(&mut res)?;
+                for num in res.iter_mut() {
+                    *num = u64::read(&mut reader)?;
+                }
                 Ok(res)
             }
         }
@@ -128,9 +161,8 @@ array_bytes!(32);
 #[macro_export]
 macro_rules! to_bytes {
     ($($x:expr),*) => ({
-        use std::io::Cursor;
-        let mut buf = Cursor::new(vec![]);
-        {$crate::push_to_vec!(buf, $($x),*)}.map(|_| buf.into_inner())
+        let mut buf = $crate::alloc::vec::Vec::<u8>::new();
+        {$crate::push_to_vec!(buf, $($x),*)}.map(|_| buf)
     });
 }
 
@@ -147,61 +179,30 @@ macro_rules! push_to_vec {
     })
 }
 
-impl ToBytes for u8 {
-    #[inline]
-    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        writer.write_u8(*self)
-    }
-}
-
-impl FromBytes for u8 {
-    #[inline]
-    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
-        reader.read_u8()
-    }
-}
-
-impl ToBytes for u16 {
-    #[inline]
-    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        writer.write_u16::<LittleEndian>(*self)
-    }
-}
-
-impl FromBytes for u16 {
-    #[inline]
-    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
-        reader.read_u16::<LittleEndian>()
-    }
-}
-
-impl ToBytes for u32 {
-    #[inline]
-    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        writer.write_u32::<LittleEndian>(*self)
-    }
-}
-
-impl FromBytes for u32 {
-    #[inline]
-    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
-        reader.read_u32::<LittleEndian>()
-    }
-}
+macro_rules! int_bytes {
+    ($int:ty) => {
+        impl ToBytes for $int {
+            #[inline]
+            fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+                writer.write_all(&self.to_le_bytes())
+            }
+        }
 
-impl ToBytes for u64 {
-    #[inline]
-    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        writer.write_u64::<LittleEndian>(*self)
-    }
+        impl FromBytes for $int {
+            #[inline]
+            fn read<R: Read>(mut reader: R) -> IoResult<Self> {
+                let mut bytes = [0u8; core::mem::size_of::<$int>()];
+                reader.read_exact(&mut bytes)?;
+                Ok(<$int>::from_le_bytes(bytes))
+            }
+        }
+    };
 }
 
-impl FromBytes for u64 {
-    #[inline]
-    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
-        reader.read_u64::<LittleEndian>()
-    }
-}
+int_bytes!(u8);
+int_bytes!(u16);
+int_bytes!(u32);
+int_bytes!(u64);
 
 impl ToBytes for () {
     #[inline]
@@ -230,7 +231,7 @@ impl FromBytes for bool {
         match u8::read(reader) {
             Ok(0) => Ok(false),
             Ok(1) => Ok(true),
-            Ok(_) => Err(::std::io::ErrorKind::Other.into()),
+            Ok(_) => Err(ErrorKind::Other.into()),
             Err(err) => Err(err),
         }
     }
@@ -279,7 +280,7 @@ mod test {
     fn test_macro_empty() {
         let array: Vec<u8> = vec![];
         let bytes: Vec<u8> = to_bytes![array].unwrap();
-        assert_eq!(&bytes, &[]);
+        assert_eq!(&bytes, &[0u8; 0]);
         assert_eq!(bytes.len(), 0);
     }
 
@@ -4,7 +4,7 @@ use crate::{
     curves::{
         bls12::{G1Affine as Bls12G1Affine, G1Prepared, G1Projective as Bls12G1Projective},
         bls12_381::{g2::G2Affine, Bls12_381, Bls12_381Parameters},
-        models::{ModelParameters, SWModelParameters},
+        models::{GLVParameters, ModelParameters, SWModelParameters},
         PairingCurve, PairingEngine,
     },
     fields::{
@@ -75,6 +75,48 @@ impl SWModelParameters for Bls12_381G1Parameters {
     }
 }
 
+impl GLVParameters for Bls12_381G1Parameters {
+    /// BETA = a primitive cube root of unity in Fq
+    /// = 4002409555221667392624310435006688643935503118305586438271171395842971157480381377015405980053539358417135540939436
+    const BETA: Fq = field_new!(Fq, BigInteger384([
+        0xcd03c9e48671f071,
+        0x5dab22461fcda5d2,
+        0x587042afd3851b95,
+        0x8eb60ebe01bacb9e,
+        0x3f97d6e83d050d2,
+        0x18f0206554638741,
+    ]));
+
+    /// LAMBDA = x^2 - 1 = 228988810152649578064853576960394133503
+    const LAMBDA: Fr = field_new!(Fr, BigInteger256([
+        0x92d9090b093011d2,
+        0xfc9cbd719d6aa073,
+        0xc1f14ef0cd65a1a6,
+        0x17f6d35e72fcdeb,
+    ]));
+
+    /// V1 = (LAMBDA, -1)
+    const V1: ((BigInteger256, bool), (BigInteger256, bool)) = (
+        (BigInteger256([0xffffffff, 0xac45a4010001a402, 0x0, 0x0]), false),
+        (BigInteger256([0x1, 0x0, 0x0, 0x0]), true),
+    );
+
+    /// V2 = (1, LAMBDA + 1)
+    const V2: ((BigInteger256, bool), (BigInteger256, bool)) = (
+        (BigInteger256([0x1, 0x0, 0x0, 0x0]), false),
+        (BigInteger256([0x100000000, 0xac45a4010001a402, 0x0, 0x0]), false),
+    );
+
+    /// G1 = round(2^256 * (LAMBDA + 1) / r)
+    const G1: (BigInteger256, bool) = (
+        BigInteger256([0x63f6e522f6cfee30, 0x7c6becf1e01faadd, 0x1, 0x0]),
+        false,
+    );
+
+    /// G2 = round(2^256 / r) = 2
+    const G2: (BigInteger256, bool) = (BigInteger256([0x2, 0x0, 0x0, 0x0]), false);
+}
+
 /// G1_GENERATOR_X =
 /// 3685416753713387016781088315183077757961620795782546409894578378688607592378376318836054947676345821548104185464507
 pub const G1_GENERATOR_X: Fq = field_new!(Fq, BigInteger384([
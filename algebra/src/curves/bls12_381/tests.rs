@@ -6,7 +6,7 @@ use crate::{
             g2::{Bls12_381G2Parameters, G2Affine, G2Projective},
             Bls12_381,
         },
-        models::SWModelParameters,
+        models::{GLVParameters, SWModelParameters},
         tests::curve_tests,
         AffineCurve, PairingEngine, ProjectiveCurve,
     },
@@ -38,6 +38,38 @@ fn test_g1_generator() {
     assert!(generator.is_in_correct_subgroup_assuming_on_curve());
 }
 
+#[test]
+fn test_g1_glv_decompose() {
+    use crate::biginteger::BigInteger;
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+    use crate::UniformRand;
+
+    type P = Bls12_381G1Parameters;
+
+    // the endomorphism is the multiplication by LAMBDA
+    let g = G1Affine::prime_subgroup_generator();
+    let phi_g = G1Affine::new(g.x * &P::BETA, g.y, false);
+    assert_eq!(g.mul(P::LAMBDA).into_affine(), phi_g);
+
+    let signed = |k: <Fr as PrimeField>::BigInt, neg: bool| {
+        let k = Fr::from_repr(k);
+        if neg { -k } else { k }
+    };
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let scalars = (0..1000)
+        .map(|_| Fr::rand(&mut rng))
+        .chain(vec![Fr::zero(), Fr::one(), -Fr::one(), P::LAMBDA, -P::LAMBDA]);
+    for k in scalars {
+        let (k1, k2, sign1, sign2) = P::glv_decompose(&k);
+        assert_eq!(signed(k1, sign1) + &(signed(k2, sign2) * &P::LAMBDA), k);
+        // both halves are below 2^127 < sqrt(r)
+        assert!(k1.num_bits() <= (Fr::size_in_bits() as u32 - 1) / 2);
+        assert!(k2.num_bits() <= (Fr::size_in_bits() as u32 - 1) / 2);
+    }
+}
+
 #[test]
 fn test_g2_projective_curve() {
     curve_tests::<G2Projective>();
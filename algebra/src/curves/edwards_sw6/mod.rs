@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use crate::field_new;
 use crate::{
     biginteger::BigInteger384 as BigInteger,
@@ -7,7 +8,7 @@ use crate::{
     },
     fields::edwards_sw6::{fq::Fq, fr::Fr},
 };
-use std::str::FromStr;
+use core::str::FromStr;
 
 #[cfg(test)]
 mod tests;
@@ -3,7 +3,7 @@ use crate::{curves::{
         G1Affine, G1Projective, G2Affine, G2Projective,
         MNT4,
     },
-    tests::curve_tests,
+    tests::{curve_tests, generator_order_test},
     AffineCurve, PairingEngine,
 }, biginteger::BigInteger768, fields::mnt4753::{fq::Fq, fq2::Fq2, fq4::Fq4, fr::Fr}, groups::tests::{
     group_test, compression_test, gt_compression_test
@@ -30,6 +30,11 @@ fn test_g1_generator() {
     assert!(generator.is_in_correct_subgroup_assuming_on_curve());
 }
 
+#[test]
+fn test_g1_generator_order() {
+    generator_order_test::<G1Projective>();
+}
+
 #[test]
 fn test_g1_compression_decompression() {
 
@@ -129,6 +134,11 @@ fn test_g2_generator() {
     assert!(generator.is_in_correct_subgroup_assuming_on_curve());
 }
 
+#[test]
+fn test_g2_generator_order() {
+    generator_order_test::<G2Projective>();
+}
+
 #[test]
 fn test_g2_compression_decompression() {
     let even = G2Affine::new(
@@ -489,6 +499,42 @@ fn test_bilinearity() {
     assert_eq!(ans3.pow(Fr::characteristic()), Fq4::one());
 }
 
+#[test]
+fn test_product_of_pairings() {
+    use crate::{curves::PairingCurve, UniformRand};
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+
+    for num_pairings in 1..4 {
+        let ps = (0..num_pairings).map(|_| G1Projective::rand(rng).into_affine()).collect::<Vec<_>>();
+        let qs = (0..num_pairings).map(|_| G2Projective::rand(rng).into_affine()).collect::<Vec<_>>();
+
+        let prepared_ps = ps.iter().map(|p| p.prepare()).collect::<Vec<_>>();
+        let prepared_qs = qs.iter().map(|q| q.prepare()).collect::<Vec<_>>();
+        let pairs = prepared_ps.iter().zip(prepared_qs.iter()).collect::<Vec<_>>();
+
+        let expected = ps.iter().zip(qs.iter())
+            .map(|(p, q)| MNT4::pairing(*p, *q))
+            .fold(Fq4::one(), |acc, e| acc * &e);
+
+        assert_eq!(MNT4::product_of_pairings(&pairs), expected);
+
+        // The squarings are shared, but the result is the product of the single Miller loops
+        let single_loops = pairs.iter()
+            .map(|(p, q)| MNT4::ate_miller_loop(p, q))
+            .fold(Fq4::one(), |acc, f| acc * &f);
+        assert_eq!(MNT4::ate_multi_miller_loop(&pairs), single_loops);
+    }
+
+    // e(a, b) * e(-a, b) = 1
+    let a = G1Projective::rand(rng).into_affine();
+    let b = G2Projective::rand(rng).into_affine();
+    let (a_prep, neg_a_prep, b_prep) = (a.prepare(), (-a).prepare(), b.prepare());
+    assert_eq!(MNT4::product_of_pairings(&[(&a_prep, &b_prep), (&neg_a_prep, &b_prep)]), Fq4::one());
+}
+
 #[test]
 fn test_gt_compression(){
     let even = Fq4::new(
@@ -1594,4 +1640,127 @@ fn test_g2_affine_projective_conversion() {
     ));
 
     assert_eq!(a_a.into_projective(), a);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_line_evaluation_sparse_mul() {
+    use crate::curves::models::mnt4::{G1Prepared, G2Prepared};
+
+    let p: G1Projective = rand::random();
+    let q: G2Projective = rand::random();
+    let p_prep = G1Prepared::from_affine(&p.into_affine());
+    let q_prep = G2Prepared::from_affine(&q.into_affine());
+
+    // The line evaluations accumulated by the Miller loop are sparse, and `mul_by_023`
+    // agrees with the full multiplication on them.
+    for c in q_prep.coeffs.iter() {
+        let line = MNT4::line_evaluation(&p_prep, c, &c.r_y);
+        assert!(line.c0.c1.is_zero());
+        let f: Fq4 = rand::random();
+        assert_eq!(f.mul_by_023(&line), f * &line);
+    }
+}
+
+#[test]
+fn test_final_exponentiation_vs_generic_pow() {
+    use crate::{curves::{tests::final_exponent, PairingCurve}, UniformRand};
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+
+    let p = G1Projective::rand(rng).into_affine().prepare();
+    let q = G2Projective::rand(rng).into_affine().prepare();
+    let f = MNT4::miller_loop(&[(&p, &q)]);
+
+    // (q^4 - 1)/r, computed from the moduli rather than from the chunks of the
+    // final exponent used by the pairing, and applied by the generic square and multiply
+    let exponent = final_exponent::<Fq, Fr>(4);
+    let expected = f.pow(&exponent);
+
+    let result = MNT4::final_exponentiation(&f);
+    assert_eq!(result, expected);
+    assert_ne!(result, Fq4::one());
+    assert_eq!(result.pow(Fr::characteristic()), Fq4::one());
+}
+
+#[test]
+fn test_subgroup_check_and_checked_deserialization() {
+    use crate::{bytes::ToBytes, to_bytes, UniformRand};
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+
+    // G1 has cofactor one: every point of the curve is in the subgroup
+    let p = G1Projective::rand(rng).into_affine();
+    assert!(p.is_in_correct_subgroup_assuming_on_curve());
+    assert_eq!(G1Affine::deserialize_checked(to_bytes!(p).unwrap().as_slice()).unwrap(), p);
+
+    let q = G2Projective::rand(rng).into_affine();
+    assert!(q.is_in_correct_subgroup_assuming_on_curve());
+    assert_eq!(G2Affine::deserialize_checked(to_bytes!(q).unwrap().as_slice()).unwrap(), q);
+    let zero = G2Affine::zero();
+    assert_eq!(G2Affine::deserialize_checked(to_bytes!(zero).unwrap().as_slice()).unwrap(), zero);
+
+    // A point on the twist which is (with overwhelming probability) outside the subgroup
+    let not_in_subgroup = loop {
+        if let Some(q) = G2Affine::get_point_from_x_and_parity(Fq2::rand(rng), false) {
+            break q;
+        }
+    };
+    assert!(not_in_subgroup.is_on_curve());
+    assert!(!not_in_subgroup.is_in_correct_subgroup_assuming_on_curve());
+    assert!(G2Affine::deserialize_checked(to_bytes!(not_in_subgroup).unwrap().as_slice()).is_err());
+    // while the unchecked deserialization accepts it
+    assert_eq!(
+        <G2Affine as crate::bytes::FromBytes>::read(to_bytes!(not_in_subgroup).unwrap().as_slice()).unwrap(),
+        not_in_subgroup
+    );
+
+    // A point not on the curve
+    let not_on_curve = G1Affine::new(p.x, p.y.double(), false);
+    assert!(!not_on_curve.is_on_curve());
+    assert!(G1Affine::deserialize_checked(to_bytes!(not_on_curve).unwrap().as_slice()).is_err());
+}
+
+#[test]
+fn test_batch_decompression() {
+    use crate::{ToCompressedBits, UniformRand};
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+
+    let mut points = (0..10)
+        .map(|_| G2Projective::rand(rng).into_affine())
+        .collect::<Vec<_>>();
+    points.push(G2Affine::zero());
+    let compressed = points.iter().map(|p| p.compress()).collect::<Vec<_>>();
+
+    let decompressed = G2Affine::from_compressed_batch(&compressed).unwrap();
+    assert_eq!(decompressed, points);
+    for (c, p) in compressed.iter().zip(decompressed.iter()) {
+        assert_eq!(&G2Affine::decompress(c.clone()).unwrap(), p);
+    }
+    assert!(G2Affine::from_compressed_batch(&[]).unwrap().is_empty());
+
+    // A point on the twist which is (with overwhelming probability) outside the subgroup
+    let not_in_subgroup = loop {
+        if let Some(q) = G2Affine::get_point_from_x_and_parity(Fq2::rand(rng), false) {
+            break q;
+        }
+    };
+    let mut invalid = compressed.clone();
+    invalid[4] = not_in_subgroup.compress();
+    let (index, _) = G2Affine::from_compressed_batch(&invalid).unwrap_err();
+    assert_eq!(index, 4);
+
+    // Illegal flags: infinity set together with the parity bit
+    let mut invalid = compressed;
+    let last = invalid.len() - 1;
+    let len = invalid[last].len();
+    invalid[last][len - 1] = true;
+    let (index, _) = G2Affine::from_compressed_batch(&invalid).unwrap_err();
+    assert_eq!(index, last);
+}
@@ -10,8 +10,8 @@ use crate::{
     },
     fields::mnt6::{Fq, Fq3, Fq6, Fr},
 };
-use std::io::{Result as IoResult, Write, Read};
-use std::io;
+use crate::io::{Result as IoResult, Write, Read};
+use crate::io;
 
 pub type G1Affine = GroupAffine<MNT6G1Parameters>;
 pub type G1Projective = GroupProjective<MNT6G1Parameters>;
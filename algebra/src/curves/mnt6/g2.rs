@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use crate::{field_new, FromBytes};
 use crate::{
     biginteger::BigInteger320,
@@ -10,9 +11,8 @@ use crate::{
     },
     fields::mnt6::{Fq, Fq3, Fq6, Fr},
 };
-use std::io::{Result as IoResult, Write, Read};
-use std::io;
-use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+use crate::io::{Result as IoResult, Write, Read};
+use crate::io;
 
 pub type G2Affine = GroupAffine<MNT6G2Parameters>;
 pub type G2Projective = GroupProjective<MNT6G2Parameters>;
@@ -183,11 +183,11 @@ impl ToBytes for G2Prepared {
         self.y.write(&mut writer)?;
         self.x_over_twist.write(&mut writer)?;
         self.y_over_twist.write(&mut writer)?;
-        writer.write_u32::<BigEndian>(self.double_coefficients.len() as u32)?;
+        writer.write_all(&(self.double_coefficients.len() as u32).to_be_bytes())?;
         for dc in self.double_coefficients.clone() {
             dc.write(&mut writer)?;
         }
-        writer.write_u32::<BigEndian>(self.addition_coefficients.len() as u32)?;
+        writer.write_all(&(self.addition_coefficients.len() as u32).to_be_bytes())?;
         for ac in self.addition_coefficients.clone(){
             ac.write(&mut writer)?;
         }
@@ -206,7 +206,9 @@ impl FromBytes for G2Prepared {
         let y_over_twist = Fq3::read(&mut reader)
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
-        let double_coeffs_len = reader.read_u32::<BigEndian>()? as usize;
+        let mut double_coeffs_len_bytes = [0u8; 4];
+        reader.read_exact(&mut double_coeffs_len_bytes)?;
+        let double_coeffs_len = u32::from_be_bytes(double_coeffs_len_bytes) as usize;
         let mut double_coefficients = vec![];
         for _ in 0..double_coeffs_len {
             let dc = AteDoubleCoefficients::read(&mut reader)
@@ -214,7 +216,9 @@ impl FromBytes for G2Prepared {
             double_coefficients.push(dc);
         }
 
-        let add_coeffs_len = reader.read_u32::<BigEndian>()? as usize;
+        let mut add_coeffs_len_bytes = [0u8; 4];
+        reader.read_exact(&mut add_coeffs_len_bytes)?;
+        let add_coeffs_len = u32::from_be_bytes(add_coeffs_len_bytes) as usize;
         let mut addition_coefficients = vec![];
         for _ in 0..add_coeffs_len {
             let ac = AteAdditionCoefficients::read(&mut reader)
@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use crate::field_new;
 use crate::{
     biginteger::BigInteger320,
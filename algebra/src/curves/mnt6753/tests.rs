@@ -612,6 +612,71 @@ fn test_bilinearity() {
 }
 
 
+#[test]
+fn test_product_of_pairings() {
+    use crate::{curves::PairingCurve, UniformRand};
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+
+    for num_pairings in 1..4 {
+        let ps = (0..num_pairings).map(|_| G1Projective::rand(rng).into_affine()).collect::<Vec<_>>();
+        let qs = (0..num_pairings).map(|_| G2Projective::rand(rng).into_affine()).collect::<Vec<_>>();
+
+        let prepared_ps = ps.iter().map(|p| p.prepare()).collect::<Vec<_>>();
+        let prepared_qs = qs.iter().map(|q| q.prepare()).collect::<Vec<_>>();
+        let pairs = prepared_ps.iter().zip(prepared_qs.iter()).collect::<Vec<_>>();
+
+        let expected = ps.iter().zip(qs.iter())
+            .map(|(p, q)| MNT6::pairing(*p, *q))
+            .fold(Fq6::one(), |acc, e| acc * &e);
+
+        assert_eq!(MNT6::product_of_pairings(&pairs), expected);
+        assert_eq!(<MNT6 as PairingEngine>::final_exponentiation(&MNT6::miller_loop(&pairs)).unwrap(), expected);
+
+        // The squarings are shared, but the result is the product of the single Miller loops
+        let single_loops = pairs.iter()
+            .map(|(p, q)| MNT6::ate_miller_loop(p, q))
+            .fold(Fq6::one(), |acc, f| acc * &f);
+        assert_eq!(MNT6::ate_multi_miller_loop(&pairs), single_loops);
+    }
+
+    // e(a, b) * e(-a, b) = 1
+    let a = G1Projective::rand(rng).into_affine();
+    let b = G2Projective::rand(rng).into_affine();
+    let (a_prep, neg_a_prep, b_prep) = (a.prepare(), (-a).prepare(), b.prepare());
+    assert_eq!(MNT6::product_of_pairings(&[(&a_prep, &b_prep), (&neg_a_prep, &b_prep)]), Fq6::one());
+}
+
+#[test]
+fn test_prepared_serialization() {
+    use crate::{bytes::{FromBytes, ToBytes}, curves::PairingCurve, to_bytes, UniformRand};
+    use crate::curves::models::mnt6::{G1Prepared, G2Prepared};
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+
+    let p = G1Projective::rand(rng).into_affine();
+    let q = G2Projective::rand(rng).into_affine();
+    let p_prep = p.prepare();
+    let q_prep = q.prepare();
+
+    let p_bytes = to_bytes!(p_prep).unwrap();
+    let p_prep_read = G1Prepared::read(p_bytes.as_slice()).unwrap();
+    assert_eq!(p_prep_read, p_prep);
+
+    let q_bytes = to_bytes!(q_prep).unwrap();
+    let q_prep_read = G2Prepared::read(q_bytes.as_slice()).unwrap();
+    assert_eq!(q_prep_read, q_prep);
+
+    assert_eq!(
+        MNT6::product_of_pairings(&[(&p_prep_read, &q_prep_read)]),
+        MNT6::pairing(p, q)
+    );
+}
+
 #[test]
 fn test_gt_compression(){
 
@@ -2026,4 +2091,46 @@ fn test_g2_affine_projective_conversion() {
         false
     ));
     assert_eq!(a_a.into_projective(), a);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_line_evaluation_sparse_mul() {
+    use crate::curves::models::mnt6::{G1Prepared, G2Prepared};
+
+    let p: G1Projective = rand::random();
+    let q: G2Projective = rand::random();
+    let p_prep = G1Prepared::from_affine(&p.into_affine());
+    let q_prep = G2Prepared::from_affine(&q.into_affine());
+
+    // The line evaluations accumulated by the Miller loop are sparse, and `mul_by_2345`
+    // agrees with the full multiplication on them.
+    for c in q_prep.coeffs.iter() {
+        let line = MNT6::line_evaluation(&p_prep, c, &c.r_y);
+        assert!(line.c0.c0.is_zero() && line.c0.c1.is_zero());
+        let f: Fq6 = rand::random();
+        assert_eq!(f.mul_by_2345(&line), f * &line);
+    }
+}
+
+#[test]
+fn test_final_exponentiation_vs_generic_pow() {
+    use crate::{curves::{tests::final_exponent, PairingCurve}, UniformRand};
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+
+    let p = G1Projective::rand(rng).into_affine().prepare();
+    let q = G2Projective::rand(rng).into_affine().prepare();
+    let f = MNT6::miller_loop(&[(&p, &q)]);
+
+    // (q^6 - 1)/r, computed from the moduli rather than from the chunks of the
+    // final exponent used by the pairing, and applied by the generic square and multiply
+    let exponent = final_exponent::<Fq, Fr>(6);
+    let expected = f.pow(&exponent);
+
+    let result = MNT6::final_exponentiation(&f);
+    assert_eq!(result, expected);
+    assert_ne!(result, Fq6::one());
+    assert_eq!(result.pow(Fr::characteristic()), Fq6::one());
+}
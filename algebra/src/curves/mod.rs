@@ -1,8 +1,9 @@
 use crate::{bytes::{FromBytes, ToBytes}, fields::{Field, PrimeField, SquareRootField}, groups::Group};
 use crate::UniformRand;
-use std::{
+use core::{
     fmt::{Debug, Display},
     hash::Hash,
+    iter::Sum,
     ops::{Add, AddAssign, Neg, Sub, SubAssign},
 };
 
@@ -130,6 +131,8 @@ pub trait ProjectiveCurve:
     + for<'a> Sub<&'a Self, Output = Self>
     + for<'a> AddAssign<&'a Self>
     + for<'a> SubAssign<&'a Self>
+    + Sum<Self>
+    + for<'a> Sum<&'a Self>
 {
     type ScalarField: PrimeField + SquareRootField + Into<<Self::ScalarField as PrimeField>::BigInt>;
     type BaseField: Field;
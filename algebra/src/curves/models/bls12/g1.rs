@@ -3,8 +3,8 @@ use crate::{bytes::ToBytes, curves::{
     short_weierstrass_jacobian::{GroupAffine, GroupProjective},
     AffineCurve,
 }, FromBytes};
-use std::io::{Result as IoResult, Write, Read};
-use std::io;
+use crate::io::{Result as IoResult, Write, Read};
+use crate::io;
 
 pub type G1Affine<P> = GroupAffine<<P as Bls12Parameters>::G1Parameters>;
 pub type G1Projective<P> = GroupProjective<<P as Bls12Parameters>::G1Parameters>;
@@ -1,12 +1,12 @@
+use alloc::vec::Vec;
 use crate::{bytes::ToBytes, curves::{
     bls12::{Bls12Parameters, TwistType},
     models::SWModelParameters,
     short_weierstrass_jacobian::{GroupAffine, GroupProjective},
     AffineCurve,
 }, fields::{BitIterator, Field, Fp2}, FromBytes};
-use std::io::{Result as IoResult, Write, Read};
-use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
-use std::io;
+use crate::io::{Result as IoResult, Write, Read};
+use crate::io;
 
 pub type G2Affine<P> = GroupAffine<<P as Bls12Parameters>::G2Parameters>;
 pub type G2Projective<P> = GroupProjective<<P as Bls12Parameters>::G2Parameters>;
@@ -45,7 +45,7 @@ impl<P: Bls12Parameters> Default for G2Prepared<P> {
 
 impl<P: Bls12Parameters> ToBytes for G2Prepared<P> {
     fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        writer.write_u32::<BigEndian>(self.ell_coeffs.len() as u32)?;
+        writer.write_all(&(self.ell_coeffs.len() as u32).to_be_bytes())?;
         for coeff in &self.ell_coeffs {
             coeff.0.write(&mut writer)?;
             coeff.1.write(&mut writer)?;
@@ -57,7 +57,9 @@ impl<P: Bls12Parameters> ToBytes for G2Prepared<P> {
 
 impl<P: Bls12Parameters> FromBytes for G2Prepared<P> {
     fn read<R: Read>(mut reader: R) -> IoResult<Self> {
-        let ell_coeffs_len = reader.read_u32::<BigEndian>()? as usize;
+        let mut ell_coeffs_len_bytes = [0u8; 4];
+        reader.read_exact(&mut ell_coeffs_len_bytes)?;
+        let ell_coeffs_len = u32::from_be_bytes(ell_coeffs_len_bytes) as usize;
         let mut ell_coeffs = vec![];
         for _ in 0..ell_coeffs_len {
             let c0 = Fp2::<P::Fp2Params>::read(&mut reader)
@@ -11,7 +11,7 @@ use crate::{
     },
 };
 
-use std::marker::PhantomData;
+use core::marker::PhantomData;
 
 pub enum TwistType {
     M,
@@ -1,8 +1,8 @@
 use crate::curves::models::mnt6::{MNT6Parameters, MNT6p};
 use crate::curves::short_weierstrass_projective::{GroupAffine, GroupProjective};
 use crate::{Fp3, ToBytes, AffineCurve, FromBytes};
-use std::io::{Write, Result as IoResult, Read};
-use std::io;
+use crate::io::{Write, Result as IoResult, Read};
+use crate::io;
 
 pub type G1Affine<P> = GroupAffine<<P as MNT6Parameters>::G1Parameters>;
 pub type G1Projective<P> = GroupProjective<<P as MNT6Parameters>::G1Parameters>;
@@ -1,9 +1,9 @@
+use alloc::vec::Vec;
 use crate::curves::models::mnt6::{MNT6Parameters, MNT6p};
 use crate::curves::short_weierstrass_projective::{GroupAffine, GroupProjective};
 use crate::{Fp3, ToBytes, AffineCurve, FromBytes};
-use std::io::{Write, Result as IoResult, Read};
-use std::io;
-use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+use crate::io::{Write, Result as IoResult, Read};
+use crate::io;
 
 pub type G2Affine<P> = GroupAffine<<P as MNT6Parameters>::G2Parameters>;
 pub type G2Projective<P> = GroupProjective<<P as MNT6Parameters>::G2Parameters>;
@@ -59,7 +59,7 @@ pub struct G2Prepared<P: MNT6Parameters>{
 impl<P: MNT6Parameters> ToBytes for G2Prepared<P> {
     fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
         self.q.write(&mut writer)?;
-        writer.write_u32::<BigEndian>(self.coeffs.len() as u32)?;
+        writer.write_all(&(self.coeffs.len() as u32).to_be_bytes())?;
         for c in &self.coeffs{
             c.write(&mut writer)?;
         }
@@ -72,7 +72,9 @@ impl<P: MNT6Parameters> FromBytes for G2Prepared<P> {
         let q = G2Affine::<P>::read(&mut reader)
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
-        let coeffs_len = reader.read_u32::<BigEndian>()? as usize;
+        let mut coeffs_len_bytes = [0u8; 4];
+        reader.read_exact(&mut coeffs_len_bytes)?;
+        let coeffs_len = u32::from_be_bytes(coeffs_len_bytes) as usize;
         let mut coeffs = vec![];
 
         for _ in 0..coeffs_len {
@@ -1,8 +1,9 @@
+use alloc::vec::Vec;
 use crate::{Fp3, BigInteger768 as BigInteger, PrimeField, SquareRootField, Fp3Parameters,
             Fp6Parameters, SWModelParameters, ModelParameters, PairingEngine, Fp6, PairingCurve,
             Field};
-use std::marker::PhantomData;
-use std::ops::{Add, Mul, Sub, MulAssign};
+use core::marker::PhantomData;
+use core::ops::{Add, Mul, Sub, MulAssign};
 
 
 // Ate pairing e: G_1 x G_2 -> G_T for MNT6 curves over prime fields
@@ -156,6 +157,15 @@ impl<P: MNT6Parameters> MNT6p<P> {
 
 
     pub fn ate_miller_loop(p: &G1Prepared<P>, q: &G2Prepared<P>) -> Fp6<P::Fp6Params> {
+        Self::ate_multi_miller_loop(&[(p, q)])
+    }
+
+    /// Computes the product of the Miller loops of all the `pairs`, sharing the squarings
+    /// of the accumulator among them: the line evaluations of every pair are multiplied
+    /// into the same `f` at each step of the loop. The result is the product of the
+    /// `ate_miller_loop`s of the pairs, hence a single final exponentiation gives the
+    /// product of the pairings.
+    pub fn ate_multi_miller_loop(pairs: &[(&G1Prepared<P>, &G2Prepared<P>)]) -> Fp6<P::Fp6Params> {
 
         let mut f = Fp6::<P::Fp6Params>::one();
 
@@ -167,8 +177,6 @@ impl<P: MNT6Parameters> MNT6p<P> {
 
             // doubling step
             f = f.square();
-            let c = &q.coeffs[idx];
-            idx += 1;
 
             // evaluate the tangent line g_{R,R} at P in F6 (scaled by twist^2) using the
             // pre-computed data:
@@ -181,51 +189,63 @@ impl<P: MNT6Parameters> MNT6p<P> {
             //     g_{R,R}(P) = y_p*twist^2 + (gamma*x'- gamma*twist*x_p - y') *Y.
             // The scale factor twist^2 from F3 is cancelled out by the final exponentiation.
 
-            let mut gamma_twist_times_x = c.gamma.mul(&P::TWIST);
-            gamma_twist_times_x.mul_assign_by_fp(&p.p.x);
-            let g_rr_at_p = Fp6::<P::Fp6Params>::new(
-                p.py_twist_squared,
-                c.gamma_x - &gamma_twist_times_x  -&c.r_y,
-            );
-            //and cumulate it to f
-            f = f.mul_by_2345(&g_rr_at_p);
+            for &(p, q) in pairs.iter() {
+                let c = &q.coeffs[idx];
+                let g_rr_at_p = Self::line_evaluation(p, c, &c.r_y);
+
+                //and cumulate it to f
+                f = f.mul_by_2345(&g_rr_at_p);
+            }
+            idx += 1;
 
             //addition/substraction step
             if n != 0 {
-                let c = &q.coeffs[idx];
+                for &(p, q) in pairs.iter() {
+                    let c = &q.coeffs[idx];
+                    //evaluate chord g_{RQ}(P) in F6 using pre-computed data as above
+                    let q_y = if n > 0 { q.q.y } else { -q.q.y };
+                    let g_rq_at_p = Self::line_evaluation(p, c, &q_y);
+
+                    //and cumulate it to f
+                    f = f.mul_by_2345(&g_rq_at_p);
+                }
                 idx += 1;
-
-                //evaluate chord g_{RQ}(P) in F6 using pre-computed data as above
-                //I suggest to write a separate function for the point evaluation
-                //as done in the implementation of the sw6 Miller loop
-                let mut gamma_twist_times_x = c.gamma.mul(&P::TWIST);
-                gamma_twist_times_x.mul_assign_by_fp(&p.p.x);
-                let g_rq_at_p_c1 = if n > 0 {
-                    c.gamma_x - &gamma_twist_times_x - &q.q.y
-                } else {
-                    c.gamma_x - &gamma_twist_times_x + &q.q.y
-                };
-                let g_rq_at_p = Fp6::<P::Fp6Params>::new(
-                    p.py_twist_squared,
-                    g_rq_at_p_c1,
-                );
-                //and cumulate it to f
-                f = f.mul_by_2345(&g_rq_at_p);
             }
         }
 
+        // f is not unitary yet, but after the final exponentiation its conjugate
+        // yields the inverse of the (unitary) pairing value
         if P::ATE_IS_LOOP_COUNT_NEG {
-            f = f.unitary_inverse();
+            f.conjugate();
         }
 
         f
     }
 
+    // Evaluates at P, using the pre-computed coefficients c of a doubling or addition step,
+    // the line through the twist point (x', y') having F3-slope gamma, where gamma_x = gamma * x'
+    // (hence y' = r_y for the doubling step, and y' = +/- q.y for the addition step):
+    //     g(P) = y_p*twist^2 + (gamma*x'- gamma*twist*x_p - y') *Y.
+    pub fn line_evaluation(
+        p: &G1Prepared<P>,
+        c: &G2PreparedCoefficients<P>,
+        y: &Fp3<P::Fp3Params>,
+    ) -> Fp6<P::Fp6Params> {
+        let mut gamma_twist_times_x = c.gamma.mul(&P::TWIST);
+        gamma_twist_times_x.mul_assign_by_fp(&p.p.x);
+
+        Fp6::<P::Fp6Params>::new(
+            p.py_twist_squared,
+            c.gamma_x - &gamma_twist_times_x - y,
+        )
+    }
+
     pub fn final_exponentiation(value: &Fp6<P::Fp6Params>) -> Fp6<P::Fp6Params> {
         let value_inv = value.inverse().unwrap();
         // "easy part" of the exponentiation
         let value_to_first_chunk = Self::final_exponentiation_first_chunk(value, &value_inv);
-        let value_inv_to_first_chunk = Self::final_exponentiation_first_chunk(&value_inv, value);
+        // the result of the easy part is unitary, hence its inverse is its conjugate
+        let value_inv_to_first_chunk = value_to_first_chunk.unitary_inverse();
         // "hard part"
         Self::final_exponentiation_last_chunk(&value_to_first_chunk, &value_inv_to_first_chunk)
     }
@@ -310,11 +330,8 @@ impl<P: MNT6Parameters> PairingEngine for MNT6p<P>
                 ),
             >,
     {
-        let mut result = Self::Fqk::one();
-        for &(ref p, ref q) in i {
-            result *= &Self::ate_miller_loop(p, q);
-        }
-        result
+        let pairs = i.into_iter().map(|&(p, q)| (p, q)).collect::<Vec<_>>();
+        Self::ate_multi_miller_loop(&pairs)
     }
 
     fn final_exponentiation(r: &Self::Fqk) -> Option<Self::Fqk> {
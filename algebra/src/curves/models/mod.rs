@@ -1,4 +1,7 @@
-use crate::{biginteger::BigInteger, fields::{Field, PrimeField, SquareRootField}};
+use crate::{
+    biginteger::{arithmetic::mac_with_carry, BigInteger},
+    fields::{Field, FpParameters, PrimeField, SquareRootField},
+};
 
 pub mod bls12;
 pub mod mnt4;
@@ -63,6 +66,106 @@ pub trait SWModelParameters: ModelParameters {
     }
 }
 
+/// Parameters of a GLV endomorphism `phi(x, y) = (BETA * x, y)` of a short
+/// Weierstrass curve with `a = 0`, which acts as multiplication by `LAMBDA` on the
+/// prime order subgroup. A scalar `k` can then be split into `k1 + k2 * LAMBDA`
+/// with `k1, k2` of half the length, see `glv_decompose`.
+///
+/// The MNT curves have no such endomorphism, as their CM discriminant is large.
+pub trait GLVParameters: SWModelParameters {
+    /// A primitive cube root of unity in the base field.
+    const BETA: Self::BaseField;
+
+    /// The primitive cube root of unity in the scalar field belonging to `BETA`.
+    const LAMBDA: Self::ScalarField;
+
+    /// A reduced basis `v1 = (a1, b1)`, `v2 = (a2, b2)` of the lattice of all `(a, b)`
+    /// with `a + b * LAMBDA = 0 mod r`, such that `a1 * b2 - a2 * b1 = r`. Entries
+    /// are given by their absolute value and whether they are negative.
+    const V1: (
+        (<Self::ScalarField as PrimeField>::BigInt, bool),
+        (<Self::ScalarField as PrimeField>::BigInt, bool),
+    );
+    const V2: (
+        (<Self::ScalarField as PrimeField>::BigInt, bool),
+        (<Self::ScalarField as PrimeField>::BigInt, bool),
+    );
+
+    /// `round(2^n * b2 / r)` and `round(-2^n * b1 / r)`, where `n` is the bit size
+    /// of the scalar representation, as absolute values and signs.
+    const G1: (<Self::ScalarField as PrimeField>::BigInt, bool);
+    const G2: (<Self::ScalarField as PrimeField>::BigInt, bool);
+
+    /// Returns `(k1, k2, sign1, sign2)` such that `k = (-1)^sign1 * k1 +
+    /// (-1)^sign2 * k2 * LAMBDA mod r`, by rounding `(k, 0)` to a close vector of
+    /// the lattice spanned by `V1, V2`. For a reduced basis, `k1, k2 < sqrt(r)`.
+    fn glv_decompose(
+        k: &Self::ScalarField,
+    ) -> (
+        <Self::ScalarField as PrimeField>::BigInt,
+        <Self::ScalarField as PrimeField>::BigInt,
+        bool,
+        bool,
+    ) {
+        let repr = k.into_repr();
+        // c1 = round(k * b2 / r), c2 = round(-k * b1 / r)
+        let c1 = mul_shift_round(&repr, &Self::G1.0);
+        let c2 = mul_shift_round(&repr, &Self::G2.0);
+        let c1 = signed::<Self::ScalarField>(&(c1, Self::G1.1));
+        let c2 = signed::<Self::ScalarField>(&(c2, Self::G2.1));
+
+        let (a1, b1) = Self::V1;
+        let (a2, b2) = Self::V2;
+        let (a1, b1) = (signed::<Self::ScalarField>(&a1), signed::<Self::ScalarField>(&b1));
+        let (a2, b2) = (signed::<Self::ScalarField>(&a2), signed::<Self::ScalarField>(&b2));
+
+        // (k1, k2) = (k, 0) - c1 * v1 - c2 * v2
+        let k1 = *k - &(c1 * &a1) - &(c2 * &a2);
+        let k2 = -(c1 * &b1 + &(c2 * &b2));
+
+        let (k1, sign1) = unsigned(k1);
+        let (k2, sign2) = unsigned(k2);
+        (k1, k2, sign1, sign2)
+    }
+}
+
+/// Returns the field element of a signed integer given as absolute value and sign.
+fn signed<F: PrimeField>(x: &(F::BigInt, bool)) -> F {
+    let res = F::from_repr(x.0);
+    if x.1 { -res } else { res }
+}
+
+/// Returns the absolute value and sign of `x`, as an integer in `(-r/2, r/2)`.
+fn unsigned<F: PrimeField>(x: F) -> (F::BigInt, bool) {
+    let repr = x.into_repr();
+    if repr > F::Params::MODULUS_MINUS_ONE_DIV_TWO {
+        ((-x).into_repr(), true)
+    } else {
+        (repr, false)
+    }
+}
+
+/// Returns `round(a * b / 2^n)`, where `n` is the bit size of the representation.
+/// The result must fit into the representation.
+fn mul_shift_round<B: BigInteger>(a: &B, b: &B) -> B {
+    let n = a.as_ref().len();
+    let mut prod = vec![0u64; 2 * n];
+    for (i, &x) in a.as_ref().iter().enumerate() {
+        let mut carry = 0;
+        for (j, &y) in b.as_ref().iter().enumerate() {
+            prod[i + j] = mac_with_carry(prod[i + j], x, y, &mut carry);
+        }
+        prod[i + n] = carry;
+    }
+
+    let mut res = B::default();
+    res.as_mut().copy_from_slice(&prod[n..]);
+    if prod[n - 1] >> 63 == 1 {
+        res.add_nocarry(&B::from(1));
+    }
+    res
+}
+
 pub trait TEModelParameters: ModelParameters {
     const COEFF_A: Self::BaseField;
     const COEFF_D: Self::BaseField;
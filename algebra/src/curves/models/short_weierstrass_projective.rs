@@ -1,18 +1,20 @@
+use alloc::{boxed::Box, vec::Vec};
 use rand::{Rng, distributions::{Standard, Distribution}};
 use crate::{UniformRand, ToCompressedBits, FromCompressedBits, Error, BitSerializationError};
 use crate::curves::models::SWModelParameters as Parameters;
-use std::{
+use core::{
     fmt::{Display, Formatter, Result as FmtResult},
-    io::{Read, Result as IoResult, Write},
+    iter::Sum,
     marker::PhantomData,
 };
+use crate::io::{self, Read, Result as IoResult, Write};
 
 use crate::{
     bytes::{FromBytes, ToBytes},
     curves::{AffineCurve, ProjectiveCurve},
     fields::{BitIterator, Field, PrimeField, SquareRootField},
 };
-use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 #[derive(Derivative)]
 #[derivative(
@@ -114,11 +116,52 @@ impl<P: Parameters> GroupAffine<P> {
         }
     }
 
+    /// Checks that `[r] * self` is the neutral element, `r` being the order of the
+    /// prime order subgroup. If the cofactor is one (e.g. for the G1 of the MNT curves)
+    /// every point of the curve belongs to the subgroup and no scalar multiplication
+    /// is needed. An empty `COFACTOR` is not taken as one.
     #[inline]
     pub fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool {
+        let cofactor_is_one = match P::COFACTOR.split_first() {
+            Some((&1, higher_limbs)) => higher_limbs.iter().all(|&limb| limb == 0),
+            _ => false,
+        };
+        if cofactor_is_one {
+            return true;
+        }
         self.mul_bits(BitIterator::new(P::ScalarField::characteristic()))
             .is_zero()
     }
+
+    /// Reads a point as `FromBytes::read` does, additionally checking that it is on
+    /// the curve and in the prime order subgroup. Points coming from untrusted sources
+    /// should be deserialized this way.
+    pub fn deserialize_checked<R: Read>(reader: R) -> IoResult<Self> {
+        let p = Self::read(reader)?;
+        if !p.is_on_curve() {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "point is not on the curve"));
+        }
+        if !p.is_in_correct_subgroup_assuming_on_curve() {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "point is not in the prime order subgroup"));
+        }
+        Ok(p)
+    }
+
+    /// Decompresses a batch of points produced by `ToCompressedBits::compress`,
+    /// performing the same checks as `FromCompressedBits::decompress` on each of them.
+    /// If some entry is invalid, the index of the first invalid one is returned
+    /// together with its error.
+    ///
+    /// Affine decompression does not need any inversion, and the square roots
+    /// can't be shared among different x-coordinates, so the points are decompressed
+    /// one by one, stopping at the first invalid entry.
+    pub fn from_compressed_batch(compressed: &[Vec<bool>]) -> Result<Vec<Self>, (usize, Error)> {
+        compressed
+            .iter()
+            .enumerate()
+            .map(|(i, c)| Self::decompress(c.clone()).map_err(|e| (i, e)))
+            .collect()
+    }
 }
 
 impl<P: Parameters> AffineCurve for GroupAffine<P> {
@@ -631,6 +674,18 @@ impl<'a, P: Parameters> SubAssign<&'a Self> for GroupProjective<P> {
     }
 }
 
+impl<P: Parameters> Sum<Self> for GroupProjective<P> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, x| acc + &x)
+    }
+}
+
+impl<'a, P: Parameters> Sum<&'a Self> for GroupProjective<P> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, x| acc + x)
+    }
+}
+
 impl<'a, P: Parameters> Mul<&'a P::ScalarField> for GroupProjective<P> {
     type Output = Self;
     fn mul(self, other: &'a P::ScalarField) -> Self {
@@ -1,11 +1,13 @@
+use alloc::vec::Vec;
 use rand::{Rng, distributions::{Standard, Distribution}};
 use crate::UniformRand;
-use std::{
+use core::{
     fmt::{Display, Formatter, Result as FmtResult},
-    io::{Read, Result as IoResult, Write},
+    iter::Sum,
     marker::PhantomData,
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
+use crate::io::{Read, Result as IoResult, Write};
 
 use crate::{
     bytes::{FromBytes, ToBytes},
@@ -605,6 +607,18 @@ impl<'a, P: Parameters> SubAssign<&'a Self> for GroupProjective<P> {
     }
 }
 
+impl<P: Parameters> Sum<Self> for GroupProjective<P> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, x| acc + &x)
+    }
+}
+
+impl<'a, P: Parameters> Sum<&'a Self> for GroupProjective<P> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, x| acc + x)
+    }
+}
+
 impl<'a, P: Parameters> Mul<&'a P::ScalarField> for GroupProjective<P> {
     type Output = Self;
     fn mul(self, other: &'a P::ScalarField) -> Self {
@@ -1,6 +1,7 @@
 use crate::{
+    bytes::{FromBytes, ToBytes},
     curves::{AffineCurve, ProjectiveCurve},
-    fields::{Field, PrimeField},
+    fields::{Field, FpParameters, PrimeField},
 };
 use crate::UniformRand;
 use rand::SeedableRng;
@@ -221,6 +222,126 @@ fn random_transformation_test<G: ProjectiveCurve>() {
     }
 }
 
+fn random_batch_serialization_test<G: ProjectiveCurve>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let mut points = (0..ITERATIONS)
+        .map(|_| G::rand(&mut rng).into_affine())
+        .collect::<Vec<_>>();
+    points[0] = G::Affine::zero();
+    let point_size = to_bytes!(points[1]).unwrap().len();
+
+    let mut serialized = vec![];
+    G::Affine::write_batch(&points, &mut serialized).unwrap();
+    assert_eq!(serialized.len(), 8 + ITERATIONS * point_size);
+    assert_eq!(G::Affine::read_batch(serialized.as_slice(), ITERATIONS).unwrap(), points);
+    assert!(G::Affine::read_batch(serialized.as_slice(), ITERATIONS - 1).is_err());
+    assert!(G::Affine::read_batch(&serialized[..serialized.len() - 1], ITERATIONS).is_err());
+}
+
+fn random_sum_test<G: ProjectiveCurve>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let points = (0..ITERATIONS).map(|_| G::rand(&mut rng)).collect::<Vec<_>>();
+    let expected = points.iter().fold(G::zero(), |acc, p| acc + p);
+
+    assert_eq!(points.iter().sum::<G>(), expected);
+    assert_eq!(points.iter().cloned().sum::<G>(), expected);
+    assert!(points[..0].iter().sum::<G>().is_zero());
+    assert_eq!(points[..1].iter().sum::<G>(), points[0]);
+}
+
+/// Checks that the fixed generator has the order of the scalar field, and that
+/// the affine and projective identities agree.
+pub fn generator_order_test<G: ProjectiveCurve>() {
+    let generator = G::prime_subgroup_generator();
+    let generator_affine = G::Affine::prime_subgroup_generator();
+    assert!(!generator.is_zero());
+    assert!(!generator_affine.is_zero());
+    assert_eq!(generator.into_affine(), generator_affine);
+
+    // Identity in both representations.
+    assert!(G::zero().is_zero());
+    assert!(G::Affine::zero().is_zero());
+    assert!(G::zero().into_affine().is_zero());
+    assert!(G::Affine::zero().into_projective().is_zero());
+    assert_eq!(generator + &G::zero(), generator);
+
+    // [r]G == 0 and [r - 1]G == -G.
+    let order = <G::ScalarField as PrimeField>::Params::MODULUS;
+    let mut g = generator;
+    g.mul_assign(order);
+    assert!(g.is_zero());
+    assert!(generator_affine.mul(order).is_zero());
+
+    let minus_one = -G::ScalarField::one();
+    let mut g = generator;
+    g.mul_assign(minus_one);
+    assert_eq!(g, -generator);
+}
+
+/// Returns the final exponent `(q^k - 1)/r` of a pairing with embedding degree `k`,
+/// as little-endian limbs, where `q` and `r` are the moduli of `Fq` and `Fr`. It is
+/// computed by schoolbook multiplication and binary long division, independently of
+/// the decomposition of the exponent used by the final exponentiation.
+pub fn final_exponent<Fq: PrimeField, Fr: PrimeField>(k: usize) -> Vec<u64> {
+    let q = Fq::Params::MODULUS;
+    let q = q.as_ref();
+    let r = Fr::Params::MODULUS;
+    let r = r.as_ref();
+
+    let mut n = vec![1u64];
+    for _ in 0..k {
+        let mut product = vec![0u64; n.len() + q.len()];
+        for (i, a) in n.iter().enumerate() {
+            let mut carry = 0u128;
+            for (j, b) in q.iter().enumerate() {
+                let t = product[i + j] as u128 + (*a as u128) * (*b as u128) + carry;
+                product[i + j] = t as u64;
+                carry = t >> 64;
+            }
+            product[i + q.len()] = carry as u64;
+        }
+        n = product;
+    }
+    // q is odd, hence so is q^k
+    n[0] -= 1;
+
+    let mut quotient = vec![0u64; n.len()];
+    let mut remainder = vec![0u64; r.len() + 1];
+    for i in (0..64 * n.len()).rev() {
+        // remainder = 2 * remainder + (i-th bit of n)
+        let mut carry = (n[i / 64] >> (i % 64)) & 1;
+        for limb in remainder.iter_mut() {
+            let next_carry = *limb >> 63;
+            *limb = (*limb << 1) | carry;
+            carry = next_carry;
+        }
+        let r_limb = |j: usize| if j < r.len() { r[j] } else { 0 };
+        let geq = (0..remainder.len())
+            .rev()
+            .find(|&j| remainder[j] != r_limb(j))
+            .map_or(true, |j| remainder[j] > r_limb(j));
+        if geq {
+            let mut borrow = 0u64;
+            for (j, limb) in remainder.iter_mut().enumerate() {
+                let (t, b1) = limb.overflowing_sub(r_limb(j));
+                let (t, b2) = t.overflowing_sub(borrow);
+                *limb = t;
+                borrow = (b1 || b2) as u64;
+            }
+            quotient[i / 64] |= 1 << (i % 64);
+        }
+    }
+    // r divides q^k - 1
+    assert!(remainder.iter().all(|limb| *limb == 0));
+
+    while quotient.last() == Some(&0) {
+        quotient.pop();
+    }
+    quotient
+}
+
 pub fn curve_tests<G: ProjectiveCurve>() {
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
 
@@ -279,4 +400,6 @@ pub fn curve_tests<G: ProjectiveCurve>() {
     random_doubling_test::<G>();
     random_negation_test::<G>();
     random_transformation_test::<G>();
+    random_batch_serialization_test::<G>();
+    random_sum_test::<G>();
 }
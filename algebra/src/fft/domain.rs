@@ -12,6 +12,7 @@
 
 use std::fmt;
 use crate::{FpParameters, PrimeField};
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 use rand::Rng;
 
@@ -46,6 +47,7 @@ impl<F: PrimeField> fmt::Debug for EvaluationDomain<F> {
 }
 
 impl<F: PrimeField> EvaluationDomain<F> {
+    #[cfg(feature = "parallel")]
     fn calculate_chunk_size(size: usize) -> usize {
         size / rayon::current_num_threads()
     }
@@ -67,16 +69,13 @@ impl<F: PrimeField> EvaluationDomain<F> {
         let size = num_coeffs.next_power_of_two() as u64;
         let log_size_of_group = size.trailing_zeros();
 
-        if log_size_of_group >= F::Params::TWO_ADICITY {
+        if log_size_of_group > F::Params::TWO_ADICITY {
             return None;
         }
 
         // Compute the generator for the multiplicative subgroup.
         // It should be 2^(log_size_of_group) root of unity.
-        let mut group_gen = F::root_of_unity();
-        for _ in log_size_of_group..F::Params::TWO_ADICITY {
-            group_gen.square_in_place();
-        }
+        let group_gen = F::get_root_of_unity(size as usize)?;
 
         let size_as_bigint = F::BigInt::from(size);
         let size_as_field_element = F::from_repr(size_as_bigint);
@@ -97,7 +96,7 @@ impl<F: PrimeField> EvaluationDomain<F> {
     /// having `num_coeffs` coefficients.
     pub fn compute_size_of_domain(num_coeffs: usize) -> Option<usize> {
         let size = num_coeffs.next_power_of_two();
-        if size.trailing_zeros() < F::Params::TWO_ADICITY {
+        if size.trailing_zeros() <= F::Params::TWO_ADICITY {
             Some(size)
         } else {
             None
@@ -134,7 +133,14 @@ impl<F: PrimeField> EvaluationDomain<F> {
     pub fn ifft_in_place(&self, evals: &mut Vec<F>) {
         evals.resize(self.size(), F::zero());
         best_fft(evals, &Worker::new(), self.group_gen_inv, self.log_size_of_group);
-        evals.par_iter_mut().for_each(|val| *val *= &self.size_inv);
+
+        #[cfg(feature = "parallel")]
+        let iter = evals.par_iter_mut();
+
+        #[cfg(not(feature = "parallel"))]
+        let iter = evals.iter_mut();
+
+        iter.for_each(|val| *val *= &self.size_inv);
     }
 
     fn distribute_powers(coeffs: &mut Vec<F>, g: F) {
@@ -211,7 +217,14 @@ impl<F: PrimeField> EvaluationDomain<F> {
             }
 
             batch_inversion(u.as_mut_slice());
-            u.par_iter_mut().zip(ls).for_each(|(tau_minus_r, l)| {
+
+            #[cfg(feature = "parallel")]
+            let iter = u.par_iter_mut();
+
+            #[cfg(not(feature = "parallel"))]
+            let iter = u.iter_mut();
+
+            iter.zip(ls).for_each(|(tau_minus_r, l)| {
                 *tau_minus_r = l * tau_minus_r;
             });
             u
@@ -289,15 +302,23 @@ impl<F: PrimeField> EvaluationDomain<F> {
     pub fn mul_polynomials_in_evaluation_domain(&self, self_evals: &[F], other_evals: &[F]) -> Vec<F> {
         assert_eq!(self_evals.len(), other_evals.len());
         let mut result = self_evals.to_vec();
-        let chunk_size = Self::calculate_chunk_size(self.size());
-        result
-            .par_chunks_mut(chunk_size)
-            .zip(other_evals.par_chunks(chunk_size))
-            .for_each(|(a, b)| {
-                for (a, b) in a.iter_mut().zip(b) {
-                    *a *= b;
-                }
-            });
+
+        #[cfg(feature = "parallel")]
+        {
+            let chunk_size = Self::calculate_chunk_size(self.size());
+            result
+                .par_chunks_mut(chunk_size)
+                .zip(other_evals.par_chunks(chunk_size))
+                .for_each(|(a, b)| {
+                    for (a, b) in a.iter_mut().zip(b) {
+                        *a *= b;
+                    }
+                });
+        }
+
+        #[cfg(not(feature = "parallel"))]
+        result.iter_mut().zip(other_evals).for_each(|(a, b)| *a *= b);
+
         result
     }
 }
@@ -305,16 +326,21 @@ impl<F: PrimeField> EvaluationDomain<F> {
 fn best_fft<F: PrimeField>(a: &mut [F], worker: &Worker, omega: F, log_n: u32) {
     let log_cpus = worker.log_num_cpus();
 
-    if log_n <= log_cpus {
+    // A single thread gains nothing from splitting into sub-FFTs
+    if log_n <= log_cpus || log_cpus == 0 {
         serial_fft(a, omega, log_n);
     } else {
         parallel_fft(a, worker, omega, log_n, log_cpus);
     }
 }
 
-pub(crate) fn serial_fft<F: PrimeField>(a: &mut [F], omega: F, log_n: u32) {
+/// Permutes `data` in place, moving the element at index `k` to the index obtained
+/// by reversing the `log2(data.len())` lowest bits of `k`, as needed by radix-2 FFTs.
+/// The permutation is an involution. Does nothing if the length of `data` is not a
+/// power of two.
+pub fn bit_reverse_permute<T>(data: &mut [T]) {
     #[inline]
-    fn bitreverse(mut n: u32, l: u32) -> u32 {
+    fn bitreverse(mut n: usize, l: u32) -> usize {
         let mut r = 0;
         for _ in 0..l {
             r = (r << 1) | (n & 1);
@@ -323,15 +349,25 @@ pub(crate) fn serial_fft<F: PrimeField>(a: &mut [F], omega: F, log_n: u32) {
         r
     }
 
-    let n = a.len() as u32;
-    assert_eq!(n, 1 << log_n);
+    let n = data.len();
+    if !n.is_power_of_two() {
+        return;
+    }
+    let log_n = n.trailing_zeros();
 
     for k in 0..n {
         let rk = bitreverse(k, log_n);
         if k < rk {
-            a.swap(rk as usize, k as usize);
+            data.swap(rk, k);
         }
     }
+}
+
+pub(crate) fn serial_fft<F: PrimeField>(a: &mut [F], omega: F, log_n: u32) {
+    let n = a.len() as u32;
+    assert_eq!(n, 1 << log_n);
+
+    bit_reverse_permute(a);
 
     let mut m = 1;
     for _ in 0..log_n {
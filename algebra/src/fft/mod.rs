@@ -4,7 +4,7 @@ pub mod polynomial;
 
 pub(crate) mod multicore;
 
-pub use domain::EvaluationDomain;
+pub use domain::{bit_reverse_permute, EvaluationDomain};
 pub use evaluations::Evaluations;
 pub use polynomial::{DensePolynomial, SparsePolynomial, DenseOrSparsePolynomial};
 
@@ -1,7 +1,12 @@
 //! This is an interface for dealing with the kinds of
 //! parallel computations involved in `snark`. It's
-//! currently just a thin wrapper around `rayon`.
+//! currently just a thin wrapper around `rayon`. Without the
+//! `parallel` feature, the work is carried out serially in the
+//! calling thread, through the same interface.
+#[cfg(feature = "parallel")]
 use rayon::{self, Scope};
+#[cfg(not(feature = "parallel"))]
+use std::marker::PhantomData;
 
 #[derive(Copy, Clone)]
 pub(crate) struct Worker {
@@ -9,28 +14,63 @@ pub(crate) struct Worker {
 }
 
 impl Worker {
+    #[cfg(feature = "parallel")]
     pub(crate) fn new() -> Worker {
         let cpus = rayon::current_num_threads();
         Self { cpus }
     }
 
+    #[cfg(not(feature = "parallel"))]
+    pub(crate) fn new() -> Worker {
+        Self { cpus: 1 }
+    }
+
     pub(crate) fn log_num_cpus(&self) -> u32 {
         log2_floor(self.cpus)
     }
 
+    fn chunk_size(&self, elements: usize) -> usize {
+        if elements < self.cpus {
+            1
+        } else {
+            elements / self.cpus
+        }
+    }
+
+    #[cfg(feature = "parallel")]
     pub(crate) fn scope<'a, F, R>(&self, elements: usize, f: F) -> R
     where
         F: 'a + Send + FnOnce(&Scope<'a>, usize) -> R,
         R: Send,
     {
-        let chunk_size = if elements < self.cpus {
-            1
-        } else {
-            elements / self.cpus
-        };
-
+        let chunk_size = self.chunk_size(elements);
         rayon::scope(move |scope| f(scope, chunk_size))
     }
+
+    #[cfg(not(feature = "parallel"))]
+    pub(crate) fn scope<'a, F, R>(&self, elements: usize, f: F) -> R
+    where
+        F: 'a + FnOnce(&Scope<'a>, usize) -> R,
+    {
+        let chunk_size = self.chunk_size(elements);
+        f(&Scope { _marker: PhantomData }, chunk_size)
+    }
+}
+
+/// Serial counterpart of `rayon::Scope`: spawned jobs are run immediately.
+#[cfg(not(feature = "parallel"))]
+pub(crate) struct Scope<'a> {
+    _marker: PhantomData<&'a ()>,
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<'a> Scope<'a> {
+    pub(crate) fn spawn<F>(&self, f: F)
+    where
+        F: 'a + FnOnce(&Scope<'a>),
+    {
+        f(self)
+    }
 }
 
 pub(crate) fn log2_floor(num: usize) -> u32 {
@@ -6,6 +6,7 @@ use std::ops::{Add, AddAssign, Deref, DerefMut, Div, Mul, Neg, Sub, SubAssign};
 use crate::{Field, PrimeField};
 use crate::{Evaluations, EvaluationDomain, DenseOrSparsePolynomial};
 use rand::Rng;
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
 /// Stores a polynomial in coefficient form.
@@ -61,15 +62,22 @@ impl<F: Field> DensePolynomial<F> {
     }
 
     /// Constructs a new polynomial from a list of coefficients.
-    pub fn from_coefficients_vec(mut coeffs: Vec<F>) -> Self {
-        // While there are zeros at the end of the coefficient vector, pop them off.
-        while coeffs.last().map_or(false, |c| c.is_zero()) {
-            coeffs.pop();
-        }
+    pub fn from_coefficients_vec(coeffs: Vec<F>) -> Self {
+        let mut result = Self { coeffs };
+        result.truncate_leading_zeros();
         // Check that either the coefficients vec is empty or that the last coeff is non-zero.
-        assert!(coeffs.last().map_or(true, |coeff| !coeff.is_zero()));
+        assert!(result.coeffs.last().map_or(true, |coeff| !coeff.is_zero()));
 
-        Self { coeffs }
+        result
+    }
+
+    /// Pops off the zero coefficients at the end of the coefficient vector,
+    /// so that `degree()` stays accurate after operations that may cancel
+    /// the leading terms.
+    fn truncate_leading_zeros(&mut self) {
+        while self.coeffs.last().map_or(false, |c| c.is_zero()) {
+            self.coeffs.pop();
+        }
     }
 
     /// Returns the degree of the polynomial.
@@ -94,12 +102,14 @@ impl<F: Field> DensePolynomial<F> {
             cur *= &point;
         }
         assert_eq!(powers_of_point.len(), self.coeffs.len());
-        let zero = F::zero();
-        powers_of_point
-            .into_par_iter()
-            .zip(&self.coeffs)
-            .map(|(power, coeff)| power * coeff)
-            .reduce(|| zero, |a, b| a + &b)
+
+        #[cfg(feature = "parallel")]
+        let iter = powers_of_point.into_par_iter();
+
+        #[cfg(not(feature = "parallel"))]
+        let iter = powers_of_point.into_iter();
+
+        iter.zip(&self.coeffs).map(|(power, coeff)| power * coeff).sum()
     }
 
     /// Perform a naive n^2 multiplicatoin of `self` by `other`.
@@ -134,7 +144,14 @@ impl<F: PrimeField> DensePolynomial<F> {
     pub fn mul_by_vanishing_poly(&self, domain: EvaluationDomain<F>) -> DensePolynomial<F> {
         let mut shifted = vec![F::zero(); domain.size()];
         shifted.extend_from_slice(&self.coeffs);
-        shifted.par_iter_mut().zip(&self.coeffs).for_each(|(s, c)| *s -= c);
+
+        #[cfg(feature = "parallel")]
+        let iter = shifted.par_iter_mut();
+
+        #[cfg(not(feature = "parallel"))]
+        let iter = shifted.iter_mut();
+
+        iter.zip(&self.coeffs).for_each(|(s, c)| *s -= c);
         DensePolynomial::from_coefficients_vec(shifted)
     }
 
@@ -156,7 +173,7 @@ impl<'a, 'b, F: Field> Add<&'a DensePolynomial<F>> for &'b DensePolynomial<F> {
         } else if other.is_zero() {
             self.clone()
         } else {
-            if self.degree() >= other.degree() {
+            let mut result = if self.degree() >= other.degree() {
                 let mut result = self.clone();
                 for (a, b) in result.coeffs.iter_mut().zip(&other.coeffs) {
                     *a += b
@@ -167,12 +184,11 @@ impl<'a, 'b, F: Field> Add<&'a DensePolynomial<F>> for &'b DensePolynomial<F> {
                 for (a, b) in result.coeffs.iter_mut().zip(&self.coeffs) {
                     *a += b
                 }
-                // If the leading coefficient ends up being zero, pop it off.
-                while result.coeffs.last().unwrap().is_zero() {
-                    result.coeffs.pop();
-                }
                 result
-            }
+            };
+            // If the leading coefficients end up being zero, pop them off.
+            result.truncate_leading_zeros();
+            result
         }
     }
 }
@@ -195,11 +211,9 @@ impl<'a, 'b, F: Field> AddAssign<&'a DensePolynomial<F>> for DensePolynomial<F>
                 for (a, b) in self.coeffs.iter_mut().zip(&other.coeffs) {
                     *a += b
                 }
-                // If the leading coefficient ends up being zero, pop it off.
-                while self.coeffs.last().unwrap().is_zero() {
-                    self.coeffs.pop();
-                }
             }
+            // If the leading coefficients end up being zero, pop them off.
+            self.truncate_leading_zeros();
         }
     }
 }
@@ -223,11 +237,9 @@ impl<'a, 'b, F: Field> AddAssign<(F, &'a DensePolynomial<F>)> for DensePolynomia
                 for (a, b) in self.coeffs.iter_mut().zip(&other.coeffs) {
                     *a += &(f * b);
                 }
-                // If the leading coefficient ends up being zero, pop it off.
-                while self.coeffs.last().unwrap().is_zero() {
-                    self.coeffs.pop();
-                }
             }
+            // If the leading coefficients end up being zero, pop them off.
+            self.truncate_leading_zeros();
         }
     }
 }
@@ -272,27 +284,16 @@ impl<'a, 'b, F: Field> Sub<&'a DensePolynomial<F>> for &'b DensePolynomial<F> {
         } else if other.is_zero() {
             self.clone()
         } else {
-            if self.degree() >= other.degree() {
-                let mut result = self.clone();
-                for (a, b) in result.coeffs.iter_mut().zip(&other.coeffs) {
-                    *a -= b
-                }
-                result
-            } else {
-                let mut result = self.clone();
+            let mut result = self.clone();
+            if self.degree() < other.degree() {
                 result.coeffs.resize(other.coeffs.len(), F::zero());
-                for (a, b) in result.coeffs.iter_mut().zip(&other.coeffs) {
-                    *a -= b;
-                }
-                if !result.is_zero() {
-                    // If the leading coefficient ends up being zero, pop it off.
-                    while result.coeffs.last().unwrap().is_zero() {
-                        result.coeffs.pop();
-                    }
-                }
-
-                result
             }
+            for (a, b) in result.coeffs.iter_mut().zip(&other.coeffs) {
+                *a -= b;
+            }
+            // If the leading coefficients end up being zero, pop them off.
+            result.truncate_leading_zeros();
+            result
         }
     }
 }
@@ -305,6 +306,7 @@ impl<'a, 'b, F: Field> SubAssign<&'a DensePolynomial<F>> for DensePolynomial<F>
             for (i, coeff) in other.coeffs.iter().enumerate() {
                 self.coeffs[i] -= coeff;
             }
+            self.truncate_leading_zeros();
         } else if other.is_zero() {
             return;
         } else {
@@ -318,11 +320,9 @@ impl<'a, 'b, F: Field> SubAssign<&'a DensePolynomial<F>> for DensePolynomial<F>
                 for (a, b) in self.coeffs.iter_mut().zip(&other.coeffs) {
                     *a -= b
                 }
-                // If the leading coefficient ends up being zero, pop it off.
-                while self.coeffs.last().unwrap().is_zero() {
-                    self.coeffs.pop();
-                }
             }
+            // If the leading coefficients end up being zero, pop them off.
+            self.truncate_leading_zeros();
         }
     }
 }
@@ -338,14 +338,19 @@ impl<'a, 'b, F: Field> Div<&'a DensePolynomial<F>> for &'b DensePolynomial<F> {
     }
 }
 
-/// Performs O(nlogn) multiplication of polynomials if F is smooth.
-impl<'a, 'b, F: PrimeField> Mul<&'a DensePolynomial<F>> for &'b DensePolynomial<F> {
-    type Output = DensePolynomial<F>;
+/// Number of coefficients of the smaller factor below which `Mul` falls back
+/// to schoolbook multiplication instead of going through the FFT.
+pub const DEFAULT_FFT_MUL_THRESHOLD: usize = 16;
 
-    #[inline]
-    fn mul(self, other: &'a DensePolynomial<F>) -> DensePolynomial<F> {
+impl<F: PrimeField> DensePolynomial<F> {
+    /// Multiply `self` by `other`, using schoolbook multiplication if one of the
+    /// factors has less than `threshold` coefficients, and FFT-based multiplication
+    /// otherwise.
+    pub fn mul_with_threshold(&self, other: &Self, threshold: usize) -> Self {
         if self.is_zero() || other.is_zero() {
             DensePolynomial::zero()
+        } else if std::cmp::min(self.coeffs.len(), other.coeffs.len()) < threshold {
+            self.naive_mul(other)
         } else {
             let domain = EvaluationDomain::new(self.coeffs.len() + other.coeffs.len()).expect("field is not smooth enough to construct domain");
             let mut self_evals = self.evaluate_over_domain_by_ref(domain);
@@ -356,6 +361,17 @@ impl<'a, 'b, F: PrimeField> Mul<&'a DensePolynomial<F>> for &'b DensePolynomial<
     }
 }
 
+/// Performs O(nlogn) multiplication of polynomials if F is smooth, and
+/// schoolbook multiplication for small polynomials.
+impl<'a, 'b, F: PrimeField> Mul<&'a DensePolynomial<F>> for &'b DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    #[inline]
+    fn mul(self, other: &'a DensePolynomial<F>) -> DensePolynomial<F> {
+        self.mul_with_threshold(other, DEFAULT_FFT_MUL_THRESHOLD)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::polynomial::*;
@@ -479,6 +495,78 @@ mod tests {
         }
     }
 
+    #[test]
+    fn mul_polynomials_threshold() {
+        let rng = &mut thread_rng();
+        for a_degree in 0..40 {
+            for b_degree in 0..40 {
+                let a = DensePolynomial::<Fr>::rand(a_degree, rng);
+                let b = DensePolynomial::<Fr>::rand(b_degree, rng);
+                let fft = a.mul_with_threshold(&b, 0);
+                let schoolbook = a.mul_with_threshold(&b, usize::max_value());
+                assert_eq!(fft, schoolbook);
+                assert_eq!(fft.degree(), a_degree + b_degree);
+            }
+        }
+    }
+
+    #[test]
+    fn mul_polynomials_distributive() {
+        let rng = &mut thread_rng();
+        for a_degree in (0..70).step_by(7) {
+            for b_degree in (0..70).step_by(5) {
+                let a = DensePolynomial::<Fr>::rand(a_degree, rng);
+                let b = DensePolynomial::<Fr>::rand(b_degree, rng);
+                let c = DensePolynomial::<Fr>::rand(b_degree, rng);
+                assert_eq!(&a * &(&b + &c), &(&a * &b) + &(&a * &c));
+                assert_eq!(&a * &(&b - &c), &(&a * &b) - &(&a * &c));
+            }
+        }
+    }
+
+    #[test]
+    fn leading_zeros_are_normalized() {
+        let rng = &mut thread_rng();
+        for degree in 1..20 {
+            let p = DensePolynomial::<Fr>::rand(degree, rng);
+            let mut q = -p.clone();
+            q.coeffs[0] += &Fr::one();
+
+            let sum = &p + &q;
+            assert_eq!(sum.degree(), 0);
+            assert_eq!(sum.coeffs, vec![Fr::one()]);
+
+            let diff = &p - &p;
+            assert!(diff.coeffs.is_empty());
+
+            let mut acc = p.clone();
+            acc += &q;
+            assert_eq!(acc, sum);
+
+            let mut acc = p.clone();
+            acc -= &p;
+            assert!(acc.coeffs.is_empty());
+        }
+    }
+
+    #[test]
+    fn divide_by_vanishing_poly_exact() {
+        let rng = &mut thread_rng();
+        for size in 1..8 {
+            let domain = EvaluationDomain::new(1 << size).unwrap();
+            for degree in 0..40 {
+                let q = DensePolynomial::<Fr>::rand(degree, rng);
+                let p = q.mul_by_vanishing_poly(domain);
+                for x in domain.elements() {
+                    assert!(p.evaluate(x).is_zero());
+                }
+                let (quotient, remainder) = p.divide_by_vanishing_poly(domain).unwrap();
+                assert_eq!(quotient, q);
+                assert!(remainder.is_zero());
+            }
+        }
+    }
+
     #[test]
     fn mul_by_vanishing_poly() {
         let rng = &mut thread_rng();
@@ -10,7 +10,7 @@ use crate::{Evaluations, EvaluationDomain};
 mod dense;
 mod sparse;
 
-pub use dense::DensePolynomial;
+pub use dense::{DensePolynomial, DEFAULT_FFT_MUL_THRESHOLD};
 pub use sparse::SparsePolynomial;
 
 /// Represents either a sparse polynomial or a dense one.
@@ -1,4 +1,4 @@
-use crate::UniformRand;
+use crate::{Field, FpParameters, PrimeField, UniformRand};
 use crate::curves::{bls12_381::Bls12_381, PairingEngine};
 use crate::{domain::*, multicore::*};
 use rand;
@@ -42,6 +42,55 @@ fn fft_composition() {
     test_fft_composition::<Bls12_381, _>(rng);
 }
 
+// Test that (coset) FFTs of non-power-of-two sized inputs agree with
+// naive Horner evaluation over the (coset) domain.
+#[test]
+fn fft_naive_evaluation() {
+    fn horner<F: Field>(coeffs: &[F], point: F) -> F {
+        coeffs.iter().rev().fold(F::zero(), |acc, c| acc * &point + c)
+    }
+
+    fn test_fft_naive_evaluation<E: PairingEngine, R: rand::Rng>(rng: &mut R) {
+        for num_coeffs in 1..40 {
+            let coeffs = (0..num_coeffs).map(|_| E::Fr::rand(rng)).collect::<Vec<_>>();
+            let domain = EvaluationDomain::<E::Fr>::new(num_coeffs).unwrap();
+            assert!(domain.size() >= num_coeffs);
+            assert!(domain.size().is_power_of_two());
+
+            let evals = domain.fft(&coeffs);
+            let coset_evals = domain.coset_fft(&coeffs);
+            assert_eq!(evals.len(), domain.size());
+            assert_eq!(coset_evals.len(), domain.size());
+
+            let g = E::Fr::multiplicative_generator();
+            for (i, x) in domain.elements().enumerate() {
+                assert_eq!(evals[i], horner(&coeffs, x), "fft != naive evaluation");
+                assert_eq!(coset_evals[i], horner(&coeffs, g * &x), "coset_fft != naive evaluation");
+            }
+        }
+    }
+
+    let rng = &mut rand::thread_rng();
+
+    test_fft_naive_evaluation::<Bls12_381, _>(rng);
+}
+
+// The largest supported domain has size 2^TWO_ADICITY; anything larger
+// does not fit into the two-adic subgroup of the field.
+#[test]
+fn domain_size_bounds() {
+    type Fr = <Bls12_381 as PairingEngine>::Fr;
+    let two_adicity = <Fr as PrimeField>::Params::TWO_ADICITY;
+
+    let max_size = 1usize << two_adicity;
+    let domain = EvaluationDomain::<Fr>::new(max_size).unwrap();
+    assert_eq!(domain.size(), max_size);
+    assert_eq!(domain.group_gen, Fr::root_of_unity());
+    assert!(EvaluationDomain::<Fr>::new(max_size + 1).is_none());
+    assert_eq!(EvaluationDomain::<Fr>::compute_size_of_domain(max_size), Some(max_size));
+    assert!(EvaluationDomain::<Fr>::compute_size_of_domain(max_size + 1).is_none());
+}
+
 #[test]
 fn parallel_fft_consistency() {
     fn test_consistency<E: PairingEngine, R: rand::Rng>(rng: &mut R) {
@@ -70,3 +119,63 @@ fn parallel_fft_consistency() {
 
     test_consistency::<Bls12_381, _>(rng);
 }
+
+// The FFTs of the domain, which are split into sub-FFTs when running with the
+// `parallel` feature, must agree with the serial FFT for any number of threads.
+#[test]
+fn serial_parallel_fft_equality() {
+    fn test_equality<E: PairingEngine, R: rand::Rng>(rng: &mut R) {
+        let worker = Worker::new();
+
+        for log_d in 0..12 {
+            let d = 1 << log_d;
+            let coeffs = (0..d).map(|_| E::Fr::rand(rng)).collect::<Vec<_>>();
+            let domain = EvaluationDomain::<E::Fr>::new(d).unwrap();
+
+            let mut serial = coeffs.clone();
+            serial_fft(&mut serial, domain.group_gen, log_d);
+            assert_eq!(domain.fft(&coeffs), serial);
+
+            for log_cpus in 0..=min(log_d, 3) {
+                let mut parallel = coeffs.clone();
+                parallel_fft(&mut parallel, &worker, domain.group_gen, log_d, log_cpus);
+                assert_eq!(parallel, serial);
+            }
+        }
+    }
+
+    let rng = &mut rand::thread_rng();
+
+    test_equality::<Bls12_381, _>(rng);
+}
+
+#[test]
+fn bit_reverse_permutation() {
+    let mut data = [0, 1];
+    bit_reverse_permute(&mut data);
+    assert_eq!(data, [0, 1]);
+
+    let mut data = [0, 1, 2, 3];
+    bit_reverse_permute(&mut data);
+    assert_eq!(data, [0, 2, 1, 3]);
+
+    let mut data = [0, 1, 2, 3, 4, 5, 6, 7];
+    bit_reverse_permute(&mut data);
+    assert_eq!(data, [0, 4, 2, 6, 1, 5, 3, 7]);
+
+    // Applying the permutation twice is the identity
+    for log_n in 0..10 {
+        let original = (0..1usize << log_n).collect::<Vec<_>>();
+        let mut data = original.clone();
+        bit_reverse_permute(&mut data);
+        bit_reverse_permute(&mut data);
+        assert_eq!(data, original);
+    }
+
+    // Lengths which are not a power of two are left untouched
+    let mut data = [0, 1, 2, 3, 4, 5];
+    bit_reverse_permute(&mut data);
+    assert_eq!(data, [0, 1, 2, 3, 4, 5]);
+    let mut data: [u8; 0] = [];
+    bit_reverse_permute(&mut data);
+}
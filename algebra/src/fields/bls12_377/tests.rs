@@ -9,7 +9,7 @@ use crate::{
     ToBits,
 };
 use crate::UniformRand;
-use rand::SeedableRng;
+use rand::{Rng, SeedableRng};
 use rand_xorshift::XorShiftRng;
 use std::{
     cmp::Ordering,
@@ -554,3 +554,187 @@ fn test_fq12_mul_by_034() {
         assert_eq!(a, b);
     }
 }
+
+// Maps a random element into the cyclotomic subgroup by raising it
+// to (q^6 - 1)(q^2 + 1).
+fn random_cyclotomic_element<R: Rng>(rng: &mut R) -> Fq12 {
+    let f = Fq12::rand(rng);
+    let mut f1 = f;
+    f1.conjugate();
+    let mut f2 = f1 * &f.inverse().unwrap();
+    let r = f2;
+    f2.frobenius_map(2);
+    f2 * &r
+}
+
+#[test]
+fn test_fq12_cyclotomic_square() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    assert_eq!(Fq12::one().cyclotomic_square(), Fq12::one());
+
+    for _ in 0..ITERATIONS {
+        let g = random_cyclotomic_element(&mut rng);
+        assert_eq!(g.cyclotomic_square(), g.square());
+        assert_eq!(g.cyclotomic_square(), g * &g);
+
+        // The subgroup is closed under Frobenius and conjugation
+        let mut h = g;
+        h.frobenius_map(1);
+        assert_eq!(h.cyclotomic_square(), h.square());
+        h.conjugate();
+        assert_eq!(h.cyclotomic_square(), h.square());
+
+        // Outside of the cyclotomic subgroup the formula doesn't apply
+        let f = Fq12::rand(&mut rng);
+        assert_ne!(f.cyclotomic_square(), f.square());
+    }
+}
+
+#[test]
+fn test_fq12_cyclotomic_pow2() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let g = random_cyclotomic_element(&mut rng);
+        let mut h = g;
+        for k in 0..10 {
+            assert_eq!(g.cyclotomic_pow2(k), h);
+            assert_eq!(g.cyclotomic_pow2(k), g.pow2(k));
+            h.square_in_place();
+        }
+    }
+}
+
+#[test]
+fn test_fq12_cyclotomic_exp_naf() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    // The curve parameter x and a few random exponents.
+    let mut exponents = vec![0x8508c00000000001u64, 0, 1];
+    exponents.extend((0..ITERATIONS).map(|_| rng.gen::<u64>()));
+
+    for _ in 0..ITERATIONS {
+        let g = random_cyclotomic_element(&mut rng);
+        assert_eq!(g * &g.unitary_inverse(), Fq12::one());
+
+        for e in exponents.iter() {
+            let naf = BigInteger384::from(*e)
+                .find_wnaf()
+                .into_iter()
+                .map(|d| d as i8)
+                .collect::<Vec<_>>();
+            assert_eq!(g.cyclotomic_exp_naf(&naf), g.cyclotomic_exp(&[*e]));
+            assert_eq!(g.cyclotomic_exp_naf(&naf), g.pow(&[*e]));
+        }
+    }
+}
+
+#[test]
+fn test_extension_double_in_place() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..1000 {
+        // Doubling in the quadratic extensions equals the componentwise
+        // doubling of the base field coordinates.
+        let a = Fq2::rand(&mut rng);
+        let mut b = a;
+        b.double_in_place();
+        assert_eq!(b, Fq2::new(a.c0.double(), a.c1.double()));
+        assert_eq!(b, a + &a);
+
+        let a = Fq12::rand(&mut rng);
+        let mut b = a;
+        b.double_in_place();
+        assert_eq!(b, Fq12::new(a.c0.double(), a.c1.double()));
+        assert_eq!(b.c0.c0, Fq2::new(a.c0.c0.c0.double(), a.c0.c0.c1.double()));
+        assert_eq!(b, a + &a);
+    }
+}
+
+#[test]
+fn test_rand_with_trait_object_rng() {
+    use rand::RngCore;
+
+    // `UniformRand::rand` accepts unsized rngs, hence trait objects, and consumes
+    // the same stream as with the concrete rng.
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let mut boxed_rng: Box<dyn RngCore> = Box::new(XorShiftRng::seed_from_u64(1231275789u64));
+
+    for _ in 0..ITERATIONS {
+        assert_eq!(Fq::rand(&mut rng), Fq::rand(boxed_rng.as_mut()));
+        assert_eq!(Fq2::rand(&mut rng), Fq2::rand(boxed_rng.as_mut()));
+        assert_eq!(Fq6::rand(&mut rng), Fq6::rand(&mut *boxed_rng));
+        assert_eq!(Fq12::rand(&mut rng), Fq12::rand(&mut *boxed_rng));
+    }
+    assert_eq!(rng.next_u64(), boxed_rng.next_u64());
+}
+
+#[test]
+fn test_fq6_mul_assign_by_fp2() {
+    use crate::fields::{fp12_2over3over2::Fp12Parameters, bls12_377::Fq12Parameters};
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let a = Fq6::rand(&mut rng);
+        let c = Fq2::rand(&mut rng);
+        let mut b = a;
+        b.mul_assign_by_fp2(&c);
+        assert_eq!(b, Fq6::new(a.c0 * &c, a.c1 * &c, a.c2 * &c));
+        assert_eq!(b, a * &Fq6::new(c, Fq2::zero(), Fq2::zero()));
+
+        // Applying the Frobenius coefficients by hand matches `frobenius_map`
+        let f = Fq12::rand(&mut rng);
+        for power in 0..14 {
+            let mut expected = f;
+            expected.frobenius_map(power);
+
+            let mut c0 = f.c0;
+            let mut c1 = f.c1;
+            c0.frobenius_map(power);
+            c1.frobenius_map(power);
+            c1.mul_assign_by_fp2(&Fq12Parameters::FROBENIUS_COEFF_FP12_C1[power % 12]);
+            assert_eq!(Fq12::new(c0, c1), expected);
+        }
+    }
+}
+
+#[test]
+fn test_fq6_sparse_mul() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let a = Fq6::rand(&mut rng);
+        let c0 = Fq2::rand(&mut rng);
+        let c1 = Fq2::rand(&mut rng);
+
+        let mut b = a;
+        b.mul_by_01(&c0, &c1);
+        assert_eq!(b, a * &Fq6::new(c0, c1, Fq2::zero()));
+
+        let mut b = a;
+        b.mul_by_1(&c1);
+        assert_eq!(b, a * &Fq6::new(Fq2::zero(), c1, Fq2::zero()));
+
+        let fp = Fq::rand(&mut rng);
+        let mut b = a;
+        b.mul_by_fp(&fp);
+        assert_eq!(b, a * &Fq6::new(Fq2::new(fp, Fq::zero()), Fq2::zero(), Fq2::zero()));
+    }
+}
+
+#[test]
+fn test_fq12_unitary_inverse() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let g = random_cyclotomic_element(&mut rng);
+        assert_eq!(g * &g.unitary_inverse(), Fq12::one());
+        assert_eq!(g.unitary_inverse(), g.inverse().unwrap());
+
+        let mut conjugate = g;
+        conjugate.conjugate();
+        assert_eq!(g.unitary_inverse(), conjugate);
+    }
+}
@@ -13,7 +13,7 @@ use crate::{
     ToBits,
 };
 use crate::UniformRand;
-use rand::SeedableRng;
+use rand::{Rng, SeedableRng};
 use rand_xorshift::XorShiftRng;
 use std::{
     cmp::Ordering,
@@ -22,6 +22,21 @@ use std::{
 
 pub(crate) const ITERATIONS: usize = 5;
 
+#[test]
+fn test_bls12_381_fr_field_info() {
+    use crate::fields::{bls12_381::Fr, field_info};
+
+    assert_eq!(
+        field_info::<Fr>().to_string(),
+        "MODULUS = 73EDA753299D7D483339D80809A1D80553BDA402FFFE5BFEFFFFFFFF00000001\n\
+         MODULUS_BITS = 255\n\
+         R = 1824B159ACC5056F998C4FEFECBC4FF55884B7FA0003480200000001FFFFFFFE\n\
+         R2 = 0748D9D99F59FF1105D314967254398F2B6CEDCB87925C23C999E990F3F29C6D\n\
+         INV = 0xfffffffeffffffff\n\
+         TWO_ADICITY = 32"
+    );
+}
+
 #[test]
 fn test_bls12_381_fr() {
     use crate::fields::bls12_381::Fr;
@@ -1355,6 +1370,70 @@ fn test_fq_add_assign() {
     }
 }
 
+#[test]
+fn test_fq_from_random_bytes_mod_order_distribution() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    const SAMPLES: usize = 1 << 14;
+    let num_bytes = (FqParameters::MODULUS_BITS as usize + 128 + 7) / 8;
+    let mut half_modulus = FqParameters::MODULUS;
+    half_modulus.div2();
+
+    let mut below_half = 0usize;
+    let mut buckets = [0usize; 16];
+    for _ in 0..SAMPLES {
+        let bytes = (0..num_bytes).map(|_| rng.gen::<u8>()).collect::<Vec<_>>();
+        let repr = Fq::from_random_bytes_mod_order(&bytes).into_repr();
+        if repr < half_modulus {
+            below_half += 1;
+        }
+        buckets[(repr.0[0] & 15) as usize] += 1;
+    }
+
+    // Binomial(SAMPLES, 1/2) has standard deviation sqrt(SAMPLES)/2 = 64.
+    assert!((below_half as i64 - (SAMPLES / 2) as i64).abs() < 5 * 64);
+
+    // Chi-squared test with 15 degrees of freedom on the low 4 bits
+    // (the 99.9% quantile is about 37.7).
+    let expected = (SAMPLES / 16) as f64;
+    let chi_squared: f64 = buckets
+        .iter()
+        .map(|&c| (c as f64 - expected).powi(2) / expected)
+        .sum();
+    assert!(chi_squared < 37.7, "chi squared = {}", chi_squared);
+}
+
+#[test]
+fn test_fq_add_assign_unreduced() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    // A reduced element plus up to 2^REPR_SHAVE_BITS - 1 reduced summands
+    // fits into the backing BigInteger.
+    let max_summands = (1usize << FqParameters::REPR_SHAVE_BITS) - 1;
+    assert_eq!(max_summands + 1, 8);
+    for num_summands in (1..16).chain(std::iter::once(max_summands)) {
+        let mut reduced = Fq::rand(&mut rng);
+        let mut unreduced = reduced;
+        for _ in 0..num_summands {
+            let b = Fq::rand(&mut rng);
+            reduced.add_assign(&b);
+            unreduced.add_assign_unreduced(&b);
+        }
+        unreduced.reduce_now();
+        assert_eq!(reduced, unreduced);
+    }
+
+    // Worst case, all summands equal to MODULUS - 1
+    let mut reduced = -Fq::one();
+    let mut unreduced = reduced;
+    for _ in 0..max_summands {
+        reduced.add_assign(&-Fq::one());
+        unreduced.add_assign_unreduced(&-Fq::one());
+    }
+    unreduced.reduce_now();
+    assert_eq!(reduced, unreduced);
+}
+
 #[test]
 fn test_fq_sub_assign() {
     {
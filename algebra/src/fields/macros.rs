@@ -16,7 +16,7 @@ macro_rules! impl_prime_field_standard_sample {
                 loop {
                     let mut tmp = $field(rng.sample(rand::distributions::Standard), PhantomData);
                     // Mask away the unused bits at the beginning.
-                    tmp.0.as_mut().last_mut().map(|val| *val &= std::u64::MAX >> P::REPR_SHAVE_BITS);
+                    tmp.0.as_mut().last_mut().map(|val| *val &= core::u64::MAX >> P::REPR_SHAVE_BITS);
 
                     if tmp.is_valid() {
                         return tmp;
@@ -27,6 +27,36 @@ macro_rules! impl_prime_field_standard_sample {
     };
 }
 
+/// Implements `Sum` and `Product`, both for owned and borrowed items, by folding
+/// with the field operations, starting from zero and one respectively.
+macro_rules! impl_field_sum_and_product {
+    ($field: ident, $params: ident) => {
+        impl<P: $params> core::iter::Sum<Self> for $field<P> {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::zero(), |acc, x| acc + &x)
+            }
+        }
+
+        impl<'a, P: $params> core::iter::Sum<&'a Self> for $field<P> {
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.fold(Self::zero(), |acc, x| acc + x)
+            }
+        }
+
+        impl<P: $params> core::iter::Product<Self> for $field<P> {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::one(), |acc, x| acc * &x)
+            }
+        }
+
+        impl<'a, P: $params> core::iter::Product<&'a Self> for $field<P> {
+            fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.fold(Self::one(), |acc, x| acc * x)
+            }
+        }
+    };
+}
+
 macro_rules! impl_prime_field_from_int {
     ($field: ident, u128, $params: ident) => {
         impl<P: $params> From<u128> for $field<P> {
@@ -40,6 +70,22 @@ macro_rules! impl_prime_field_from_int {
             }
         }
     };
+    ($field: ident, i64, $params: ident) => {
+        impl<P: $params> From<i64> for $field<P> {
+            fn from(other: i64) -> Self {
+                // `wrapping_abs` maps i64::MIN to itself, whose cast is its magnitude 2^63
+                let abs = Self::from(other.wrapping_abs() as u64);
+                if other.is_negative() { -abs } else { abs }
+            }
+        }
+    };
+    ($field: ident, i32, $params: ident) => {
+        impl<P: $params> From<i32> for $field<P> {
+            fn from(other: i32) -> Self {
+                Self::from(i64::from(other))
+            }
+        }
+    };
     ($field: ident, $int: ident, $params: ident) => {
         impl<P: $params> From<$int> for $field<P> {
             fn from(other: $int) -> Self {
@@ -52,56 +98,63 @@ macro_rules! impl_prime_field_from_int {
 macro_rules! sqrt_impl {
     ($Self:ident, $P:tt, $self:expr) => {{
         use crate::fields::LegendreSymbol::*;
-        // https://eprint.iacr.org/2012/685.pdf (page 12, algorithm 5)
-        // Actually this is just normal Tonelli-Shanks; since `P::Generator`
-        // is a quadratic non-residue, `P::ROOT_OF_UNITY = P::GENERATOR ^ t`
-        // is also a quadratic non-residue (since `t` is odd).
         match $self.legendre() {
             Zero => Some(*$self),
             QuadraticNonResidue => None,
-            QuadraticResidue => {
-                let mut z = $Self::qnr_to_t();
-                let mut w = $self.pow($P::T_MINUS_ONE_DIV_TWO);
-                let mut x = w * $self;
-                let mut b = x * &w;
+            QuadraticResidue => Some(sqrt_unchecked_impl!($Self, $P, $self)),
+        }
+    }};
+}
 
-                let mut v = $P::TWO_ADICITY as usize;
-                // t = self^t
-                #[cfg(debug_assertions)]
-                {
-                    let mut check = b;
-                    for _ in 0..(v - 1) {
-                        check.square_in_place();
-                    }
-                    if !check.is_one() {
-                        panic!("Input is not a square root, but it passed the QR test")
-                    }
-                }
+/// The Tonelli-Shanks main loop of `sqrt_impl!`, without the Legendre symbol
+/// computation: `$self` must be a quadratic residue (or zero). Otherwise the
+/// order of `b` below is `2^TWO_ADICITY`, which is detected while searching for
+/// it and makes the macro panic.
+macro_rules! sqrt_unchecked_impl {
+    ($Self:ident, $P:tt, $self:expr) => {{
+        // https://eprint.iacr.org/2012/685.pdf (page 12, algorithm 5)
+        // Actually this is just normal Tonelli-Shanks; since `P::Generator`
+        // is a quadratic non-residue, `P::ROOT_OF_UNITY = P::GENERATOR ^ t`
+        // is also a quadratic non-residue (since `t` is odd).
+        if $self.is_zero() {
+            $Self::zero()
+        } else {
+            let mut z = $Self::qnr_to_t();
+            let mut w = $self.pow($P::T_MINUS_ONE_DIV_TWO);
+            let mut x = w * $self;
+            let mut b = x * &w;
 
-                while !b.is_one() {
-                    let mut k = 0usize;
+            let mut v = $P::TWO_ADICITY as usize;
 
-                    let mut b2k = b;
-                    while !b2k.is_one() {
-                        // invariant: b2k = b^(2^k) after entering this loop
-                        b2k.square_in_place();
-                        k += 1;
-                    }
+            // b = self^t has order 2^k with k < v as long as self is a quadratic
+            // residue, and k strictly decreases at each iteration
+            while !b.is_one() {
+                let mut k = 0usize;
 
-                    let j = v - k - 1;
-                    w = z;
-                    for _ in 0..j {
-                        w.square_in_place();
+                let mut b2k = b;
+                while !b2k.is_one() {
+                    // invariant: b2k = b^(2^k) after entering this loop
+                    b2k.square_in_place();
+                    k += 1;
+                    if k == v {
+                        panic!("sqrt_unchecked of a quadratic non-residue")
                     }
+                }
 
-                    z = w.square();
-                    b *= &z;
-                    x *= &w;
-                    v = k;
+                let j = v - k - 1;
+                w = z;
+                for _ in 0..j {
+                    w.square_in_place();
                 }
 
-                Some(x)
-            },
+                z = w.square();
+                b *= &z;
+                x *= &w;
+                v = k;
+            }
+
+            debug_assert_eq!(x.square(), *$self);
+            x
         }
     }};
 }
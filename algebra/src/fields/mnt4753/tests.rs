@@ -1,15 +1,15 @@
 use crate::{
     biginteger::{BigInteger, BigInteger768},
-    fields::tests::{field_test, frobenius_test, primefield_test, sqrt_field_test},
+    fields::tests::{field_test, frobenius_test, known_answer_tests, primefield_test, sqrt_field_test},
     fields::mnt4753::{Fq, FqParameters, Fq2, Fq2Parameters, Fq4, Fq4Parameters, Fr},
     fields::FpParameters,
     fields::models::{fp2::Fp2Parameters, fp4::Fp4Parameters},
     Field, PrimeField, SquareRootField,
-    UniformRand,
+    UniformRand, MulShort,
     bytes::{ToBytes, FromBytes}, to_bytes, ToBits,
 };
 
-use rand::SeedableRng;
+use rand::{Rng, SeedableRng};
 use rand_xorshift::XorShiftRng;
 use std::{
     ops::{AddAssign, MulAssign, SubAssign},
@@ -446,6 +446,37 @@ fn test_fq_add_assign() {
 }
 
 
+#[test]
+fn test_fq_add_assign_unreduced() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    // A reduced element plus up to 2^REPR_SHAVE_BITS - 1 reduced summands
+    // fits into the backing BigInteger.
+    let max_summands = (1usize << FqParameters::REPR_SHAVE_BITS) - 1;
+    assert_eq!(max_summands + 1, 1 << 15);
+    for num_summands in (1..16).chain(std::iter::once(max_summands)) {
+        let mut reduced = Fq::rand(&mut rng);
+        let mut unreduced = reduced;
+        for _ in 0..num_summands {
+            let b = Fq::rand(&mut rng);
+            reduced.add_assign(&b);
+            unreduced.add_assign_unreduced(&b);
+        }
+        unreduced.reduce_now();
+        assert_eq!(reduced, unreduced);
+    }
+
+    // Worst case, all summands equal to MODULUS - 1
+    let mut reduced = -Fq::one();
+    let mut unreduced = reduced;
+    for _ in 0..max_summands {
+        reduced.add_assign(&-Fq::one());
+        unreduced.add_assign_unreduced(&-Fq::one());
+    }
+    unreduced.reduce_now();
+    assert_eq!(reduced, unreduced);
+}
+
 #[test]
 fn test_fq_sub_assign() {
     {
@@ -693,6 +724,38 @@ fn test_fq_mul_assign() {
 }
 
 
+#[test]
+fn test_mul_short() {
+    fn test_mul_short_for<F: PrimeField<BigInt = BigInteger768> + MulShort>(rng: &mut XorShiftRng) {
+        // mul_short(t, b) = t * b * 2^-64, whereas t * b = t * b * 2^-768 on the
+        // internal representations, so the two differ by a factor of 2^704.
+        let two_to_704 = F::from_repr(BigInteger768::from(2)).pow(&[704]);
+        let mut shorts = vec![0u64, 1, u64::max_value()];
+        shorts.extend((0..100).map(|_| rng.gen::<u64>()));
+        for s in shorts {
+            let mut repr = BigInteger768::from(0);
+            repr.0[0] = s;
+            let a = F::from_repr_raw(repr);
+            let b = F::rand(rng);
+            assert_eq!(a.mul_short(&b), a * &b * &two_to_704);
+        }
+    }
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    test_mul_short_for::<Fr>(&mut rng);
+    test_mul_short_for::<Fq>(&mut rng);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "must fit into a single 64-bit limb")]
+fn test_mul_short_out_of_range() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let a: Fr = UniformRand::rand(&mut rng);
+    let b: Fr = UniformRand::rand(&mut rng);
+    let _ = a.mul_short(&b);
+}
+
 #[test]
 fn test_fq_squaring() {
     let mut a = Fq::new(BigInteger768([
@@ -886,6 +949,17 @@ fn test_fq_sqrt() {
     }
 }
 
+#[test]
+#[should_panic(expected = "sqrt_unchecked of a quadratic non-residue")]
+fn test_fq_sqrt_unchecked_non_residue() {
+    use crate::fields::LegendreSymbol;
+
+    // The multiplicative generator is a quadratic non-residue
+    let g = Fq::multiplicative_generator();
+    assert_eq!(g.legendre(), LegendreSymbol::QuadraticNonResidue);
+    let _ = g.sqrt_unchecked();
+}
+
 
 #[test]
 fn test_fq_num_bits() {
@@ -916,6 +990,27 @@ fn test_fq_bytes() {
     assert_eq!(a, a_read);
 }
 
+#[test]
+fn test_serialized_size() {
+    // Usable in a const context to size fixed buffers
+    const FQ_SIZE: usize = Fq::SERIALIZED_SIZE;
+    const FQ4_SIZE: usize = Fq4::SERIALIZED_SIZE;
+    assert_eq!(FQ_SIZE, 96);
+    assert_eq!(Fq2::SERIALIZED_SIZE, 2 * FQ_SIZE);
+    assert_eq!(FQ4_SIZE, 4 * FQ_SIZE);
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let mut fq_buf = [0u8; FQ_SIZE];
+    let mut fq4_buf = [0u8; FQ4_SIZE];
+    let (a, b) = (Fq::rand(&mut rng), Fq4::rand(&mut rng));
+    a.write(&mut fq_buf[..]).unwrap();
+    b.write(&mut fq4_buf[..]).unwrap();
+    assert_eq!(to_bytes!(a).unwrap().len(), FQ_SIZE);
+    assert_eq!(to_bytes!(b).unwrap().len(), FQ4_SIZE);
+    assert_eq!(Fq::read(&fq_buf[..]).unwrap(), a);
+    assert_eq!(Fq4::read(&fq4_buf[..]).unwrap(), b);
+}
+
 #[test]
 fn test_convert_fq_fr() {
     use crate::fields::{
@@ -1885,4 +1980,73 @@ fn test_fq4_mul_by_023() {
 
         assert_eq!(a.mul_by_023(&to_mul), b);
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_fq4_unitary_inverse() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        // a^(q^2 - 1) is unitary
+        let a = Fq4::rand(&mut rng);
+        let mut u = a;
+        u.frobenius_map(2);
+        u *= &a.inverse().unwrap();
+
+        assert_eq!(u * &u.unitary_inverse(), Fq4::one());
+        assert_eq!(u.unitary_inverse(), u.inverse().unwrap());
+        // for non-unitary elements the conjugate is not the inverse
+        let mut conjugate = a;
+        conjugate.conjugate();
+        assert_ne!(conjugate, a.inverse().unwrap());
+    }
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "unitary_inverse of a non-unitary element")]
+fn test_fq4_unitary_inverse_non_unitary() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let _ = Fq4::rand(&mut rng).unitary_inverse();
+}
+
+#[test]
+fn test_mnt4753_fq_known_answers() {
+    use crate::fields::mnt4753::Fq;
+    known_answer_tests::<Fq>("src/fields/mnt4753/test_vec/mnt4753_fq_kat");
+}
+
+#[test]
+fn test_mnt4753_fr_known_answers() {
+    // The scalar field is the base field of the other curve of the cycle.
+    use crate::fields::mnt4753::Fr;
+    known_answer_tests::<Fr>("src/fields/mnt6753/test_vec/mnt6753_fq_kat");
+}
+
+#[test]
+fn test_mnt4753_field_info() {
+    use crate::fields::{field_info, tests::field_info_test};
+
+    field_info_test::<Fq>();
+    field_info_test::<Fr>();
+    assert_eq!(field_info::<Fq>().modulus_bits, 753);
+    assert_eq!(field_info::<Fr>().modulus_bits, 753);
+    assert_eq!(field_info::<Fq>().two_adicity, FqParameters::TWO_ADICITY);
+}
+
+#[test]
+fn test_mnt4753_characteristic_bigint() {
+    fn check_characteristic<F: PrimeField>() {
+        let p = F::characteristic_bigint();
+        assert_eq!(p.as_ref(), F::characteristic());
+        // p - 1 = -1 mod p
+        let mut p_minus_one = p;
+        assert!(!p_minus_one.sub_noborrow(&F::BigInt::from(1)));
+        assert_eq!(F::from_repr(p_minus_one), -F::one());
+    }
+
+    check_characteristic::<Fq>();
+    check_characteristic::<Fr>();
+    assert_eq!(Fq2::characteristic_bigint(), Fq::characteristic_bigint());
+    assert_eq!(Fq4::characteristic_bigint().as_ref(), Fq4::characteristic());
+}
@@ -1,4 +1,4 @@
-use crate::{BigInteger, BigInteger768, fields::tests::{field_test, frobenius_test, primefield_test, sqrt_field_test}, fields::mnt6753::{Fq, Fq3, Fq6, FqParameters, Fq3Parameters, Fq6Parameters}, fields::FpParameters, fields::models::{Fp3Parameters, Fp6Parameters}, Field, PrimeField, SquareRootField, UniformRand, bytes::ToBytes, to_bytes, ToBits};
+use crate::{BigInteger, BigInteger768, fields::tests::{field_test, frobenius_test, known_answer_tests, primefield_test, sqrt_field_test}, fields::mnt6753::{Fq, Fq3, Fq6, FqParameters, Fq3Parameters, Fq6Parameters}, fields::FpParameters, fields::models::{Fp3Parameters, Fp6Parameters}, Field, PrimeField, SquareRootField, UniformRand, bytes::ToBytes, to_bytes, ToBits};
 use rand::SeedableRng;
 use rand_xorshift::XorShiftRng;
 use std::{
@@ -2397,4 +2397,79 @@ fn test_fq6_mul_by_2345() {
 
         assert_eq!(a.mul_by_2345(&to_mul), b);
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_mnt6753_fq3_sparse_mul() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let a = Fq3::rand(&mut rng);
+        let c0 = Fq::rand(&mut rng);
+        let c1 = Fq::rand(&mut rng);
+
+        let mut b = a;
+        b.mul_by_01(&c0, &c1);
+        assert_eq!(b, a * &Fq3::new(c0, c1, Fq::zero()));
+
+        let mut b = a;
+        b.mul_by_1(&c1);
+        assert_eq!(b, a * &Fq3::new(Fq::zero(), c1, Fq::zero()));
+
+        let mut b = a;
+        b.mul_assign_by_fp(&c0);
+        assert_eq!(b, a * &Fq3::new(c0, Fq::zero(), Fq::zero()));
+    }
+}
+
+#[test]
+fn test_fq6_unitary_inverse() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        // a^(q^3 - 1) is unitary
+        let a = Fq6::rand(&mut rng);
+        let mut u = a;
+        u.frobenius_map(3);
+        u *= &a.inverse().unwrap();
+
+        assert_eq!(u * &u.unitary_inverse(), Fq6::one());
+        assert_eq!(u.unitary_inverse(), u.inverse().unwrap());
+        // for non-unitary elements the conjugate is not the inverse
+        let mut conjugate = a;
+        conjugate.conjugate();
+        assert_ne!(conjugate, a.inverse().unwrap());
+    }
+}
+
+#[test]
+fn test_mnt6753_fq_known_answers() {
+    use crate::fields::mnt6753::Fq;
+    known_answer_tests::<Fq>("src/fields/mnt6753/test_vec/mnt6753_fq_kat");
+}
+
+#[test]
+fn test_mnt6753_fr_known_answers() {
+    // The scalar field is the base field of the other curve of the cycle.
+    use crate::fields::mnt6753::Fr;
+    known_answer_tests::<Fr>("src/fields/mnt4753/test_vec/mnt4753_fq_kat");
+}
+
+#[test]
+fn test_mnt6753_characteristic_bigint() {
+    use crate::fields::mnt6753::Fr;
+
+    fn check_characteristic<F: PrimeField>() {
+        let p = F::characteristic_bigint();
+        assert_eq!(p.as_ref(), F::characteristic());
+        // p - 1 = -1 mod p
+        let mut p_minus_one = p;
+        assert!(!p_minus_one.sub_noborrow(&F::BigInt::from(1)));
+        assert_eq!(F::from_repr(p_minus_one), -F::one());
+    }
+
+    check_characteristic::<Fq>();
+    check_characteristic::<Fr>();
+    assert_eq!(Fq3::characteristic_bigint(), Fq::characteristic_bigint());
+    assert_eq!(Fq6::characteristic_bigint().as_ref(), Fq6::characteristic());
+}
@@ -1,10 +1,14 @@
+use alloc::{borrow::ToOwned, boxed::Box, string::String, vec::Vec};
 use crate::{biginteger::BigInteger, bytes::{FromBytes, ToBytes}, UniformRand, bits::{ToBits, FromBits}, Error, BitSerializationError};
-use std::{
+use core::{
+    cmp::Ordering,
     fmt::{Debug, Display},
     hash::Hash,
+    iter::{Product, Sum},
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
     str::FromStr,
 };
+use crate::io::{Result as IoResult, Write};
 
 #[macro_use]
 mod macros;
@@ -29,14 +33,14 @@ macro_rules! field_new {
     ($name:ident, $c0:expr) => {
         $name {
             0: $c0, 
-            1: std::marker::PhantomData
+            1: core::marker::PhantomData
         }
     };
     ($name:ident, $c0:expr, $c1:expr $(,)?) => {
         $name {
             c0: $c0,
             c1: $c1,
-            _parameters: std::marker::PhantomData,
+            _parameters: core::marker::PhantomData,
         }
     };
     ($name:ident, $c0:expr, $c1:expr, $c2:expr $(,)?) => {
@@ -44,13 +48,20 @@ macro_rules! field_new {
             c0: $c0,
             c1: $c1,
             c2: $c2,
-            _parameters: std::marker::PhantomData,
+            _parameters: core::marker::PhantomData,
         }
     };
 }
 
+/// Partial Montgomery multiplication with Montgomery constant R = 2^64.
 pub trait MulShort where Self: Sized {
 
+    /// Computes `self * other * 2^-64 mod M` on the internal representations,
+    /// using only the least significant limb of `self`. Hence `self` must be a
+    /// "short" constant, i.e. its internal representation must fit into a single
+    /// 64-bit limb (a 64-bit value `t` stored as `t * 2^64 mod M` in partial
+    /// Montgomery form). Violating this bound is checked in debug builds only,
+    /// and silently gives a wrong result otherwise.
     fn mul_short(self, other: &Self) -> Self;
 }
 
@@ -79,6 +90,8 @@ pub trait Field:
     + From<u32>
     + From<u16>
     + From<u8>
+    + From<i64>
+    + From<i32>
     + for<'a> Add<&'a Self, Output = Self>
     + for<'a> Sub<&'a Self, Output = Self>
     + for<'a> Mul<&'a Self, Output = Self>
@@ -87,7 +100,14 @@ pub trait Field:
     + for<'a> SubAssign<&'a Self>
     + for<'a> MulAssign<&'a Self>
     + for<'a> DivAssign<&'a Self>
+    + Sum<Self>
+    + for<'a> Sum<&'a Self>
+    + Product<Self>
+    + for<'a> Product<&'a Self>
 {
+    /// The number of bytes written by `ToBytes` for an element of the field.
+    const SERIALIZED_SIZE: usize;
+
     /// Returns the zero element of the field, the additive identity.
     fn zero() -> Self;
 
@@ -113,6 +133,13 @@ pub trait Field:
     /// Doubles `self` in place.
     fn double_in_place(&mut self) -> &mut Self;
 
+    /// Doubles each element of `elems` in place.
+    fn batch_double(elems: &mut [Self]) {
+        for elem in elems.iter_mut() {
+            elem.double_in_place();
+        }
+    }
+
     /// Returns `self * self`.
     #[must_use]
     fn square(&self) -> Self;
@@ -155,6 +182,64 @@ pub trait Field:
         }
         res
     }
+
+    /// Returns the odd powers `self, self^3, ..., self^(2^window - 1)` of this
+    /// element, to be used as the table of `pow_with_table`.
+    fn odd_powers(&self, window: usize) -> Vec<Self> {
+        assert!(window > 0);
+        let square = self.square();
+        let mut table = Vec::with_capacity(1 << (window - 1));
+        table.push(*self);
+        for i in 1..(1 << (window - 1)) {
+            let next = table[i - 1] * &square;
+            table.push(next);
+        }
+        table
+    }
+
+    /// Exponentiates the base of `table`, as returned by `odd_powers`, by a number
+    /// represented with `u64` limbs, least significant limb first. Uses a sliding
+    /// window, so that repeated exponentiations of the same base share the table.
+    fn pow_with_table<S: AsRef<[u64]>>(table: &[Self], exp: S) -> Self {
+        assert!(table.len().is_power_of_two());
+        let window = table.len().trailing_zeros() as usize + 1;
+
+        let bits = BitIterator::new(exp)
+            .skip_while(|b| !b)
+            .collect::<Vec<_>>();
+
+        let mut res = Self::one();
+        let mut i = 0;
+        while i < bits.len() {
+            if !bits[i] {
+                res.square_in_place();
+                i += 1;
+                continue;
+            }
+            // Take the longest chunk of at most `window` bits which ends with a one.
+            let mut j = core::cmp::min(i + window, bits.len()) - 1;
+            while !bits[j] {
+                j -= 1;
+            }
+            let mut value = 0usize;
+            for &b in &bits[i..=j] {
+                res.square_in_place();
+                value = (value << 1) | (b as usize);
+            }
+            res *= &table[value >> 1];
+            i = j + 1;
+        }
+        res
+    }
+
+    /// Exponentiates this element by `2^k`, i.e. squares it `k` times.
+    fn pow2(&self, k: u32) -> Self {
+        let mut res = *self;
+        for _ in 0..k {
+            res.square_in_place();
+        }
+        res
+    }
 }
 
 /// A trait that defines parameters for a prime field.
@@ -169,6 +254,9 @@ pub trait FpParameters: 'static + Send + Sync + Sized {
 
     /// The number of bits that must be shaved from the beginning of
     /// the representation when randomly sampling.
+    /// Must be at least one: doubling and addition compute the unreduced sum
+    /// of two reduced elements before subtracting the modulus, and rely on it
+    /// fitting into the backing `BigInteger` without a carry out of the top limb.
     const REPR_SHAVE_BITS: u32;
 
     /// R = 2^256 % Self::MODULUS
@@ -207,17 +295,103 @@ pub trait FpParameters: 'static + Send + Sync + Sized {
     const MODULUS_MINUS_ONE_DIV_TWO: Self::BigInt;
 }
 
+/// The main parameters of a prime field, as returned by `field_info`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldInfo<B: BigInteger> {
+    pub modulus: B,
+    pub modulus_bits: u32,
+    pub r: B,
+    pub r2: B,
+    pub inv: u64,
+    pub two_adicity: u32,
+}
+
+impl<B: BigInteger> Display for FieldInfo<B> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        writeln!(f, "MODULUS = {}", self.modulus)?;
+        writeln!(f, "MODULUS_BITS = {}", self.modulus_bits)?;
+        writeln!(f, "R = {}", self.r)?;
+        writeln!(f, "R2 = {}", self.r2)?;
+        writeln!(f, "INV = 0x{:016x}", self.inv)?;
+        write!(f, "TWO_ADICITY = {}", self.two_adicity)
+    }
+}
+
+/// Returns the modulus and the Montgomery constants of `F`, so that they can be
+/// inspected without naming its `FpParameters`.
+pub fn field_info<F: PrimeField>() -> FieldInfo<F::BigInt> {
+    FieldInfo {
+        modulus: F::Params::MODULUS,
+        modulus_bits: F::Params::MODULUS_BITS,
+        r: F::Params::R,
+        r2: F::Params::R2,
+        inv: F::Params::INV,
+        two_adicity: F::Params::TWO_ADICITY,
+    }
+}
+
 /// The interface for a prime field.
 pub trait PrimeField: Field + FromStr {
     type Params: FpParameters<BigInt = Self::BigInt>;
     type BigInt: BigInteger;
 
     /// Returns a prime field element from its underlying representation.
+    /// `repr` must be canonical, i.e. smaller than the modulus: otherwise zero is
+    /// returned. Use `reduce_repr` to reduce arbitrary integers.
     fn from_repr(repr: <Self::Params as FpParameters>::BigInt) -> Self;
 
+    /// Returns the prime field element `repr mod char()`. Unlike `from_repr`, this
+    /// accepts any integer fitting into the underlying representation.
+    fn reduce_repr(repr: Self::BigInt) -> Self {
+        if repr < Self::Params::MODULUS {
+            return Self::from_repr(repr);
+        }
+        // Horner's rule over the 64 bit limbs, each of which is smaller than the modulus.
+        let two_64 = Self::one().double().pow([64u64]);
+        repr.as_ref().iter().rev().fold(Self::zero(), |acc, limb| {
+            acc * &two_64 + &Self::from_repr(Self::BigInt::from(*limb))
+        })
+    }
+
     /// Returns the underlying representation of the prime field element.
     fn into_repr(&self) -> Self::BigInt;
 
+    /// Returns the characteristic of the field as a big integer. This is the same
+    /// number as `Field::characteristic()`, but can be used directly in arithmetic.
+    fn characteristic_bigint() -> Self::BigInt {
+        Self::Params::MODULUS
+    }
+
+    /// Returns the canonical value of the element as big-endian hexadecimal,
+    /// without prefix and zero-padded to the `(MODULUS_BITS + 3) / 4` digits
+    /// of the modulus. It is parsed back by `BigInteger::from_hex_str`.
+    fn to_hex_string(&self) -> String {
+        let width = ((Self::Params::MODULUS_BITS + 3) / 4) as usize;
+        let mut hex = String::with_capacity(16 * self.into_repr().as_ref().len());
+        for limb in self.into_repr().as_ref().iter().rev() {
+            for shift in (0..16).rev() {
+                let digit = ((limb >> (4 * shift)) & 0xf) as u32;
+                hex.push(core::char::from_digit(digit, 16).unwrap());
+            }
+        }
+        hex.split_off(hex.len() - width)
+    }
+
+    /// Returns the underlying representations of a slice of prime field elements.
+    /// Equivalent to calling `into_repr()` on each element.
+    fn batch_into_repr(elements: &[Self]) -> Vec<Self::BigInt> {
+        elements.iter().map(|e| e.into_repr()).collect()
+    }
+
+    /// Returns the dot product `sum_i a[i] * b[i]` of two slices of prime field
+    /// elements, or an error if their lengths differ.
+    fn dot_product(a: &[Self], b: &[Self]) -> Result<Self, Error> {
+        if a.len() != b.len() {
+            return Err(format!("length mismatch in dot product: {} != {}", a.len(), b.len()).into());
+        }
+        Ok(a.iter().zip(b.iter()).fold(Self::zero(), |acc, (a_i, b_i)| acc + &(*a_i * b_i)))
+    }
+
     /// Returns a prime field element from its underlying raw representation.
     fn from_repr_raw(repr: Self::BigInt) -> Self;
 
@@ -228,12 +402,46 @@ pub trait PrimeField: Field + FromStr {
     /// otherwise returns None.
     fn from_random_bytes(bytes: &[u8]) -> Option<Self>;
 
+    /// Interprets `bytes` as a little-endian integer of arbitrary length and reduces
+    /// it modulo `char()`. Unlike `from_random_bytes` this always succeeds; for
+    /// uniformly random input of at least `(MODULUS_BITS + 128 + 7) / 8` bytes the
+    /// statistical distance of the output from uniform is below 2^-128.
+    fn from_random_bytes_mod_order(bytes: &[u8]) -> Self {
+        // Chunks of `CAPACITY / 8` bytes are always smaller than the modulus.
+        let chunk_size = (Self::Params::CAPACITY / 8) as usize;
+        let two = Self::one().double();
+        let mut res = Self::zero();
+        // Horner's rule, starting from the most significant chunk.
+        for chunk in bytes.rchunks(chunk_size) {
+            let mut repr = <Self::Params as FpParameters>::BigInt::default();
+            for (i, byte) in chunk.iter().enumerate() {
+                repr.as_mut()[i / 8] |= (*byte as u64) << (8 * (i % 8));
+            }
+            res *= &two.pow([8 * chunk.len() as u64]);
+            res += &Self::from_repr(repr);
+        }
+        res
+    }
+
     /// Returns the multiplicative generator of `char()` - 1 order.
     fn multiplicative_generator() -> Self;
 
     /// Returns the 2^s root of unity.
     fn root_of_unity() -> Self;
 
+    /// Returns the primitive `n`-th root of unity `root_of_unity()^(2^(s - log2(n)))`,
+    /// or `None` if `n` is not a power of two or exceeds 2^s.
+    fn get_root_of_unity(n: usize) -> Option<Self> {
+        if !n.is_power_of_two() {
+            return None;
+        }
+        let log_n = n.trailing_zeros();
+        if log_n > Self::Params::TWO_ADICITY {
+            return None;
+        }
+        Some(Self::root_of_unity().pow2(Self::Params::TWO_ADICITY - log_n))
+    }
+
     /// Return the a QNR^T
     fn qnr_to_t() -> Self {
         Self::root_of_unity()
@@ -261,27 +469,108 @@ pub trait PrimeField: Field + FromStr {
 
 }
 
+/// Big-endian bits of the canonical representation `repr` of an element of `F`,
+/// skipping the leading bits beyond `F::Params::MODULUS_BITS`.
+fn repr_to_bits<F: PrimeField>(repr: F::BigInt) -> Vec<bool> {
+    let num_bits = F::Params::MODULUS_BITS;
+
+    let mut field_char = BitIterator::new(F::characteristic());
+    let mut tmp = Vec::with_capacity(num_bits as usize);
+    let mut found_one = false;
+    for b in BitIterator::new(repr) {
+        // Skip leading bits
+        found_one |= field_char.next().unwrap();
+        if !found_one {
+            continue;
+        }
+
+        tmp.push(b);
+    }
+
+    assert_eq!(tmp.len(), num_bits as usize);
+
+    tmp
+}
+
 impl<F: PrimeField> ToBits for F {
     #[inline]
     fn write_bits(&self) -> Vec<bool> {
-        let num_bits = <Self as PrimeField>::Params::MODULUS_BITS;
+        repr_to_bits::<F>(self.into_repr())
+    }
+}
 
-        let mut field_char = BitIterator::new(Self::characteristic());
-        let mut tmp = Vec::with_capacity(num_bits as usize);
-        let mut found_one = false;
-        for b in BitIterator::new(self.into_repr()) {
-            // Skip leading bits
-            found_one |= field_char.next().unwrap();
-            if !found_one {
-                continue;
-            }
+/// A prime field element together with its canonical representation, computed
+/// once on construction. Comparisons, serialization and bit decomposition of a
+/// field element each call `into_repr()`, i.e. perform a Montgomery reduction:
+/// wrapping the element avoids repeating it when it is used several times, e.g.
+/// when sorting. Ordering, `ToBytes` and `ToBits` agree with those of `F`.
+#[derive(Derivative)]
+#[derivative(
+    Copy(bound = "F: PrimeField"),
+    Clone(bound = "F: PrimeField"),
+    Debug(bound = "F: PrimeField"),
+    PartialEq(bound = "F: PrimeField"),
+    Eq(bound = "F: PrimeField")
+)]
+pub struct ReprCached<F: PrimeField> {
+    value: F,
+    repr: F::BigInt,
+}
 
-            tmp.push(b);
+impl<F: PrimeField> ReprCached<F> {
+    #[inline]
+    pub fn new(value: F) -> Self {
+        Self {
+            value,
+            repr: value.into_repr(),
         }
+    }
+
+    /// Returns the wrapped field element.
+    #[inline]
+    pub fn value(&self) -> F {
+        self.value
+    }
 
-        assert_eq!(tmp.len(), num_bits as usize);
+    /// Returns the cached canonical representation of the field element.
+    #[inline]
+    pub fn repr(&self) -> &F::BigInt {
+        &self.repr
+    }
+}
+
+impl<F: PrimeField> From<F> for ReprCached<F> {
+    #[inline]
+    fn from(value: F) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<F: PrimeField> Ord for ReprCached<F> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.repr.cmp(&other.repr)
+    }
+}
+
+impl<F: PrimeField> PartialOrd for ReprCached<F> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<F: PrimeField> ToBytes for ReprCached<F> {
+    #[inline]
+    fn write<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.repr.write(writer)
+    }
+}
 
-        tmp
+impl<F: PrimeField> ToBits for ReprCached<F> {
+    #[inline]
+    fn write_bits(&self) -> Vec<bool> {
+        repr_to_bits::<F>(self.repr)
     }
 }
 
@@ -346,6 +635,16 @@ pub trait SquareRootField: Field {
 
     /// Sets `self` to be the square root of `self`, if it exists.
     fn sqrt_in_place(&mut self) -> Option<&mut Self>;
+
+    /// Returns a square root of `self`, which must be known to be a quadratic
+    /// residue (or zero), e.g. the `y^2` of a valid compressed point. Fields
+    /// using Tonelli-Shanks override it to skip the Legendre symbol; the result
+    /// is the same as `sqrt().unwrap()`, in particular it panics on a quadratic
+    /// non-residue.
+    #[must_use]
+    fn sqrt_unchecked(&self) -> Self {
+        self.sqrt().unwrap()
+    }
 }
 
 #[derive(Debug, PartialEq)]
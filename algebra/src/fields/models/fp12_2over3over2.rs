@@ -1,11 +1,12 @@
+use alloc::vec::Vec;
 use rand::{Rng, distributions::{Standard, Distribution}};
 use crate::{UniformRand, ToBits, FromBits, PrimeField, Error};
-use std::{
+use core::{
     cmp::Ordering,
-    io::{Read, Result as IoResult, Write},
     marker::PhantomData,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
+use crate::io::{Read, Result as IoResult, Write};
 
 use crate::{
     bytes::{FromBytes, ToBytes},
@@ -59,6 +60,12 @@ impl<P: Fp12Parameters> Fp12<P> {
         }
     }
 
+    /// Returns the characteristic of the field as a big integer of the
+    /// underlying prime field.
+    pub fn characteristic_bigint() -> <<Fp2Params<P> as Fp2Parameters>::Fp as PrimeField>::BigInt {
+        Fp6::<P::Fp6Params>::characteristic_bigint()
+    }
+
     pub fn mul_by_fp(
         &mut self,
         element: &<<P::Fp6Params as Fp6Parameters>::Fp2Params as Fp2Parameters>::Fp,
@@ -71,6 +78,18 @@ impl<P: Fp12Parameters> Fp12<P> {
         self.c1 = self.c1.neg();
     }
 
+    /// Inverse of an element of the cyclotomic subgroup, such as the output of
+    /// the easy part `x^((p^6 - 1)(p^2 + 1))` of the BLS12 final exponentiation.
+    /// Those elements have norm one over `Fp6`, hence their inverse is the conjugate
+    /// `x^(p^6)`. Debug builds assert that `self` is unitary; for other elements
+    /// use `conjugate`.
+    pub fn unitary_inverse(&self) -> Self {
+        let mut res = *self;
+        res.conjugate();
+        debug_assert_eq!(*self * &res, Self::one(), "unitary_inverse of a non-unitary element");
+        res
+    }
+
     pub fn mul_by_034(
         &mut self,
         c0: &Fp2<Fp2Params<P>>,
@@ -113,6 +132,11 @@ impl<P: Fp12Parameters> Fp12<P> {
         self.c0.add_assign(&aa);
     }
 
+    /// Squaring in the cyclotomic subgroup of Fp12, following Granger and Scott
+    /// ("Faster squaring in the cyclotomic subgroup of sixth degree extensions").
+    /// The result is correct only if `self` belongs to the subgroup; this is not
+    /// checked. The formula is applied unconditionally, without any per-call test
+    /// on the characteristic.
     pub fn cyclotomic_square(&self) -> Self {
         let mut result = Self::zero();
         let fp2_nr = <P::Fp6Params as Fp6Parameters>::mul_fp2_by_nonresidue;
@@ -201,10 +225,47 @@ impl<P: Fp12Parameters> Fp12<P> {
         }
         res
     }
+
+    /// Exponentiation of an element of the cyclotomic subgroup by `2^k`,
+    /// i.e. `k` cyclotomic squarings.
+    pub fn cyclotomic_pow2(&self, k: u32) -> Self {
+        let mut res = *self;
+        for _ in 0..k {
+            res = res.cyclotomic_square();
+        }
+        res
+    }
+
+    /// Exponentiation of an element of the cyclotomic subgroup by an exponent
+    /// given in signed-digit (NAF) representation. The digits are in {-1, 0, 1}
+    /// and ordered from least to most significant, as returned by `find_wnaf`.
+    pub fn cyclotomic_exp_naf(&self, naf: &[i8]) -> Self {
+        let self_inv = self.unitary_inverse();
+        let mut res = Self::one();
+
+        let mut found_nonzero = false;
+
+        for &digit in naf.iter().rev() {
+            if found_nonzero {
+                res = res.cyclotomic_square();
+            }
+
+            if digit != 0 {
+                found_nonzero = true;
+
+                if digit > 0 {
+                    res *= self;
+                } else {
+                    res *= &self_inv;
+                }
+            }
+        }
+        res
+    }
 }
 
-impl<P: Fp12Parameters> std::fmt::Display for Fp12<P> {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl<P: Fp12Parameters> core::fmt::Display for Fp12<P> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         write!(f, "Fp12({} + {} * w)", self.c0, self.c1)
     }
 }
@@ -217,6 +278,8 @@ impl<P: Fp12Parameters> Distribution<Fp12<P>> for Standard {
 }
 
 impl<P: Fp12Parameters> Field for Fp12<P> {
+    const SERIALIZED_SIZE: usize = 2 * Fp6::<P::Fp6Params>::SERIALIZED_SIZE;
+
     fn zero() -> Self {
         Self::new(Fp6::zero(), Fp6::zero())
     }
@@ -259,14 +322,7 @@ impl<P: Fp12Parameters> Field for Fp12<P> {
         self.c1.frobenius_map(power);
 
         self.c1
-            .c0
-            .mul_assign(&P::FROBENIUS_COEFF_FP12_C1[power % 12]);
-        self.c1
-            .c1
-            .mul_assign(&P::FROBENIUS_COEFF_FP12_C1[power % 12]);
-        self.c1
-            .c2
-            .mul_assign(&P::FROBENIUS_COEFF_FP12_C1[power % 12]);
+            .mul_assign_by_fp2(&P::FROBENIUS_COEFF_FP12_C1[power % 12]);
     }
 
     fn square(&self) -> Self {
@@ -465,6 +521,18 @@ impl<P: Fp12Parameters> From<u8> for Fp12<P> {
     }
 }
 
+impl<P: Fp12Parameters> From<i64> for Fp12<P> {
+    fn from(other: i64) -> Self {
+        Self::new(other.into(), Fp6::zero())
+    }
+}
+
+impl<P: Fp12Parameters> From<i32> for Fp12<P> {
+    fn from(other: i32) -> Self {
+        Self::new(other.into(), Fp6::zero())
+    }
+}
+
 impl<P: Fp12Parameters> ToBytes for Fp12<P> {
     #[inline]
     fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
@@ -500,3 +568,5 @@ impl<P: Fp12Parameters> FromBits for Fp12<P> {
         Ok(Fp12::new(c0, c1))
     }
 }
+
+impl_field_sum_and_product!(Fp12, Fp12Parameters);
@@ -1,11 +1,12 @@
+use alloc::vec::Vec;
 use crate::{UniformRand, ToBits, FromBits, Error};
 use rand::{Rng, distributions::{Standard, Distribution}};
-use std::{
+use core::{
     cmp::{Ord, Ordering, PartialOrd},
-    io::{Read, Result as IoResult, Write},
     marker::PhantomData,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
+use crate::io::{Read, Result as IoResult, Write};
 use crate::{
     bytes::{FromBytes, ToBytes},
     fields::{Field, LegendreSymbol, PrimeField, SquareRootField, FpParameters},
@@ -65,6 +66,12 @@ impl<P: Fp2Parameters> Fp2<P> {
         }
     }
 
+    /// Returns the characteristic of the field as a big integer of the
+    /// underlying prime field.
+    pub fn characteristic_bigint() -> <P::Fp as PrimeField>::BigInt {
+        P::Fp::characteristic_bigint()
+    }
+
     /// Norm of Fp2 over Fp: Norm(a) = a.x^2 - beta * a.y^2
     pub fn norm(&self) -> P::Fp {
         let t0 = self.c0.square();
@@ -74,6 +81,7 @@ impl<P: Fp2Parameters> Fp2<P> {
         t1
     }
 
+    /// Multiplies each coordinate of `self` by the base field element `element`.
     pub fn mul_by_fp(&mut self, element: &P::Fp) {
         self.c0.mul_assign(&element);
         self.c1.mul_assign(&element);
@@ -81,6 +89,8 @@ impl<P: Fp2Parameters> Fp2<P> {
 }
 
 impl<P: Fp2Parameters> Field for Fp2<P> {
+    const SERIALIZED_SIZE: usize = 2 * P::Fp::SERIALIZED_SIZE;
+
     fn zero() -> Self {
         Fp2::new(P::Fp::zero(), P::Fp::zero())
     }
@@ -113,6 +123,9 @@ impl<P: Fp2Parameters> Field for Fp2<P> {
         result
     }
 
+    /// Doubles each coordinate. Every base field doubling is a shift followed by
+    /// at most one conditional subtraction of the modulus, which is the least
+    /// needed to keep the coordinates reduced: there is nothing left to defer.
     fn double_in_place(&mut self) -> &mut Self {
         self.c0.double_in_place();
         self.c1.double_in_place();
@@ -270,6 +283,18 @@ impl<P: Fp2Parameters> From<u8> for Fp2<P> {
     }
 }
 
+impl<P: Fp2Parameters> From<i64> for Fp2<P> {
+    fn from(other: i64) -> Self {
+        Self::new(other.into(), P::Fp::zero())
+    }
+}
+
+impl<P: Fp2Parameters> From<i32> for Fp2<P> {
+    fn from(other: i32) -> Self {
+        Self::new(other.into(), P::Fp::zero())
+    }
+}
+
 impl<P: Fp2Parameters> ToBytes for Fp2<P> {
     #[inline]
     fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
@@ -407,8 +432,10 @@ impl<'a, P: Fp2Parameters> DivAssign<&'a Self> for Fp2<P> {
     }
 }
 
-impl<P: Fp2Parameters> std::fmt::Display for Fp2<P> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<P: Fp2Parameters> core::fmt::Display for Fp2<P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "Fp2({} + {} * u)", self.c0, self.c1)
     }
 }
+
+impl_field_sum_and_product!(Fp2, Fp2Parameters);
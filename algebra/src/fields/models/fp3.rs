@@ -1,12 +1,13 @@
+use alloc::vec::Vec;
 use rand::{Rng, distributions::{Standard, Distribution}};
 use crate::{UniformRand, ToBits, FromBits, Error};
-use std::{
+use core::{
     cmp::{Ord, Ordering, PartialOrd},
-    io::{Read, Result as IoResult, Write},
     marker::PhantomData,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
     str::FromStr,
 };
+use crate::io::{Read, Result as IoResult, Write};
 
 use crate::{
     bytes::{FromBytes, ToBytes},
@@ -76,12 +77,46 @@ impl<P: Fp3Parameters> Fp3<P> {
         }
     }
 
+    /// Returns the characteristic of the field as a big integer of the
+    /// underlying prime field.
+    pub fn characteristic_bigint() -> <P::Fp as PrimeField>::BigInt {
+        P::Fp::characteristic_bigint()
+    }
+
+    /// Multiplies each coordinate of `self` by the base field element `value`.
     pub fn mul_assign_by_fp(&mut self, value: &P::Fp) {
         self.c0.mul_assign(value);
         self.c1.mul_assign(value);
         self.c2.mul_assign(value);
     }
 
+    /// Sparse multiplication: multiplies `self` by `0 + c1 * X + 0 * X^2`.
+    pub fn mul_by_1(&mut self, c1: &P::Fp) {
+        let b_b = self.c1 * c1;
+        // X^3 = alpha
+        let t1 = P::mul_fp_by_nonresidue(&(self.c2 * c1));
+        let t2 = self.c0 * c1;
+
+        self.c0 = t1;
+        self.c1 = t2;
+        self.c2 = b_b;
+    }
+
+    /// Sparse multiplication: multiplies `self` by `c0 + c1 * X + 0 * X^2`,
+    /// using Karatsuba for the products of the non-zero coefficients.
+    pub fn mul_by_01(&mut self, c0: &P::Fp, c1: &P::Fp) {
+        let a_a = self.c0 * c0;
+        let b_b = self.c1 * c1;
+
+        let t1 = P::mul_fp_by_nonresidue(&(self.c2 * c1)) + &a_a;
+        let t2 = (*c0 + c1) * &(self.c0 + &self.c1) - &a_a - &b_b;
+        let t3 = self.c2 * c0 + &b_b;
+
+        self.c0 = t1;
+        self.c1 = t2;
+        self.c2 = t3;
+    }
+
     // Calculate the norm of an element with respect to the base field Fp.
     pub fn norm(&self) -> P::Fp {
         let mut self_to_p = *self;
@@ -105,6 +140,8 @@ impl<P: Fp3Parameters> Fp3<P> {
 }
 
 impl<P: Fp3Parameters> Field for Fp3<P> {
+    const SERIALIZED_SIZE: usize = 3 * P::Fp::SERIALIZED_SIZE;
+
     fn zero() -> Self {
         Fp3 {
             c0:          P::Fp::zero(),
@@ -258,6 +295,11 @@ impl<P: Fp3Parameters> SquareRootField for Fp3<P> {
         sqrt_impl!(Self, P, self)
     }
 
+    /// Returns the square root of self, assumed to be a quadratic residue.
+    fn sqrt_unchecked(&self) -> Self {
+        sqrt_unchecked_impl!(Self, P, self)
+    }
+
     /// Sets `self` to be the square root of `self`, if it exists.
     fn sqrt_in_place(&mut self) -> Option<&mut Self> {
         (*self).sqrt().map(|sqrt| {
@@ -328,6 +370,20 @@ impl<P: Fp3Parameters> From<u8> for Fp3<P> {
     }
 }
 
+impl<P: Fp3Parameters> From<i64> for Fp3<P> {
+    fn from(other: i64) -> Self {
+        let fe: P::Fp = other.into();
+        Self::new(fe, P::Fp::zero(), P::Fp::zero())
+    }
+}
+
+impl<P: Fp3Parameters> From<i32> for Fp3<P> {
+    fn from(other: i32) -> Self {
+        let fe: P::Fp = other.into();
+        Self::new(fe, P::Fp::zero(), P::Fp::zero())
+    }
+}
+
 impl<P: Fp3Parameters> ToBytes for Fp3<P> {
     #[inline]
     fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
@@ -490,15 +546,12 @@ impl<P: Fp3Parameters> FromStr for Fp3<P> {
     fn from_str(mut s: &str) -> Result<Self, Self::Err> {
         s = s.trim();
         if s.is_empty() {
-            println!("is empty");
             return Err(());
         }
         if s.len() < 3 {
-            println!("len is less than 3");
             return Err(());
         }
         if !(s.starts_with('[') && s.ends_with(']')) {
-            println!("doesn't start and end with square brackets");
             return Err(());
         }
         let mut point = Vec::new();
@@ -509,7 +562,6 @@ impl<P: Fp3Parameters> FromStr for Fp3<P> {
             }
         }
         if point.len() != 3 {
-            println!("not enough points");
             return Err(());
         }
         let point = Fp3::new(point[0], point[1], point[2]);
@@ -517,8 +569,10 @@ impl<P: Fp3Parameters> FromStr for Fp3<P> {
     }
 }
 
-impl<P: Fp3Parameters> ::std::fmt::Display for Fp3<P> {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl<P: Fp3Parameters> ::core::fmt::Display for Fp3<P> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         write!(f, "Fp3({}, {}, {})", self.c0, self.c1, self.c2)
     }
 }
+
+impl_field_sum_and_product!(Fp3, Fp3Parameters);
@@ -1,12 +1,13 @@
+use alloc::{boxed::Box, vec::Vec};
 use rand::{Rng, distributions::{Standard, Distribution}};
 use crate::{UniformRand, ToBits, FromBits, PrimeField, Error, BitSerializationError};
 
-use std::{
+use core::{
     cmp::Ordering,
-    io::{Read, Result as IoResult, Write},
     marker::PhantomData,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
+use crate::io::{Read, Result as IoResult, Write};
 
 use crate::{bytes::{FromBytes, ToBytes}, fields::{Field, Fp2, Fp2Parameters, FpParameters},
             biginteger::BigInteger, ToCompressedBits, FromCompressedBits};
@@ -61,14 +62,33 @@ impl<P: Fp4Parameters> Fp4<P> {
         }
     }
 
+    /// Returns the characteristic of the field as a big integer of the
+    /// underlying prime field.
+    pub fn characteristic_bigint() -> <<P::Fp2Params as Fp2Parameters>::Fp as PrimeField>::BigInt {
+        Fp2::<P::Fp2Params>::characteristic_bigint()
+    }
+
     pub fn mul_by_nonresidue(value: &Fp2<P::Fp2Params>) -> Fp2<P::Fp2Params> {
         let new_c0 = P::Fp2Params::mul_fp_by_nonresidue(&value.c1);
         let new_c1 = value.c0;
         Fp2::new(new_c0, new_c1)
     }
 
+    /// Negates `c1`, i.e. applies the `q^2`-power Frobenius map, which is the
+    /// conjugation of `Fp4` over `Fp2`.
+    pub fn conjugate(&mut self) {
+        self.c1 = self.c1.neg();
+    }
+
+    /// Inverse of an element of norm one over `Fp2`, i.e. with `x^(q^2 + 1) = 1`,
+    /// which is its conjugate `x^(q^2)`. The easy part `x^(q^2 - 1)` of the MNT4
+    /// final exponentiation maps into this subgroup. Debug builds assert that `self`
+    /// is unitary; for other elements use `conjugate`.
     pub fn unitary_inverse(&self) -> Self {
-        Self::new(self.c0, self.c1.neg())
+        let mut res = *self;
+        res.conjugate();
+        debug_assert_eq!(*self * &res, Self::one(), "unitary_inverse of a non-unitary element");
+        res
     }
 
     pub fn cyclotomic_square(&self) -> Self{
@@ -108,7 +128,9 @@ impl<P: Fp4Parameters> Fp4<P> {
         res
     }
 
-    //Mul by an element of the form (c0: [c0, 0] c1: [c2, c3])
+    /// Sparse multiplication by an element of the form (c0: [c0, 0] c1: [c2, c3]), which is
+    /// the form of the line evaluations `MNT4p::line_evaluation` (`py_twist_squared` being
+    /// in the base field) accumulated by the Miller loop.
     pub fn mul_by_023(self, other: &Self) -> Self
     {
         let v0 =
@@ -128,6 +150,8 @@ impl<P: Fp4Parameters> Fp4<P> {
 }
 
 impl<P: Fp4Parameters> Field for Fp4<P> {
+    const SERIALIZED_SIZE: usize = 2 * Fp2::<P::Fp2Params>::SERIALIZED_SIZE;
+
     fn zero() -> Self {
         Fp4 {
             c0:          Fp2::zero(),
@@ -283,6 +307,18 @@ impl<P: Fp4Parameters> From<u8> for Fp4<P> {
     }
 }
 
+impl<P: Fp4Parameters> From<i64> for Fp4<P> {
+    fn from(other: i64) -> Self {
+        Self::new(other.into(), Fp2::zero())
+    }
+}
+
+impl<P: Fp4Parameters> From<i32> for Fp4<P> {
+    fn from(other: i32) -> Self {
+        Self::new(other.into(), Fp2::zero())
+    }
+}
+
 impl<P: Fp4Parameters> ToBytes for Fp4<P> {
     #[inline]
     fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
@@ -478,8 +514,10 @@ impl<'a, P: Fp4Parameters> DivAssign<&'a Self> for Fp4<P> {
     }
 }
 
-impl<P: Fp4Parameters> ::std::fmt::Display for Fp4<P> {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl<P: Fp4Parameters> ::core::fmt::Display for Fp4<P> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         write!(f, "Fp4({}, {})", self.c0, self.c1)
     }
 }
+
+impl_field_sum_and_product!(Fp4, Fp4Parameters);
@@ -1,11 +1,12 @@
+use alloc::{boxed::Box, vec::Vec};
 use rand::{Rng, distributions::{Standard, Distribution}};
 use crate::{UniformRand, ToCompressedBits, FromCompressedBits, ToBits, FromBits, PrimeField, Error, BitSerializationError};
-use std::{
+use core::{
     cmp::Ordering,
-    io::{Read, Result as IoResult, Write},
     marker::PhantomData,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
+use crate::io::{Read, Result as IoResult, Write};
 
 use crate::{
     biginteger::BigInteger,
@@ -65,6 +66,12 @@ impl<P: Fp6Parameters> Fp6<P> {
         }
     }
 
+    /// Returns the characteristic of the field as a big integer of the
+    /// underlying prime field.
+    pub fn characteristic_bigint() -> <<P::Fp3Params as Fp3Parameters>::Fp as PrimeField>::BigInt {
+        Fp3::<P::Fp3Params>::characteristic_bigint()
+    }
+
     /// Multiply by quadratic nonresidue v.
     pub fn mul_by_nonresidue(value: &Fp3<P::Fp3Params>) -> Fp3<P::Fp3Params> {
         let mut res = *value;
@@ -76,8 +83,21 @@ impl<P: Fp6Parameters> Fp6<P> {
         res
     }
 
+    /// Negates `c1`, i.e. applies the `q^3`-power Frobenius map, which is the
+    /// conjugation of `Fp6` over `Fp3`.
+    pub fn conjugate(&mut self) {
+        self.c1 = -self.c1;
+    }
+
+    /// Inverse of an element of norm one over `Fp3`, i.e. with `x^(q^3 + 1) = 1`,
+    /// which is its conjugate `x^(q^3)`. The easy part `x^((q^3 - 1)(q + 1))` of the
+    /// MNT6 final exponentiation maps into this subgroup. Debug builds assert that
+    /// `self` is unitary; for other elements use `conjugate`.
     pub fn unitary_inverse(&self) -> Self {
-        Self::new(self.c0, -self.c1)
+        let mut res = *self;
+        res.conjugate();
+        debug_assert_eq!(*self * &res, Self::one(), "unitary_inverse of a non-unitary element");
+        res
     }
 
     pub fn cyclotomic_exp<B: BigInteger>(&self, exponent: &B) -> Self {
@@ -106,7 +126,8 @@ impl<P: Fp6Parameters> Fp6<P> {
         res
     }
 
-    //Mul by an element of the form [c0: (0, 0, a), c1: (b, c, d)]
+    /// Sparse multiplication by an element of the form [c0: (0, 0, a), c1: (b, c, d)], which is
+    /// the form of the line evaluations `MNT6p::line_evaluation` accumulated by the Miller loop.
     pub fn mul_by_2345(self, other: &Self) -> Self
     /* Devegili OhEig Scott Dahab --- Multiplication and Squaring on Pairing-Friendly Fields.pdf; Section 3 (Karatsuba) */
     {
@@ -123,6 +144,8 @@ impl<P: Fp6Parameters> Fp6<P> {
 }
 
 impl<P: Fp6Parameters> Field for Fp6<P> {
+    const SERIALIZED_SIZE: usize = 2 * Fp3::<P::Fp3Params>::SERIALIZED_SIZE;
+
     fn zero() -> Self {
         Fp6 {
             c0:          Fp3::zero(),
@@ -282,6 +305,18 @@ impl<P: Fp6Parameters> From<u8> for Fp6<P> {
     }
 }
 
+impl<P: Fp6Parameters> From<i64> for Fp6<P> {
+    fn from(other: i64) -> Self {
+        Self::new(other.into(), Fp3::zero())
+    }
+}
+
+impl<P: Fp6Parameters> From<i32> for Fp6<P> {
+    fn from(other: i32) -> Self {
+        Self::new(other.into(), Fp3::zero())
+    }
+}
+
 impl<P: Fp6Parameters> ToBytes for Fp6<P> {
     #[inline]
     fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
@@ -431,8 +466,8 @@ impl<'a, P: Fp6Parameters> From<&'a [bool]> for Fp6<P> {
     }
 }
 
-impl<P: Fp6Parameters> ::std::fmt::Display for Fp6<P> {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl<P: Fp6Parameters> ::core::fmt::Display for Fp6<P> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         write!(f, "Fp6_2over3({}, {})", self.c0, self.c1)
     }
 }
@@ -492,4 +527,6 @@ impl<P: Fp6Parameters> FromCompressedBits for Fp6<P> {
             _ => Err(Box::new(BitSerializationError::UndefinedSqrt)),
         }
     }
-}
\ No newline at end of file
+}
+
+impl_field_sum_and_product!(Fp6, Fp6Parameters);
@@ -1,11 +1,12 @@
+use alloc::vec::Vec;
 use rand::{Rng, distributions::{Standard, Distribution}};
 use crate::{UniformRand, ToBits, FromBits, PrimeField, Error};
-use std::{
+use core::{
     cmp::Ordering,
-    io::{Read, Result as IoResult, Write},
     marker::PhantomData,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
+use crate::io::{Read, Result as IoResult, Write};
 
 use crate::{
     bytes::{FromBytes, ToBytes},
@@ -57,18 +58,34 @@ impl<P: Fp6Parameters> Fp6<P> {
         }
     }
 
+    /// Returns the characteristic of the field as a big integer of the
+    /// underlying prime field.
+    pub fn characteristic_bigint() -> <<P::Fp2Params as Fp2Parameters>::Fp as PrimeField>::BigInt {
+        Fp2::<P::Fp2Params>::characteristic_bigint()
+    }
+
+    /// Multiplies each coordinate of `self` by the base field element `element`.
     pub fn mul_by_fp(&mut self, element: &<P::Fp2Params as Fp2Parameters>::Fp) {
         self.c0.mul_by_fp(&element);
         self.c1.mul_by_fp(&element);
         self.c2.mul_by_fp(&element);
     }
 
+    /// Multiplies each Fp2 coordinate of `self` by `c`, i.e. multiplies `self` by the
+    /// embedding of `c` into Fp6. Used to apply the Frobenius coefficients of a tower
+    /// built on top of Fp6, see `Fp12::frobenius_map`.
+    pub fn mul_assign_by_fp2(&mut self, c: &Fp2<P::Fp2Params>) {
+        self.c0.mul_assign(c);
+        self.c1.mul_assign(c);
+        self.c2.mul_assign(c);
+    }
+
+    /// Same as `mul_assign_by_fp2`.
     pub fn mul_by_fp2(&mut self, element: &Fp2<P::Fp2Params>) {
-        self.c0.mul_assign(&element);
-        self.c1.mul_assign(&element);
-        self.c2.mul_assign(&element);
+        self.mul_assign_by_fp2(element);
     }
 
+    /// Sparse multiplication: multiplies `self` by `0 + c1 * v + 0 * v^2`.
     pub fn mul_by_1(&mut self, c1: &Fp2<P::Fp2Params>) {
         let mut b_b = self.c1;
         b_b.mul_assign(c1);
@@ -97,6 +114,8 @@ impl<P: Fp6Parameters> Fp6<P> {
         self.c2 = b_b;
     }
 
+    /// Sparse multiplication: multiplies `self` by `c0 + c1 * v + 0 * v^2`.
+    /// Used by `Fp12::mul_by_034` and `Fp12::mul_by_014` for the pairing lines.
     pub fn mul_by_01(&mut self, c0: &Fp2<P::Fp2Params>, c1: &Fp2<P::Fp2Params>) {
         let mut a_a = self.c0;
         let mut b_b = self.c1;
@@ -142,6 +161,8 @@ impl<P: Fp6Parameters> Fp6<P> {
 }
 
 impl<P: Fp6Parameters> Field for Fp6<P> {
+    const SERIALIZED_SIZE: usize = 3 * Fp2::<P::Fp2Params>::SERIALIZED_SIZE;
+
     fn zero() -> Self {
         Self::new(Fp2::zero(), Fp2::zero(), Fp2::zero())
     }
@@ -268,8 +289,8 @@ impl<P: Fp6Parameters> Field for Fp6<P> {
     }
 }
 
-impl<P: Fp6Parameters> std::fmt::Display for Fp6<P> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<P: Fp6Parameters> core::fmt::Display for Fp6<P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
             "Fq6_3over2({} + {} * v, {} * v^2)",
@@ -450,6 +471,18 @@ impl<P: Fp6Parameters> From<u8> for Fp6<P> {
     }
 }
 
+impl<P: Fp6Parameters> From<i64> for Fp6<P> {
+    fn from(other: i64) -> Self {
+        Self::new(other.into(), Fp2::zero(), Fp2::zero())
+    }
+}
+
+impl<P: Fp6Parameters> From<i32> for Fp6<P> {
+    fn from(other: i32) -> Self {
+        Self::new(other.into(), Fp2::zero(), Fp2::zero())
+    }
+}
+
 impl<P: Fp6Parameters> ToBytes for Fp6<P> {
     #[inline]
     fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
@@ -488,3 +521,5 @@ impl<P: Fp6Parameters> FromBits for Fp6<P> {
         Ok(Fp6::new(c0, c1, c2))
     }
 }
+
+impl_field_sum_and_product!(Fp6, Fp6Parameters);
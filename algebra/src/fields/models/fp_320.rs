@@ -1,11 +1,11 @@
-use std::{
+use core::{
     cmp::{Ord, Ordering, PartialOrd},
-    fmt::{Display, Formatter, Result as FmtResult},
-    io::{Read, Result as IoResult, Write, Error as IoError, ErrorKind},
+    fmt::{Display, Formatter, LowerHex, Result as FmtResult},
     marker::PhantomData,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
     str::FromStr,
 };
+use crate::io::{Read, Result as IoResult, Write, Error as IoError, ErrorKind};
 
 use crate::{
     biginteger::{arithmetic as fa, BigInteger as _BigInteger, BigInteger320 as BigInteger},
@@ -51,6 +51,56 @@ impl<P: Fp320Parameters> Fp320<P> {
         }
     }
 
+    /// Adds `other` to `self` without reducing the result modulo `MODULUS`.
+    /// The sum of a reduced element and at most `2^REPR_SHAVE_BITS - 1` further
+    /// reduced elements fits into the backing `BigInteger`; beyond that, the
+    /// representation silently wraps around. Until `reduce_now` is called, `self`
+    /// must not be used as an operand of any other field operation, nor compared.
+    #[inline]
+    pub fn add_assign_unreduced(&mut self, other: &Self) {
+        self.0.add_nocarry(&other.0);
+    }
+
+    /// Fully reduces the representation of `self` after a sequence of
+    /// `add_assign_unreduced`. Costs one subtraction of `MODULUS` per multiple of
+    /// it exceeded: while the representation is below `2 * MODULUS` (e.g. after a
+    /// single unreduced addition of reduced elements) this is the same as the
+    /// reduction performed by `add_assign`.
+    #[inline]
+    pub fn reduce_now(&mut self) {
+        while !self.is_valid() {
+            self.0.sub_noborrow(&P::MODULUS);
+        }
+    }
+
+    /// Returns `-self` without branching on the value of `self`, unlike `Neg`.
+    /// Meant for secret-dependent arithmetic; use `Neg` on public data.
+    #[inline]
+    pub fn neg_ct(&self) -> Self {
+        let mask = !fa::ct_is_zero_mask(self.0.as_ref());
+        let mut tmp = P::MODULUS;
+        tmp.sub_noborrow(&self.0);
+        for limb in tmp.as_mut() {
+            *limb &= mask;
+        }
+        Fp320::<P>(tmp, PhantomData)
+    }
+
+    /// Returns `self - other` without branching on the operands, unlike `Sub`:
+    /// the modulus is always added back, masked by the borrow of the subtraction.
+    /// Meant for secret-dependent arithmetic; use `Sub` on public data.
+    #[inline]
+    pub fn sub_ct(&self, other: &Self) -> Self {
+        let mut tmp = self.0;
+        let mask = 0u64.wrapping_sub(tmp.sub_noborrow(&other.0) as u64);
+        let mut modulus = P::MODULUS;
+        for limb in modulus.as_mut() {
+            *limb &= mask;
+        }
+        tmp.add_nocarry(&modulus);
+        Fp320::<P>(tmp, PhantomData)
+    }
+
     #[inline]
     fn mont_reduce(
         &mut self,
@@ -123,6 +173,8 @@ impl<P: Fp320Parameters> Fp320<P> {
 }
 
 impl<P: Fp320Parameters> Field for Fp320<P> {
+    const SERIALIZED_SIZE: usize = 8 * 5;
+
     #[inline]
     fn zero() -> Self {
         Fp320::<P>(BigInteger::from(0), PhantomData)
@@ -142,7 +194,7 @@ impl<P: Fp320Parameters> Field for Fp320<P> {
 
     #[inline]
     fn double_in_place(&mut self) -> &mut Self {
-        // This cannot exceed the backing capacity.
+        // This cannot exceed the backing capacity, as `REPR_SHAVE_BITS >= 1`.
         self.0.mul2();
         // However, it may need to be reduced.
         self.reduce();
@@ -390,6 +442,11 @@ impl<P: Fp320Parameters> SquareRootField for Fp320<P> {
         sqrt_impl!(Self, P, self)
     }
 
+    #[inline]
+    fn sqrt_unchecked(&self) -> Self {
+        sqrt_unchecked_impl!(Self, P, self)
+    }
+
     fn sqrt_in_place(&mut self) -> Option<&mut Self> {
         if let Some(sqrt) = self.sqrt() {
             *self = sqrt;
@@ -420,8 +477,11 @@ impl_prime_field_from_int!(Fp320, u64, Fp320Parameters);
 impl_prime_field_from_int!(Fp320, u32, Fp320Parameters);
 impl_prime_field_from_int!(Fp320, u16, Fp320Parameters);
 impl_prime_field_from_int!(Fp320, u8, Fp320Parameters);
+impl_prime_field_from_int!(Fp320, i64, Fp320Parameters);
+impl_prime_field_from_int!(Fp320, i32, Fp320Parameters);
 
 impl_prime_field_standard_sample!(Fp320, Fp320Parameters);
+impl_field_sum_and_product!(Fp320, Fp320Parameters);
 
 impl<P: Fp320Parameters> ToBytes for Fp320<P> {
     #[inline]
@@ -456,12 +516,20 @@ impl<P: Fp320Parameters> FromStr for Fp320<P> {
 
     /// Interpret a string of numbers as a (congruent) prime field element.
     /// Does not accept unnecessary leading zeroes or a blank string.
+    /// A `0x`-prefixed string is read as hexadecimal instead, and must be
+    /// smaller than the modulus.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if s.is_empty() {
-            println!("Is empty!");
             return Err(());
         }
 
+        if s.starts_with("0x") {
+            return BigInteger::from_hex_str(&s[2..])
+                .filter(|repr| *repr < P::MODULUS)
+                .map(Self::from_repr)
+                .ok_or(());
+        }
+
         if s == "0" {
             return Ok(Self::zero());
         }
@@ -489,7 +557,6 @@ impl<P: Fp320Parameters> FromStr for Fp320<P> {
                     )));
                 },
                 None => {
-                    println!("Not valid digit!");
                     return Err(());
                 },
             }
@@ -509,6 +576,17 @@ impl<P: Fp320Parameters> Display for Fp320<P> {
     }
 }
 
+/// Prints the canonical value as fixed-width big-endian hexadecimal, see
+/// `PrimeField::to_hex_string`, prefixed by `0x` in the alternate form (`{:#x}`).
+impl<P: Fp320Parameters> LowerHex for Fp320<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", self.to_hex_string())
+    }
+}
+
 impl<P: Fp320Parameters> Neg for Fp320<P> {
     type Output = Self;
     #[inline]
@@ -571,7 +649,7 @@ impl<'a, P: Fp320Parameters> Div<&'a Fp320<P>> for Fp320<P> {
 impl<'a, P: Fp320Parameters> AddAssign<&'a Self> for Fp320<P> {
     #[inline]
     fn add_assign(&mut self, other: &Self) {
-        // This cannot exceed the backing capacity.
+        // This cannot exceed the backing capacity, as `REPR_SHAVE_BITS >= 1`.
         self.0.add_nocarry(&other.0);
         // However, it may need to be reduced
         self.reduce();
@@ -1,11 +1,11 @@
-use std::{
+use core::{
     cmp::{Ord, Ordering, PartialOrd},
-    fmt::{Display, Formatter, Result as FmtResult},
-    io::{Read, Result as IoResult, Write, Error as IoError, ErrorKind},
+    fmt::{Display, Formatter, LowerHex, Result as FmtResult},
     marker::PhantomData,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
     str::FromStr,
 };
+use crate::io::{Read, Result as IoResult, Write, Error as IoError, ErrorKind};
 
 use crate::{
     biginteger::{arithmetic as fa, BigInteger as _BigInteger, BigInteger384 as BigInteger},
@@ -51,6 +51,56 @@ impl<P: Fp384Parameters> Fp384<P> {
         }
     }
 
+    /// Adds `other` to `self` without reducing the result modulo `MODULUS`.
+    /// The sum of a reduced element and at most `2^REPR_SHAVE_BITS - 1` further
+    /// reduced elements fits into the backing `BigInteger`; beyond that, the
+    /// representation silently wraps around. Until `reduce_now` is called, `self`
+    /// must not be used as an operand of any other field operation, nor compared.
+    #[inline]
+    pub fn add_assign_unreduced(&mut self, other: &Self) {
+        self.0.add_nocarry(&other.0);
+    }
+
+    /// Fully reduces the representation of `self` after a sequence of
+    /// `add_assign_unreduced`. Costs one subtraction of `MODULUS` per multiple of
+    /// it exceeded: while the representation is below `2 * MODULUS` (e.g. after a
+    /// single unreduced addition of reduced elements) this is the same as the
+    /// reduction performed by `add_assign`.
+    #[inline]
+    pub fn reduce_now(&mut self) {
+        while !self.is_valid() {
+            self.0.sub_noborrow(&P::MODULUS);
+        }
+    }
+
+    /// Returns `-self` without branching on the value of `self`, unlike `Neg`.
+    /// Meant for secret-dependent arithmetic; use `Neg` on public data.
+    #[inline]
+    pub fn neg_ct(&self) -> Self {
+        let mask = !fa::ct_is_zero_mask(self.0.as_ref());
+        let mut tmp = P::MODULUS;
+        tmp.sub_noborrow(&self.0);
+        for limb in tmp.as_mut() {
+            *limb &= mask;
+        }
+        Fp384::<P>(tmp, PhantomData)
+    }
+
+    /// Returns `self - other` without branching on the operands, unlike `Sub`:
+    /// the modulus is always added back, masked by the borrow of the subtraction.
+    /// Meant for secret-dependent arithmetic; use `Sub` on public data.
+    #[inline]
+    pub fn sub_ct(&self, other: &Self) -> Self {
+        let mut tmp = self.0;
+        let mask = 0u64.wrapping_sub(tmp.sub_noborrow(&other.0) as u64);
+        let mut modulus = P::MODULUS;
+        for limb in modulus.as_mut() {
+            *limb &= mask;
+        }
+        tmp.add_nocarry(&modulus);
+        Fp384::<P>(tmp, PhantomData)
+    }
+
     #[inline]
     fn mont_reduce(
         &mut self,
@@ -141,6 +191,8 @@ impl<P: Fp384Parameters> Fp384<P> {
 }
 
 impl<P: Fp384Parameters> Field for Fp384<P> {
+    const SERIALIZED_SIZE: usize = 8 * 6;
+
     #[inline]
     fn zero() -> Self {
         Fp384::<P>(BigInteger::from(0), PhantomData)
@@ -160,7 +212,7 @@ impl<P: Fp384Parameters> Field for Fp384<P> {
 
     #[inline]
     fn double_in_place(&mut self) -> &mut Self {
-        // This cannot exceed the backing capacity.
+        // This cannot exceed the backing capacity, as `REPR_SHAVE_BITS >= 1`.
         self.0.mul2();
         // However, it may need to be reduced.
         self.reduce();
@@ -424,6 +476,11 @@ impl<P: Fp384Parameters> SquareRootField for Fp384<P> {
         sqrt_impl!(Self, P, self)
     }
 
+    #[inline]
+    fn sqrt_unchecked(&self) -> Self {
+        sqrt_unchecked_impl!(Self, P, self)
+    }
+
     fn sqrt_in_place(&mut self) -> Option<&mut Self> {
         (*self).sqrt().map(|sqrt| {
             *self = sqrt;
@@ -451,8 +508,11 @@ impl_prime_field_from_int!(Fp384, u64, Fp384Parameters);
 impl_prime_field_from_int!(Fp384, u32, Fp384Parameters);
 impl_prime_field_from_int!(Fp384, u16, Fp384Parameters);
 impl_prime_field_from_int!(Fp384, u8, Fp384Parameters);
+impl_prime_field_from_int!(Fp384, i64, Fp384Parameters);
+impl_prime_field_from_int!(Fp384, i32, Fp384Parameters);
 
 impl_prime_field_standard_sample!(Fp384, Fp384Parameters);
+impl_field_sum_and_product!(Fp384, Fp384Parameters);
 
 impl<P: Fp384Parameters> ToBytes for Fp384<P> {
     #[inline]
@@ -487,12 +547,20 @@ impl<P: Fp384Parameters> FromStr for Fp384<P> {
 
     /// Interpret a string of numbers as a (congruent) prime field element.
     /// Does not accept unnecessary leading zeroes or a blank string.
+    /// A `0x`-prefixed string is read as hexadecimal instead, and must be
+    /// smaller than the modulus.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if s.is_empty() {
-            println!("Is empty!");
             return Err(());
         }
 
+        if s.starts_with("0x") {
+            return BigInteger::from_hex_str(&s[2..])
+                .filter(|repr| *repr < P::MODULUS)
+                .map(Self::from_repr)
+                .ok_or(());
+        }
+
         if s == "0" {
             return Ok(Self::zero());
         }
@@ -520,7 +588,6 @@ impl<P: Fp384Parameters> FromStr for Fp384<P> {
                     )));
                 },
                 None => {
-                    println!("Not valid digit!");
                     return Err(());
                 },
             }
@@ -540,6 +607,17 @@ impl<P: Fp384Parameters> Display for Fp384<P> {
     }
 }
 
+/// Prints the canonical value as fixed-width big-endian hexadecimal, see
+/// `PrimeField::to_hex_string`, prefixed by `0x` in the alternate form (`{:#x}`).
+impl<P: Fp384Parameters> LowerHex for Fp384<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", self.to_hex_string())
+    }
+}
+
 impl<P: Fp384Parameters> Neg for Fp384<P> {
     type Output = Self;
     #[inline]
@@ -602,7 +680,7 @@ impl<'a, P: Fp384Parameters> Div<&'a Fp384<P>> for Fp384<P> {
 impl<'a, P: Fp384Parameters> AddAssign<&'a Self> for Fp384<P> {
     #[inline]
     fn add_assign(&mut self, other: &Self) {
-        // This cannot exceed the backing capacity.
+        // This cannot exceed the backing capacity, as `REPR_SHAVE_BITS >= 1`.
         self.0.add_nocarry(&other.0);
         // However, it may need to be reduced
         self.reduce();
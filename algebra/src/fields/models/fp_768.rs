@@ -1,11 +1,11 @@
-use std::{
+use core::{
     cmp::{Ord, Ordering, PartialOrd},
-    fmt::{Display, Formatter, Result as FmtResult},
-    io::{Read, Result as IoResult, Write, Error as IoError, ErrorKind},
+    fmt::{Display, Formatter, LowerHex, Result as FmtResult},
     marker::PhantomData,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
     str::FromStr,
 };
+use crate::io::{Read, Result as IoResult, Write, Error as IoError, ErrorKind};
 
 use crate::{biginteger::{arithmetic as fa, BigInteger as _BigInteger, BigInteger768 as BigInteger}, bytes::{FromBytes, ToBytes}, fields::{Field, FpParameters, LegendreSymbol, PrimeField, SquareRootField}, MulShort};
 
@@ -47,6 +47,56 @@ impl<P: Fp768Parameters> Fp768<P> {
         }
     }
 
+    /// Adds `other` to `self` without reducing the result modulo `MODULUS`.
+    /// The sum of a reduced element and at most `2^REPR_SHAVE_BITS - 1` further
+    /// reduced elements fits into the backing `BigInteger`; beyond that, the
+    /// representation silently wraps around. Until `reduce_now` is called, `self`
+    /// must not be used as an operand of any other field operation, nor compared.
+    #[inline]
+    pub fn add_assign_unreduced(&mut self, other: &Self) {
+        self.0.add_nocarry(&other.0);
+    }
+
+    /// Fully reduces the representation of `self` after a sequence of
+    /// `add_assign_unreduced`. Costs one subtraction of `MODULUS` per multiple of
+    /// it exceeded: while the representation is below `2 * MODULUS` (e.g. after a
+    /// single unreduced addition of reduced elements) this is the same as the
+    /// reduction performed by `add_assign`.
+    #[inline]
+    pub fn reduce_now(&mut self) {
+        while !self.is_valid() {
+            self.0.sub_noborrow(&P::MODULUS);
+        }
+    }
+
+    /// Returns `-self` without branching on the value of `self`, unlike `Neg`.
+    /// Meant for secret-dependent arithmetic; use `Neg` on public data.
+    #[inline]
+    pub fn neg_ct(&self) -> Self {
+        let mask = !fa::ct_is_zero_mask(self.0.as_ref());
+        let mut tmp = P::MODULUS;
+        tmp.sub_noborrow(&self.0);
+        for limb in tmp.as_mut() {
+            *limb &= mask;
+        }
+        Fp768::<P>(tmp, PhantomData)
+    }
+
+    /// Returns `self - other` without branching on the operands, unlike `Sub`:
+    /// the modulus is always added back, masked by the borrow of the subtraction.
+    /// Meant for secret-dependent arithmetic; use `Sub` on public data.
+    #[inline]
+    pub fn sub_ct(&self, other: &Self) -> Self {
+        let mut tmp = self.0;
+        let mask = 0u64.wrapping_sub(tmp.sub_noborrow(&other.0) as u64);
+        let mut modulus = P::MODULUS;
+        for limb in modulus.as_mut() {
+            *limb &= mask;
+        }
+        tmp.add_nocarry(&modulus);
+        Fp768::<P>(tmp, PhantomData)
+    }
+
     fn mont_reduce(
         &mut self,
         r0: u64,
@@ -282,6 +332,8 @@ impl<P: Fp768Parameters> Fp768<P> {
 }
 
 impl<P: Fp768Parameters> Field for Fp768<P> {
+    const SERIALIZED_SIZE: usize = 8 * 12;
+
     #[inline]
     fn zero() -> Self {
         Fp768::<P>(BigInteger::from(0), PhantomData)
@@ -301,7 +353,7 @@ impl<P: Fp768Parameters> Field for Fp768<P> {
 
     #[inline]
     fn double_in_place(&mut self) -> &mut Self {
-        // This cannot exceed the backing capacity.
+        // This cannot exceed the backing capacity, as `REPR_SHAVE_BITS >= 1`.
         self.0.mul2();
         // However, it may need to be reduced.
         self.reduce();
@@ -749,6 +801,11 @@ impl<P: Fp768Parameters> SquareRootField for Fp768<P> {
         sqrt_impl!(Self, P, self)
     }
 
+    #[inline]
+    fn sqrt_unchecked(&self) -> Self {
+        sqrt_unchecked_impl!(Self, P, self)
+    }
+
     fn sqrt_in_place(&mut self) -> Option<&mut Self> {
         if let Some(sqrt) = self.sqrt() {
             *self = sqrt;
@@ -778,8 +835,11 @@ impl_prime_field_from_int!(Fp768, u64, Fp768Parameters);
 impl_prime_field_from_int!(Fp768, u32, Fp768Parameters);
 impl_prime_field_from_int!(Fp768, u16, Fp768Parameters);
 impl_prime_field_from_int!(Fp768, u8, Fp768Parameters);
+impl_prime_field_from_int!(Fp768, i64, Fp768Parameters);
+impl_prime_field_from_int!(Fp768, i32, Fp768Parameters);
 
 impl_prime_field_standard_sample!(Fp768, Fp768Parameters);
+impl_field_sum_and_product!(Fp768, Fp768Parameters);
 
 impl<P: Fp768Parameters> ToBytes for Fp768<P> {
     #[inline]
@@ -814,12 +874,20 @@ impl<P: Fp768Parameters> FromStr for Fp768<P> {
 
     /// Interpret a string of numbers as a (congruent) prime field element.
     /// Does not accept unnecessary leading zeroes or a blank string.
+    /// A `0x`-prefixed string is read as hexadecimal instead, and must be
+    /// smaller than the modulus.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if s.is_empty() {
-            println!("Is empty!");
             return Err(());
         }
 
+        if s.starts_with("0x") {
+            return BigInteger::from_hex_str(&s[2..])
+                .filter(|repr| *repr < P::MODULUS)
+                .map(Self::from_repr)
+                .ok_or(());
+        }
+
         if s == "0" {
             return Ok(Self::zero());
         }
@@ -847,7 +915,6 @@ impl<P: Fp768Parameters> FromStr for Fp768<P> {
                     )));
                 },
                 None => {
-                    println!("Not valid digit!");
                     return Err(());
                 },
             }
@@ -867,6 +934,17 @@ impl<P: Fp768Parameters> Display for Fp768<P> {
     }
 }
 
+/// Prints the canonical value as fixed-width big-endian hexadecimal, see
+/// `PrimeField::to_hex_string`, prefixed by `0x` in the alternate form (`{:#x}`).
+impl<P: Fp768Parameters> LowerHex for Fp768<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", self.to_hex_string())
+    }
+}
+
 impl<P: Fp768Parameters> Neg for Fp768<P> {
     type Output = Self;
     #[inline]
@@ -929,7 +1007,7 @@ impl<'a, P: Fp768Parameters> Div<&'a Fp768<P>> for Fp768<P> {
 impl<'a, P: Fp768Parameters> AddAssign<&'a Self> for Fp768<P> {
     #[inline]
     fn add_assign(&mut self, other: &Self) {
-        // This cannot exceed the backing capacity.
+        // This cannot exceed the backing capacity, as `REPR_SHAVE_BITS >= 1`.
         self.0.add_nocarry(&other.0);
         // However, it may need to be reduced
         self.reduce();
@@ -952,6 +1030,10 @@ impl<'a, P: Fp768Parameters> MulShort for Fp768<P> {
 
     #[inline]
     fn mul_short(self, other: &Self) -> Self {
+        debug_assert!(
+            (self.0).0[1..].iter().all(|&limb| limb == 0),
+            "mul_short: the internal representation of self must fit into a single 64-bit limb"
+        );
         // This operation is used to compute the Partial Montgomery Multiplication
         // with Montgomery constant R = 2^64
         // It performs the operation
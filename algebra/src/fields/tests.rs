@@ -1,6 +1,11 @@
 use crate::{
-    fields::{Field, LegendreSymbol, PrimeField, SquareRootField},
-    ToBytes, to_bytes,
+    biginteger::BigInteger,
+    fields::{
+        field_info, Field, Fp256, Fp256Parameters, Fp320, Fp320Parameters, Fp384, Fp384Parameters,
+        Fp768, Fp768Parameters, Fp832, Fp832Parameters, FpParameters, LegendreSymbol, PrimeField,
+        ReprCached, SquareRootField,
+    },
+    FromBytes, ToBits, ToBytes, to_bytes,
 };
 use rand::{Rng, SeedableRng};
 use rand_xorshift::XorShiftRng;
@@ -108,6 +113,47 @@ fn random_squaring_tests<F: Field, R: Rng>(rng: &mut R) {
     }
 }
 
+fn random_pow2_tests<F: Field, R: Rng>(rng: &mut R) {
+    for _ in 0..ITERATIONS {
+        let a = F::rand(rng);
+        let mut b = a;
+        for k in 0..10 {
+            assert_eq!(a.pow2(k), b);
+            assert_eq!(a.pow2(k), a.pow(&[1u64 << k]));
+            b.square_in_place();
+        }
+    }
+}
+
+fn random_pow_tests<F: Field, R: Rng>(rng: &mut R) {
+    for _ in 0..ITERATIONS {
+        let a = F::rand(rng);
+        let exp: [u64; 4] = [rng.gen(), rng.gen(), rng.gen(), rng.gen()];
+        let expected = a.pow(exp);
+
+        assert_eq!(a.pow(&exp), expected);
+        assert_eq!(a.pow(&exp[..]), expected);
+        assert_eq!(a.pow(exp.to_vec()), expected);
+        // Leading zero limbs do not change the result
+        assert_eq!(a.pow([exp[0], exp[1], exp[2], exp[3], 0, 0]), expected);
+
+        for window in 1..6 {
+            let table = a.odd_powers(window);
+            assert_eq!(F::pow_with_table(&table, exp), expected);
+            assert_eq!(F::pow_with_table(&table, exp.to_vec()), expected);
+            assert!(F::pow_with_table(&table, [0u64]).is_one());
+            assert_eq!(F::pow_with_table(&table, [1u64]), a);
+        }
+
+        let mut b = F::one();
+        for e in 0..20u64 {
+            assert_eq!(a.pow([e]), b);
+            assert_eq!(F::pow_with_table(&a.odd_powers(3), [e]), b);
+            b *= &a;
+        }
+    }
+}
+
 fn random_expansion_tests<F: Field, R: Rng>(rng: &mut R) {
     for _ in 0..ITERATIONS {
         // Compare (a + b)(c + d) and (a*c + b*c + a*d + b*d)
@@ -181,6 +227,7 @@ fn random_serialization_tests<F: Field, R: Rng>(rng: &mut R) {
         //Byte serialization test
         {
             let a_serialized = to_bytes!(a).unwrap();
+            assert_eq!(a_serialized.len(), F::SERIALIZED_SIZE);
 
             //Attempt to serialize in a buffer whose size is smaller than the expected one
             let mut serialized = vec![0u8; a_serialized.len() - 1];
@@ -199,6 +246,66 @@ fn random_serialization_tests<F: Field, R: Rng>(rng: &mut R) {
             assert_eq!(a, a_deserialized)
         }
     }
+
+    //Batch serialization test
+    {
+        let n = ITERATIONS as usize;
+        let elems = (0..n).map(|_| F::rand(rng)).collect::<Vec<_>>();
+        let mut serialized = vec![];
+        F::write_batch(&elems, &mut serialized).unwrap();
+        assert_eq!(serialized.len(), 8 + n * F::SERIALIZED_SIZE);
+        assert_eq!(F::read_batch(serialized.as_slice(), n).unwrap(), elems);
+
+        //Attempt to deserialize with a wrong count
+        assert!(F::read_batch(serialized.as_slice(), n - 1).is_err());
+        assert!(F::read_batch(serialized.as_slice(), n + 1).is_err());
+
+        //Attempt to deserialize a truncated batch
+        assert!(F::read_batch(&serialized[..serialized.len() - 1], n).is_err());
+
+        //Empty batch
+        let mut serialized = vec![];
+        F::write_batch(&[], &mut serialized).unwrap();
+        assert_eq!(serialized.len(), 8);
+        assert!(F::read_batch(serialized.as_slice(), 0).unwrap().is_empty());
+    }
+}
+
+fn random_signed_int_tests<F: Field, R: Rng>(rng: &mut R) {
+    assert!(F::from(0i64).is_zero());
+    assert!(F::from(0i32).is_zero());
+    assert_eq!(F::from(1i64), F::one());
+    assert_eq!(F::from(-1i64), -F::one());
+    assert_eq!(F::from(-1i32), -F::one());
+    assert_eq!(F::from(i64::min_value()), -F::from(1u64 << 63));
+    assert_eq!(F::from(i32::min_value()), -F::from(1u64 << 31));
+
+    for _ in 0..ITERATIONS {
+        let a: i64 = rng.gen();
+        let b: i32 = rng.gen();
+
+        // Consistency with the unsigned impls on nonnegative inputs
+        let a_abs = a.wrapping_abs() as u64;
+        let b_abs = b.wrapping_abs() as u32;
+        let (a_expected, b_expected) = (F::from(a_abs), F::from(b_abs));
+        assert_eq!(F::from(a), if a < 0 { -a_expected } else { a_expected });
+        assert_eq!(F::from(b), if b < 0 { -b_expected } else { b_expected });
+    }
+}
+
+fn random_sum_product_tests<F: Field, R: Rng>(rng: &mut R) {
+    let elems = (0..ITERATIONS).map(|_| F::rand(rng)).collect::<Vec<_>>();
+    let sum = elems.iter().fold(F::zero(), |acc, x| acc + x);
+    let product = elems.iter().fold(F::one(), |acc, x| acc * x);
+
+    assert_eq!(elems.iter().sum::<F>(), sum);
+    assert_eq!(elems.iter().cloned().sum::<F>(), sum);
+    assert_eq!(elems.iter().product::<F>(), product);
+    assert_eq!(elems.iter().cloned().product::<F>(), product);
+
+    // Empty sums and products
+    assert!(elems[..0].iter().sum::<F>().is_zero());
+    assert!(elems[..0].iter().product::<F>().is_one());
 }
 
 fn random_field_tests<F: Field>() {
@@ -211,7 +318,11 @@ fn random_field_tests<F: Field>() {
     random_inversion_tests::<F, _>(&mut rng);
     random_doubling_tests::<F, _>(&mut rng);
     random_squaring_tests::<F, _>(&mut rng);
+    random_pow2_tests::<F, _>(&mut rng);
+    random_pow_tests::<F, _>(&mut rng);
     random_expansion_tests::<F, _>(&mut rng);
+    random_signed_int_tests::<F, _>(&mut rng);
+    random_sum_product_tests::<F, _>(&mut rng);
 
     assert!(F::zero().is_zero());
     {
@@ -253,10 +364,12 @@ fn random_sqrt_tests<F: SquareRootField>() {
         let a = F::rand(&mut rng);
         let b = a.square();
         assert_eq!(b.legendre(), LegendreSymbol::QuadraticResidue);
+        assert_eq!(b.sqrt_unchecked(), b.sqrt().unwrap());
 
         let b = b.sqrt().unwrap();
         assert!(a == b || a == -b);
     }
+    assert!(F::zero().sqrt_unchecked().is_zero());
 
     let mut c = F::one();
     for _ in 0..ITERATIONS {
@@ -309,6 +422,54 @@ pub fn from_str_test<F: PrimeField>() {
     assert!(F::from_str("00000000000").is_err());
 }
 
+/// Checks the parsing of `0x`-prefixed hexadecimal strings by `FromStr`, and
+/// that it inverts `to_hex_string` and the `LowerHex` formatting.
+pub fn from_hex_str_test<F: PrimeField + core::fmt::LowerHex>() {
+    let dec = F::from_str("1234567890123456789012345678901234567890").map_err(|_| ()).unwrap();
+    let hex = F::from_str("0x3a0c92075c0dbf3b8acbc5f96ce3f0ad2").map_err(|_| ()).unwrap();
+    let mixed = F::from_str("0x3A0C92075c0dbf3b8ACBC5F96ce3f0ad2").map_err(|_| ()).unwrap();
+    assert_eq!(dec, hex);
+    assert_eq!(dec, mixed);
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    for _ in 0..ITERATIONS {
+        let n: u64 = rng.gen();
+        let a = F::from_str(&format!("0x{:x}", n)).map_err(|_| ()).unwrap();
+        let b = F::from_str(&format!("0x{:X}", n)).map_err(|_| ()).unwrap();
+        assert_eq!(a, F::from_repr(n.into()));
+        assert_eq!(b, F::from_repr(n.into()));
+    }
+
+    let to_hex = |repr: &F::BigInt| {
+        let limbs = repr.as_ref().iter().rev().map(|l| format!("{:016x}", l));
+        format!("0x{}", limbs.collect::<Vec<_>>().join(""))
+    };
+    let mut modulus_minus_one = F::Params::MODULUS;
+    modulus_minus_one.sub_noborrow(&F::BigInt::from(1));
+    assert_eq!(F::from_str(&to_hex(&modulus_minus_one)).map_err(|_| ()).unwrap(), -F::one());
+    assert!(F::from_str(&to_hex(&F::Params::MODULUS)).is_err());
+
+    assert!(F::from_str("0x0").map_err(|_| ()).unwrap().is_zero());
+    assert!(F::from_str("0x0001").map_err(|_| ()).unwrap().is_one());
+    assert!(F::from_str("0x").is_err());
+    assert!(F::from_str("0x12g4").is_err());
+    assert!(F::from_str("0x-1").is_err());
+    assert!(F::from_str(&format!("0x1{}", "0".repeat(16 * F::Params::MODULUS.as_ref().len()))).is_err());
+
+    let width = ((F::Params::MODULUS_BITS + 3) / 4) as usize;
+    assert_eq!(F::zero().to_hex_string(), "0".repeat(width));
+    assert_eq!(dec.to_hex_string().trim_start_matches('0'), "3a0c92075c0dbf3b8acbc5f96ce3f0ad2");
+    for _ in 0..ITERATIONS {
+        let a = F::rand(&mut rng);
+        let hex = a.to_hex_string();
+        assert_eq!(hex.len(), width);
+        assert_eq!(F::from_repr(F::BigInt::from_hex_str(&hex).unwrap()), a);
+        assert_eq!(F::from_str(&format!("0x{}", hex)).map_err(|_| ()).unwrap(), a);
+        assert_eq!(format!("{:x}", a), hex);
+        assert_eq!(F::from_str(&format!("{:#x}", a)).map_err(|_| ()).unwrap(), a);
+    }
+}
+
 pub fn field_test<F: Field>(a: F, b: F) {
     let zero = F::zero();
     assert_eq!(zero, zero);
@@ -376,13 +537,154 @@ pub fn field_test<F: Field>(a: F, b: F) {
     random_field_tests::<F>();
 }
 
-pub fn primefield_test<F: PrimeField>() {
+/// Textbook binary long division of `repr` by the modulus, returning the remainder.
+fn reduce_by_long_division<F: PrimeField>(mut repr: F::BigInt) -> F::BigInt {
+    let modulus = F::Params::MODULUS;
+    let shift = repr.as_ref().len() as u32 * 64 - modulus.num_bits();
+    let mut shifted_modulus = modulus;
+    shifted_modulus.muln(shift);
+    for _ in 0..=shift {
+        if repr >= shifted_modulus {
+            repr.sub_noborrow(&shifted_modulus);
+        }
+        shifted_modulus.div2();
+    }
+    repr
+}
+
+pub fn primefield_test<F: FpModel>() {
     let one = F::one();
+
+    // Exponents given as a `BigInteger` and as its limbs agree
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let a = F::rand(&mut rng);
+    let exp = F::rand(&mut rng).into_repr();
+    let expected = a.pow(exp.as_ref().to_vec());
+    assert_eq!(a.pow(exp), expected);
+    assert_eq!(a.pow(&exp), expected);
+    assert_eq!(F::pow_with_table(&a.odd_powers(4), exp), expected);
     assert_eq!(F::from_repr(one.into_repr()), one);
     assert_eq!(F::from_str("1").ok().unwrap(), one);
 
+    // from_random_bytes_mod_order reduces its input modulo the characteristic
+    let modulus_bytes = to_bytes!(F::Params::MODULUS).unwrap();
+    assert!(F::from_random_bytes_mod_order(&[]).is_zero());
+    assert!(F::from_random_bytes_mod_order(&modulus_bytes).is_zero());
+    let mut modulus_plus_one = F::Params::MODULUS;
+    modulus_plus_one.add_nocarry(&F::BigInt::from(1));
+    assert_eq!(F::from_random_bytes_mod_order(&to_bytes!(modulus_plus_one).unwrap()), one);
+    // (MODULUS * 2^8 + 2) mod MODULUS = 2
+    let mut shifted_modulus = vec![2u8];
+    shifted_modulus.extend_from_slice(&modulus_bytes);
+    assert_eq!(F::from_random_bytes_mod_order(&shifted_modulus), one.double());
+    let a = F::from(123456789u64);
+    assert_eq!(F::from_random_bytes_mod_order(&to_bytes!(a.into_repr()).unwrap()), a);
+
+    // reduce_repr computes the true reduction, whereas from_repr zeroes non-canonical inputs
+    assert!(F::reduce_repr(F::Params::MODULUS).is_zero());
+    assert_eq!(F::reduce_repr(modulus_plus_one), one);
+    assert!(F::from_repr(modulus_plus_one).is_zero());
+    assert_eq!(F::reduce_repr(a.into_repr()), a);
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    for _ in 0..ITERATIONS {
+        let mut repr = F::BigInt::default();
+        for limb in repr.as_mut().iter_mut() {
+            *limb = rng.gen();
+        }
+        let reduced = reduce_by_long_division::<F>(repr);
+        assert!(reduced < F::Params::MODULUS);
+        assert_eq!(F::reduce_repr(repr), F::from_repr(reduced));
+        if repr >= F::Params::MODULUS {
+            assert!(F::from_repr(repr).is_zero());
+        }
+    }
+
+    // get_root_of_unity(n) has exact multiplicative order n
+    let two_adicity = F::Params::TWO_ADICITY;
+    for log_n in 0..=two_adicity {
+        let n = 1usize << log_n;
+        let root = F::get_root_of_unity(n).unwrap();
+        assert_eq!(root.pow2(log_n), one);
+        if log_n > 0 {
+            assert_ne!(root.pow2(log_n - 1), one);
+        }
+    }
+    assert_eq!(F::get_root_of_unity(1 << two_adicity).unwrap(), F::root_of_unity());
+    assert!(F::get_root_of_unity(0).is_none());
+    assert!(F::get_root_of_unity(3).is_none());
+    assert!(F::get_root_of_unity(1 << (two_adicity + 1)).is_none());
+
+    // The dedicated squaring agrees with multiplication, also close to the modulus
+    let mut half_modulus = F::Params::MODULUS;
+    half_modulus.div2();
+    let half_modulus = F::from_repr(half_modulus);
+    for a in [F::zero(), one, -one, -one.double(), half_modulus, half_modulus + &one].iter() {
+        assert_eq!(a.square(), *a * a);
+    }
+
     random_serialization_tests::<F, _>(&mut rng);
+
+    let elements = (0..ITERATIONS).map(|_| F::rand(&mut rng)).collect::<Vec<_>>();
+    let reprs = F::batch_into_repr(elements.as_slice());
+    assert_eq!(reprs.len(), elements.len());
+    for (elem, repr) in elements.iter().zip(reprs.iter()) {
+        assert_eq!(elem.into_repr(), *repr);
+    }
+    assert!(F::batch_into_repr(&[]).is_empty());
+
+    let other_elements = (0..ITERATIONS).map(|_| F::rand(&mut rng)).collect::<Vec<_>>();
+    let mut expected = F::zero();
+    for (a, b) in elements.iter().zip(other_elements.iter()) {
+        expected += &(*a * b);
+    }
+    assert_eq!(F::dot_product(&elements, &other_elements).unwrap(), expected);
+    assert_eq!(F::dot_product(&[], &[]).unwrap(), F::zero());
+    assert!(F::dot_product(&elements, &other_elements[1..]).is_err());
+
+    // ReprCached agrees with the wrapped element on ordering, bytes and bits
+    for (a, b) in elements.iter().zip(other_elements.iter()) {
+        let (a_cached, b_cached) = (ReprCached::new(*a), ReprCached::new(*b));
+        assert_eq!(a_cached.value(), *a);
+        assert_eq!(a_cached.cmp(&b_cached), a.cmp(b));
+        assert_eq!(a_cached.cmp(&a_cached), a.cmp(a));
+        assert_eq!(to_bytes!(a_cached).unwrap(), to_bytes!(a).unwrap());
+        assert_eq!(a_cached.write_bits(), a.write_bits());
+    }
+    let mut sorted = elements.clone();
+    sorted.sort();
+    let mut sorted_cached = elements.into_iter().map(ReprCached::from).collect::<Vec<_>>();
+    sorted_cached.sort();
+    assert_eq!(sorted_cached.iter().map(ReprCached::value).collect::<Vec<_>>(), sorted);
+
+    field_info_test::<F>();
+    from_hex_str_test::<F>();
+    square_test::<F>();
+    double_test::<F>();
+    ct_test::<F>();
+}
+
+/// Checks the constants returned by `field_info` against their definitions, and
+/// their `Display` output against the one of the underlying big integers.
+pub fn field_info_test<F: PrimeField>() {
+    let info = field_info::<F>();
+    assert_eq!(info.modulus, F::Params::MODULUS);
+    assert_eq!(info.modulus_bits as usize, F::size_in_bits());
+    assert_eq!(info.two_adicity, F::Params::TWO_ADICITY);
+    assert!(info.r < info.modulus && info.r2 < info.modulus);
+    // R = 2^(64 * limbs) mod MODULUS
+    let limbs = info.r.as_ref().len() as u64;
+    assert_eq!(F::from_repr(info.r), F::from(2u64).pow(&[64 * limbs]));
+    // R2 = R^2 mod MODULUS, both read as plain integers
+    assert_eq!(F::from_repr(info.r).square(), F::from_repr(info.r2));
+    // INV = -MODULUS^{-1} mod 2^64
+    assert_eq!(info.modulus.as_ref()[0].wrapping_mul(info.inv), u64::max_value());
+
+    let expected = format!(
+        "MODULUS = {}\nMODULUS_BITS = {}\nR = {}\nR2 = {}\nINV = 0x{:016x}\nTWO_ADICITY = {}",
+        F::Params::MODULUS, F::Params::MODULUS_BITS, F::Params::R, F::Params::R2,
+        F::Params::INV, F::Params::TWO_ADICITY,
+    );
+    assert_eq!(info.to_string(), expected);
 }
 
 pub fn sqrt_field_test<F: SquareRootField>(elem: F) {
@@ -415,3 +717,189 @@ pub fn frobenius_test<F: Field, C: AsRef<[u64]>>(characteristic: C, maxpower: us
         }
     }
 }
+
+/// Checks the dedicated squaring against the generic multiplication, on random
+/// elements as well as on the boundaries of the field.
+pub fn square_test<F: PrimeField>() {
+    let one = F::one();
+    let mut modulus_minus_one = F::Params::MODULUS;
+    modulus_minus_one.sub_noborrow(&F::BigInt::from(1));
+    let modulus_minus_one = F::from_repr(modulus_minus_one);
+    assert_eq!(modulus_minus_one, -one);
+
+    for a in [F::zero(), one, one.double(), modulus_minus_one, -one.double()].iter() {
+        assert_eq!(a.square(), *a * a);
+        let mut b = *a;
+        b.square_in_place();
+        assert_eq!(b, *a * a);
+    }
+    assert!(F::zero().square().is_zero());
+    assert!(one.square().is_one());
+    assert!(modulus_minus_one.square().is_one());
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    for _ in 0..2000 {
+        let a = F::rand(&mut rng);
+        assert_eq!(a.square(), a * &a);
+    }
+}
+
+/// Seed from which `known_answer_vectors` derives the inputs of the checked-in vectors.
+pub const KAT_SEED: u64 = 1231275789u64;
+
+/// Number of input pairs recorded in the checked-in known-answer vectors.
+pub const KAT_SAMPLES: usize = 8;
+
+/// The values recorded for each input pair `(a, b)`, in the order they are serialized.
+pub const KAT_VALUES: [&str; 8] = ["a", "b", "a + b", "a - b", "a * b", "a^2", "a^-1", "sqrt(a)"];
+
+/// One step of SplitMix64. The known-answer inputs are derived from it rather than from
+/// `XorShiftRng`, so that they can be reproduced outside of this crate.
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E3779B97F4A7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^ (z >> 31)
+}
+
+/// Samples a field element by rejection, filling the limbs of the representation
+/// least significant first and shaving the excess top bits.
+fn known_answer_sample<F: PrimeField>(state: &mut u64) -> F {
+    loop {
+        let mut repr = F::BigInt::default();
+        for limb in repr.as_mut().iter_mut() {
+            *limb = splitmix64(state);
+        }
+        *repr.as_mut().last_mut().unwrap() &= 0xffffffffffffffff >> F::Params::REPR_SHAVE_BITS;
+        if repr < F::Params::MODULUS {
+            return F::from_repr(repr);
+        }
+    }
+}
+
+/// Computes the known-answer vectors of `F`: for each of `samples` input pairs `(a, b)`
+/// derived from `seed`, the serializations of the values listed in `KAT_VALUES`.
+/// The inverse of zero and the square root of a non-residue are recorded as zero,
+/// and of the two square roots the smaller one is recorded.
+///
+/// The checked-in vectors are regenerated with
+/// `std::fs::write(path, known_answer_vectors::<F>(KAT_SEED, KAT_SAMPLES))`.
+pub fn known_answer_vectors<F: PrimeField + SquareRootField>(seed: u64, samples: usize) -> Vec<u8> {
+    let mut state = seed;
+    let mut vectors = vec![];
+    for _ in 0..samples {
+        let a: F = known_answer_sample(&mut state);
+        let b: F = known_answer_sample(&mut state);
+        let inverse = a.inverse().unwrap_or(F::zero());
+        let sqrt = a.sqrt().map(|r| std::cmp::min(r, -r)).unwrap_or(F::zero());
+        for value in [a, b, a + &b, a - &b, a * &b, a.square(), inverse, sqrt].iter() {
+            value.write(&mut vectors).unwrap();
+        }
+    }
+    vectors
+}
+
+/// Checks the arithmetic of `F` against the known-answer vectors stored at `path`,
+/// see `known_answer_vectors`.
+pub fn known_answer_tests<F: PrimeField + SquareRootField>(path: &str) {
+    let expected = std::fs::read(path).unwrap();
+    let computed = known_answer_vectors::<F>(KAT_SEED, KAT_SAMPLES);
+    assert_eq!(expected.len(), computed.len());
+
+    let size = computed.len() / (KAT_SAMPLES * KAT_VALUES.len());
+    for (i, (e, c)) in expected.chunks(size).zip(computed.chunks(size)).enumerate() {
+        assert_eq!(
+            e, c,
+            "sample {}: {} differs from the known answer",
+            i / KAT_VALUES.len(),
+            KAT_VALUES[i % KAT_VALUES.len()]
+        );
+    }
+}
+
+/// Checks doubling on the boundaries of the field, where the unreduced double
+/// is closest to overflowing the backing representation.
+pub fn double_test<F: PrimeField>() {
+    assert!(F::Params::REPR_SHAVE_BITS >= 1);
+
+    let one = F::one();
+    let two = F::from(2u64);
+    let mut modulus_minus_one_div_two = F::Params::MODULUS;
+    modulus_minus_one_div_two.div2();
+    let modulus_minus_one_div_two = F::from_repr(modulus_minus_one_div_two);
+
+    for a in [
+        -one,
+        -two,
+        modulus_minus_one_div_two,
+        modulus_minus_one_div_two + &one,
+        modulus_minus_one_div_two - &one,
+    ]
+    .iter()
+    {
+        let mut b = *a;
+        b.double_in_place();
+        assert_eq!(b, *a * &two);
+        assert_eq!(a.double(), *a * &two);
+        assert!(b.into_repr() < F::Params::MODULUS);
+    }
+    // 2 * (p - 1) = p - 2 and 2 * (p + 1)/2 = 1
+    assert_eq!((-one).double(), -two);
+    assert_eq!((modulus_minus_one_div_two + &one).double(), one);
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let mut elems = (0..ITERATIONS).map(|_| F::rand(&mut rng)).collect::<Vec<_>>();
+    let doubled = elems.iter().map(|e| e.double()).collect::<Vec<_>>();
+    F::batch_double(&mut elems);
+    assert_eq!(elems, doubled);
+}
+
+/// The prime field models checked by `primefield_test`. The constant-time
+/// `neg_ct` and `sub_ct` are inherent to the models, hence forwarded by this trait.
+pub trait FpModel: PrimeField + core::fmt::LowerHex {
+    fn neg_ct(&self) -> Self;
+    fn sub_ct(&self, other: &Self) -> Self;
+}
+
+macro_rules! impl_fp_model {
+    ($Fp:ident, $FpParameters:ident) => {
+        impl<P: $FpParameters> FpModel for $Fp<P> {
+            fn neg_ct(&self) -> Self {
+                $Fp::<P>::neg_ct(self)
+            }
+
+            fn sub_ct(&self, other: &Self) -> Self {
+                $Fp::<P>::sub_ct(self, other)
+            }
+        }
+    };
+}
+
+impl_fp_model!(Fp256, Fp256Parameters);
+impl_fp_model!(Fp320, Fp320Parameters);
+impl_fp_model!(Fp384, Fp384Parameters);
+impl_fp_model!(Fp768, Fp768Parameters);
+impl_fp_model!(Fp832, Fp832Parameters);
+
+/// Checks `neg_ct` and `sub_ct` against `Neg` and `Sub`.
+pub fn ct_test<F: FpModel>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let zero = F::zero();
+    let one = F::one();
+    assert!(zero.neg_ct().is_zero());
+    assert_eq!(one.neg_ct(), -one);
+    assert_eq!(zero.sub_ct(&one), -one);
+    assert!(one.sub_ct(&one).is_zero());
+
+    for _ in 0..ITERATIONS {
+        let a = F::rand(&mut rng);
+        let b = F::rand(&mut rng);
+        assert_eq!(a.neg_ct(), -a);
+        assert_eq!(a.sub_ct(&b), a - &b);
+        assert_eq!(b.sub_ct(&a), b - &a);
+        assert!(a.sub_ct(&a).is_zero());
+        assert_eq!(zero.sub_ct(&a), -a);
+        assert_eq!(a.sub_ct(&zero), a);
+    }
+}
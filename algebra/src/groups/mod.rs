@@ -1,6 +1,6 @@
 use crate::BitIterator;
 use crate::UniformRand;
-use std::{
+use core::{
     fmt::{Debug, Display},
     hash::Hash,
     ops::{Add, AddAssign, Neg, Sub, SubAssign},
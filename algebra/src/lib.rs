@@ -32,10 +32,19 @@
     unsafe_code
 )]
 #![forbid(unsafe_code)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg_attr(not(feature = "std"), macro_use)]
+#[doc(hidden)]
+pub extern crate alloc;
 
 #[macro_use]
 extern crate derivative;
 
+use alloc::boxed::Box;
+
+pub mod io;
+
 #[cfg_attr(test, macro_use)]
 pub mod bytes;
 pub use self::bytes::*;
@@ -62,14 +71,26 @@ pub use self::rand::*;
 mod to_field_vec;
 pub use to_field_vec::ToConstraintField;
 
-#[cfg(feature = "parallel")]
 pub mod msm;
-#[cfg(feature = "parallel")]
 pub use self::msm::*;
 
+#[cfg(feature = "serde")]
+mod serialize;
+
+#[cfg(feature = "zeroize")]
+mod secret;
+#[cfg(feature = "zeroize")]
+pub use self::secret::SecretKey;
+
 #[cfg(feature = "fft")]
 pub mod fft;
 #[cfg(feature = "fft")]
 pub use self::fft::*;
 
-pub type Error = Box<dyn std::error::Error>;
\ No newline at end of file
+// `std::error::Error`, or its `core` counterpart when building without `std`.
+#[cfg(feature = "std")]
+use std::error::Error as ErrorTrait;
+#[cfg(not(feature = "std"))]
+use core::error::Error as ErrorTrait;
+
+pub type Error = Box<dyn ErrorTrait>;
\ No newline at end of file
@@ -1,4 +1,6 @@
-use crate::{BigInteger, FpParameters, PrimeField, ProjectiveCurve};
+use alloc::vec::Vec;
+use crate::{AffineCurve, BigInteger, FpParameters, PrimeField, ProjectiveCurve};
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
 pub struct FixedBaseMSM;
@@ -8,10 +10,23 @@ impl FixedBaseMSM {
         if num_scalars < 32 {
             3
         } else {
-            (f64::from(num_scalars as u32)).ln().ceil() as usize
+            Self::ceil_ln(num_scalars)
         }
     }
 
+    #[cfg(feature = "std")]
+    fn ceil_ln(num_scalars: usize) -> usize {
+        (f64::from(num_scalars as u32)).ln().ceil() as usize
+    }
+
+    /// Approximates `ceil(ln(num_scalars))` as `log2(num_scalars) * ln(2)`, where
+    /// `log2` is rounded up to the bit length, for targets without `f64::ln`.
+    #[cfg(not(feature = "std"))]
+    fn ceil_ln(num_scalars: usize) -> usize {
+        let log2 = (0usize.count_zeros() - num_scalars.leading_zeros()) as usize;
+        (log2 * 6931 + 9999) / 10000
+    }
+
     pub fn get_window_table<T: ProjectiveCurve>(
         scalar_size: usize,
         window: usize,
@@ -75,6 +90,81 @@ impl FixedBaseMSM {
         let outerc = (scalar_size + window - 1) / window;
         assert!(outerc <= table.len());
 
-        v.par_iter().map(|e| Self::windowed_mul::<T>(outerc, window, table, e)).collect::<Vec<_>>()
+        #[cfg(feature = "parallel")]
+        let iter = v.par_iter();
+
+        #[cfg(not(feature = "parallel"))]
+        let iter = v.iter();
+
+        iter.map(|e| Self::windowed_mul::<T>(outerc, window, table, e)).collect::<Vec<_>>()
+    }
+}
+
+/// The windowed multiples of a fixed base computed by `FixedBaseMSM::get_window_table`,
+/// bundled with their parameters so that the table can be built once and reused
+/// for many scalars. A multiplication costs one addition per window, i.e.
+/// `ceil(MODULUS_BITS / window)` additions, while the table holds `2^window` points
+/// per window: larger windows trade memory for speed.
+pub struct WindowTable<G: ProjectiveCurve> {
+    scalar_size: usize,
+    window:      usize,
+    table:       Vec<Vec<G>>,
+}
+
+impl<G: ProjectiveCurve> WindowTable<G> {
+    pub fn new(base: &G::Affine, window: usize) -> Self {
+        assert!(window > 0, "window size must be positive");
+        let scalar_size = <G::ScalarField as PrimeField>::Params::MODULUS_BITS as usize;
+        let table = FixedBaseMSM::get_window_table(scalar_size, window, base.into_projective());
+        Self { scalar_size, window, table }
+    }
+
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Returns `scalar * base`.
+    pub fn mul(&self, scalar: &G::ScalarField) -> G {
+        let outerc = (self.scalar_size + self.window - 1) / self.window;
+        FixedBaseMSM::windowed_mul(outerc, self.window, &self.table, scalar)
+    }
+
+    /// Returns `scalar * base` for each of the `scalars`.
+    pub fn batch_mul(&self, scalars: &[G::ScalarField]) -> Vec<G> {
+        FixedBaseMSM::multi_scalar_mul(self.scalar_size, self.window, &self.table, scalars)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::curves::bls12_381::G1Projective;
+    use crate::fields::bls12_381::Fr;
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+    use crate::{Field, UniformRand};
+
+    #[test]
+    fn test_window_table_with_bls12() {
+        const SAMPLES: usize = 1 << 6;
+
+        let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+
+        let base = G1Projective::rand(&mut rng).into_affine();
+        let mut scalars = (0..SAMPLES).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        scalars.push(Fr::zero());
+        scalars.push(Fr::one());
+        scalars.push(-Fr::one());
+        let expected = scalars.iter().map(|s| base.mul(*s)).collect::<Vec<_>>();
+
+        // Window sizes dividing the scalar size or not
+        for &window in [1, 3, 4, 8, 11].iter() {
+            let table = WindowTable::<G1Projective>::new(&base, window);
+            assert_eq!(table.window(), window);
+            for (s, e) in scalars.iter().zip(expected.iter()) {
+                assert_eq!(table.mul(s), *e);
+            }
+            assert_eq!(table.batch_mul(&scalars), expected);
+        }
     }
 }
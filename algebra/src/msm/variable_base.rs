@@ -1,73 +1,90 @@
+use alloc::vec::Vec;
 use crate::{
     AffineCurve, BigInteger, Field, FpParameters, PrimeField,
     ProjectiveCurve,
 };
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
 pub struct VariableBaseMSM;
 
 impl VariableBaseMSM {
-    fn msm_inner<G: AffineCurve>(
-        bases: &[G],
-        scalars: &[<G::ScalarField as PrimeField>::BigInt],
-    ) -> G::Projective {
-        let c = if scalars.len() < 32 {
+    #[cfg(feature = "std")]
+    fn window_size(num_scalars: usize) -> usize {
+        if num_scalars < 32 {
             3
         } else {
-            (2.0 / 3.0 * (f64::from(scalars.len() as u32)).log2() + 2.0).ceil() as usize
-        };
+            (2.0 / 3.0 * (f64::from(num_scalars as u32)).log2() + 2.0).ceil() as usize
+        }
+    }
 
-        let num_bits =
-            <G::ScalarField as PrimeField>::Params::MODULUS_BITS as usize;
-        let fr_one = G::ScalarField::one().into_repr();
+    /// Same as above without floating point arithmetic, rounding `log2` up to
+    /// the bit length of `num_scalars`.
+    #[cfg(not(feature = "std"))]
+    fn window_size(num_scalars: usize) -> usize {
+        if num_scalars < 32 {
+            3
+        } else {
+            let log2 = (0usize.count_zeros() - num_scalars.leading_zeros()) as usize;
+            (2 * log2 + 2) / 3 + 2
+        }
+    }
 
+    /// Computes the sum of the `c`-bit window starting at bit `w_start` of
+    /// each scalar, times the corresponding base.
+    fn msm_window<G: AffineCurve>(
+        bases: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInt],
+        w_start: usize,
+        c: usize,
+    ) -> G::Projective {
+        let fr_one = G::ScalarField::one().into_repr();
         let zero = G::zero().into_projective();
-        let window_starts: Vec<_> = (0..num_bits).step_by(c).collect();
 
-        // Each window is of size `c`.
-        // We divide up the bits 0..num_bits into windows of size `c`, and
-        // in parallel process each such window.
-        let window_sums: Vec<_> = window_starts
-            .into_par_iter()
-            .map(|w_start| {
-                let mut res = zero;
-                // We don't need the "zero" bucket, so we only have 2^c - 1 buckets
-                let mut buckets = vec![zero; (1 << c) - 1];
-                scalars.iter().zip(bases).filter(|(s, _)| !s.is_zero()).for_each(|(&scalar, base)|  {
-                    if scalar == fr_one {
-                        // We only process unit scalars once in the first window.
-                        if w_start == 0 {
-                            res.add_assign_mixed(base);
-                        }
-                    } else {
-                        let mut scalar = scalar;
-
-                        // We right-shift by w_start, thus getting rid of the
-                        // lower bits.
-                        scalar.divn(w_start as u32);
-
-                        // We mod the remaining bits by the window size.
-                        let scalar = scalar.as_ref()[0] % (1 << c);
-
-                        // If the scalar is non-zero, we update the corresponding
-                        // bucket.
-                        // (Recall that `buckets` doesn't have a zero bucket.)
-                        if scalar != 0 {
-                            buckets[(scalar - 1) as usize].add_assign_mixed(&base);
-                        }
-                    }
-                });
-                G::Projective::batch_normalization(&mut buckets);
-
-                let mut running_sum = G::Projective::zero();
-                for b in buckets.into_iter().map(|g| g.into_affine()).rev() {
-                    running_sum.add_assign_mixed(&b);
-                    res += &running_sum;
+        let mut res = zero;
+        // We don't need the "zero" bucket, so we only have 2^c - 1 buckets
+        let mut buckets = vec![zero; (1 << c) - 1];
+        scalars.iter().zip(bases).filter(|(s, _)| !s.is_zero()).for_each(|(&scalar, base)|  {
+            if scalar == fr_one {
+                // We only process unit scalars once in the first window.
+                if w_start == 0 {
+                    res.add_assign_mixed(base);
                 }
+            } else {
+                let mut scalar = scalar;
 
-                res
-            })
-            .collect();
+                // We right-shift by w_start, thus getting rid of the
+                // lower bits.
+                scalar.divn(w_start as u32);
+
+                // We mod the remaining bits by the window size.
+                let scalar = scalar.as_ref()[0] % (1 << c);
+
+                // If the scalar is non-zero, we update the corresponding
+                // bucket.
+                // (Recall that `buckets` doesn't have a zero bucket.)
+                if scalar != 0 {
+                    buckets[(scalar - 1) as usize].add_assign_mixed(base);
+                }
+            }
+        });
+        G::Projective::batch_normalization(&mut buckets);
+
+        let mut running_sum = G::Projective::zero();
+        for b in buckets.into_iter().map(|g| g.into_affine()).rev() {
+            running_sum.add_assign_mixed(&b);
+            res += &running_sum;
+        }
+
+        res
+    }
+
+    /// Combines the window sums, given from the lowest to the highest window.
+    fn combine_windows<G: AffineCurve>(
+        window_sums: &[G::Projective],
+        c: usize,
+    ) -> G::Projective {
+        let zero = G::zero().into_projective();
 
         // We store the sum for the lowest window.
         let lowest = window_sums.first().unwrap();
@@ -82,11 +99,119 @@ impl VariableBaseMSM {
         }) + lowest
     }
 
+    /// Single-threaded version of `multi_scalar_mul`.
+    pub fn multi_scalar_mul_serial<G: AffineCurve>(
+        bases: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInt],
+    ) -> G::Projective {
+        let c = Self::window_size(scalars.len());
+        let num_bits =
+            <G::ScalarField as PrimeField>::Params::MODULUS_BITS as usize;
+
+        let window_sums: Vec<_> = (0..num_bits)
+            .step_by(c)
+            .map(|w_start| Self::msm_window(bases, scalars, w_start, c))
+            .collect();
+
+        Self::combine_windows::<G>(window_sums.as_slice(), c)
+    }
+
+    /// Each window is of size `c`. We divide up the bits 0..num_bits into
+    /// windows of size `c`, and in parallel process each such window. The
+    /// number of threads is the one of the global rayon thread pool, hence
+    /// it can be set via the `RAYON_NUM_THREADS` environment variable.
+    #[cfg(feature = "parallel")]
+    pub fn multi_scalar_mul_parallel<G: AffineCurve>(
+        bases: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInt],
+    ) -> G::Projective {
+        let c = Self::window_size(scalars.len());
+        let num_bits =
+            <G::ScalarField as PrimeField>::Params::MODULUS_BITS as usize;
+        let window_starts: Vec<_> = (0..num_bits).step_by(c).collect();
+
+        let window_sums: Vec<_> = window_starts
+            .into_par_iter()
+            .map(|w_start| Self::msm_window(bases, scalars, w_start, c))
+            .collect();
+
+        Self::combine_windows::<G>(window_sums.as_slice(), c)
+    }
+
+    /// Computes `sum_i scalars[i] * bases[i]`. The computation is parallelized
+    /// if the `parallel` feature is enabled.
     pub fn multi_scalar_mul<G: AffineCurve>(
         bases: &[G],
         scalars: &[<G::ScalarField as PrimeField>::BigInt],
     ) -> G::Projective {
-        Self::msm_inner(bases, scalars)
+        #[cfg(feature = "parallel")]
+        { Self::multi_scalar_mul_parallel(bases, scalars) }
+
+        #[cfg(not(feature = "parallel"))]
+        { Self::multi_scalar_mul_serial(bases, scalars) }
+    }
+
+    /// Computes `s1 * g + s2 * h` by Shamir's trick: the bits of both scalars are
+    /// processed together, from the most significant one, adding `g`, `h` or the
+    /// precomputed `g + h` as needed. This needs a single chain of doublings, as
+    /// long as the longer of the two scalars, instead of one per scalar.
+    pub fn double_base_scalar_mul<G, S1, S2>(g: &G, s1: S1, h: &G, s2: S2) -> G::Projective
+    where
+        G:  AffineCurve,
+        S1: Into<<G::ScalarField as PrimeField>::BigInt>,
+        S2: Into<<G::ScalarField as PrimeField>::BigInt>,
+    {
+        let s1 = s1.into();
+        let s2 = s2.into();
+
+        let mut g_plus_h = g.into_projective();
+        g_plus_h.add_assign_mixed(h);
+
+        // the shorter scalar is implicitly zero-extended
+        let num_bits = core::cmp::max(s1.num_bits(), s2.num_bits()) as usize;
+        let mut res = G::Projective::zero();
+        for i in (0..num_bits).rev() {
+            res.double_in_place();
+            match (s1.get_bit(i), s2.get_bit(i)) {
+                (true, false) => res.add_assign_mixed(g),
+                (false, true) => res.add_assign_mixed(h),
+                (true, true) => res += &g_plus_h,
+                (false, false) => {},
+            }
+        }
+        res
+    }
+
+    /// Computes `s * g` from the width-`w` NAF of `s`, using the precomputed odd
+    /// multiples `g, 3g, ..., (2^(w-1) - 1)g`. Compared to double-and-add, this
+    /// needs on average one addition per `w + 1` bits instead of one per two bits.
+    /// Panics if `w` is not in `2..64`.
+    pub fn wnaf_scalar_mul<G, S>(g: &G, s: S, w: usize) -> G::Projective
+    where
+        G: AffineCurve,
+        S: Into<<G::ScalarField as PrimeField>::BigInt>,
+    {
+        let naf = s.into().find_wnaf_with_width(w);
+
+        let g = g.into_projective();
+        let double_g = g.double();
+        let mut table = Vec::with_capacity(1 << (w - 2));
+        table.push(g);
+        for i in 1..(1 << (w - 2)) {
+            let next = table[i - 1] + &double_g;
+            table.push(next);
+        }
+
+        let mut res = G::Projective::zero();
+        for &d in naf.iter().rev() {
+            res.double_in_place();
+            if d > 0 {
+                res += &table[(d / 2) as usize];
+            } else if d < 0 {
+                res -= &table[(-d / 2) as usize];
+            }
+        }
+        res
     }
 }
 
@@ -95,7 +220,7 @@ mod test {
     use super::*;
     use crate::curves::bls12_381::G1Projective;
     use crate::fields::bls12_381::Fr;
-    use rand::SeedableRng;
+    use rand::{Rng, SeedableRng};
     use rand_xorshift::XorShiftRng;
     use crate::UniformRand;
 
@@ -149,4 +274,63 @@ mod test {
 
         assert_eq!(naive.into_affine(), fast.into_affine());
     }
+
+    #[test]
+    fn test_double_base_scalar_mul() {
+        let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+
+        for _ in 0..10 {
+            let g = G1Projective::rand(&mut rng).into_affine();
+            let h = G1Projective::rand(&mut rng).into_affine();
+            let s1 = Fr::rand(&mut rng);
+            let s2 = Fr::rand(&mut rng);
+            // a short scalar, to exercise differing widths
+            let s3 = Fr::from(rng.gen::<u16>() as u64);
+
+            let expected = g.mul(s1) + &h.mul(s2);
+            assert_eq!(VariableBaseMSM::double_base_scalar_mul(&g, s1, &h, s2), expected);
+            assert_eq!(VariableBaseMSM::double_base_scalar_mul(&g, s1, &h, s3), g.mul(s1) + &h.mul(s3));
+            assert_eq!(VariableBaseMSM::double_base_scalar_mul(&g, s3, &h, s1), g.mul(s3) + &h.mul(s1));
+            // s * g - e * h, as for signature verification
+            assert_eq!(VariableBaseMSM::double_base_scalar_mul(&g, s1, &h, -s2), g.mul(s1) - &h.mul(s2));
+            // g == h, zero scalars
+            assert_eq!(VariableBaseMSM::double_base_scalar_mul(&g, s1, &g, s2), g.mul(s1 + &s2));
+            assert!(VariableBaseMSM::double_base_scalar_mul(&g, Fr::zero(), &h, Fr::zero()).is_zero());
+        }
+    }
+
+    #[test]
+    fn test_wnaf_scalar_mul() {
+        let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+
+        for _ in 0..10 {
+            let g = G1Projective::rand(&mut rng).into_affine();
+            let s = Fr::rand(&mut rng);
+            for w in 2..7 {
+                assert_eq!(VariableBaseMSM::wnaf_scalar_mul(&g, s, w), g.mul(s));
+                assert_eq!(VariableBaseMSM::wnaf_scalar_mul(&g, -s, w), -g.mul(s));
+            }
+            assert!(VariableBaseMSM::wnaf_scalar_mul(&g, Fr::zero(), 4).is_zero());
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_and_serial_agree() {
+        const SAMPLES: usize = 1 << 10;
+
+        let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+
+        let v = (0..SAMPLES)
+            .map(|_| Fr::rand(&mut rng).into_repr())
+            .collect::<Vec<_>>();
+        let g = (0..SAMPLES)
+            .map(|_| G1Projective::rand(&mut rng).into_affine())
+            .collect::<Vec<_>>();
+
+        let serial = VariableBaseMSM::multi_scalar_mul_serial(g.as_slice(), v.as_slice());
+        let parallel = VariableBaseMSM::multi_scalar_mul_parallel(g.as_slice(), v.as_slice());
+
+        assert_eq!(serial.into_affine(), parallel.into_affine());
+    }
 }
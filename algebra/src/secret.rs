@@ -0,0 +1,128 @@
+//! `Zeroize` implementations for big integers and prime field elements, and a
+//! `SecretKey` wrapper which zeroizes its content when dropped. Zeroizing a field
+//! element overwrites the limbs of its (Montgomery) representation in place.
+
+use crate::{
+    biginteger::{
+        BigInteger128, BigInteger256, BigInteger320, BigInteger384, BigInteger64,
+        BigInteger768, BigInteger832,
+    },
+    fields::models::{
+        Fp256, Fp256Parameters, Fp320, Fp320Parameters, Fp384, Fp384Parameters, Fp768,
+        Fp768Parameters, Fp832, Fp832Parameters,
+    },
+};
+use core::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    ops::Deref,
+};
+use zeroize::Zeroize;
+
+macro_rules! impl_zeroize_bigint {
+    ($ty:ident) => {
+        impl Zeroize for $ty {
+            fn zeroize(&mut self) {
+                self.0.zeroize();
+            }
+        }
+    };
+}
+
+impl_zeroize_bigint!(BigInteger64);
+impl_zeroize_bigint!(BigInteger128);
+impl_zeroize_bigint!(BigInteger256);
+impl_zeroize_bigint!(BigInteger320);
+impl_zeroize_bigint!(BigInteger384);
+impl_zeroize_bigint!(BigInteger768);
+impl_zeroize_bigint!(BigInteger832);
+
+macro_rules! impl_zeroize_fp {
+    ($ty:ident, $params:ident) => {
+        impl<P: $params> Zeroize for $ty<P> {
+            fn zeroize(&mut self) {
+                self.0.zeroize();
+            }
+        }
+    };
+}
+
+impl_zeroize_fp!(Fp256, Fp256Parameters);
+impl_zeroize_fp!(Fp320, Fp320Parameters);
+impl_zeroize_fp!(Fp384, Fp384Parameters);
+impl_zeroize_fp!(Fp768, Fp768Parameters);
+impl_zeroize_fp!(Fp832, Fp832Parameters);
+
+/// Holds secret material, such as a secret scalar, and zeroizes it on drop.
+/// The secret is accessible by dereferencing, and is not shown by `Debug`.
+pub struct SecretKey<T: Zeroize>(T);
+
+impl<T: Zeroize> SecretKey<T> {
+    pub fn new(secret: T) -> Self {
+        SecretKey(secret)
+    }
+}
+
+impl<T: Zeroize> Deref for SecretKey<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: Zeroize> Zeroize for SecretKey<T> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<T: Zeroize> Drop for SecretKey<T> {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<T: Zeroize> Debug for SecretKey<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("SecretKey(..)")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SecretKey;
+    use crate::{
+        fields::{bls12_381::Fr as BlsFr, mnt4753::Fr as MNT4Fr, Field, PrimeField},
+        UniformRand,
+    };
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+    use zeroize::Zeroize;
+
+    fn zeroize_test<F: PrimeField + Zeroize>()
+    where
+        F::BigInt: Zeroize,
+    {
+        let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+        let mut f = F::rand(rng);
+        assert!(!f.is_zero());
+        f.zeroize();
+        assert!(f.into_repr_raw().as_ref().iter().all(|&l| l == 0));
+        assert!(f.is_zero());
+
+        let mut repr = F::rand(rng).into_repr();
+        repr.zeroize();
+        assert!(repr.as_ref().iter().all(|&l| l == 0));
+
+        let mut sk = SecretKey::new(F::rand(rng));
+        assert_eq!(format!("{:?}", sk), "SecretKey(..)");
+        sk.zeroize();
+        assert!(sk.into_repr_raw().as_ref().iter().all(|&l| l == 0));
+    }
+
+    #[test]
+    fn test_zeroize() {
+        zeroize_test::<BlsFr>();
+        zeroize_test::<MNT4Fr>();
+    }
+}
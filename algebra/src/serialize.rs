@@ -0,0 +1,203 @@
+//! `Serialize` and `Deserialize` implementations for field elements and curve
+//! points, delegating to their `ToBytes`/`FromBytes` encoding: a hex string for
+//! human-readable formats, raw bytes otherwise. Deserialization performs the same
+//! validity checks as `FromBytes`, and rejects trailing bytes.
+
+use crate::{
+    bytes::{FromBytes, ToBytes},
+    curves::models::{
+        short_weierstrass_jacobian::{
+            GroupAffine as SWJacobianAffine, GroupProjective as SWJacobianProjective,
+        },
+        short_weierstrass_projective::{
+            GroupAffine as SWProjectiveAffine, GroupProjective as SWProjectiveProjective,
+        },
+        twisted_edwards_extended::{GroupAffine as TEAffine, GroupProjective as TEProjective},
+        SWModelParameters, TEModelParameters,
+    },
+    fields::models::{
+        fp12_2over3over2::{Fp12, Fp12Parameters},
+        fp6_2over3::{Fp6 as Fp6_2over3, Fp6Parameters as Fp6_2over3Parameters},
+        fp6_3over2::{Fp6 as Fp6_3over2, Fp6Parameters as Fp6_3over2Parameters},
+        Fp256, Fp256Parameters, Fp2, Fp2Parameters, Fp320, Fp320Parameters, Fp384,
+        Fp384Parameters, Fp3, Fp3Parameters, Fp4, Fp4Parameters, Fp768, Fp768Parameters,
+        Fp832, Fp832Parameters,
+    },
+};
+use serde::{
+    de::{Error as DeError, SeqAccess, Visitor},
+    ser::Error as SerError,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use std::fmt;
+
+fn serialize_via_bytes<T: ToBytes, S: Serializer>(t: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    let mut bytes = vec![];
+    t.write(&mut bytes).map_err(S::Error::custom)?;
+    if serializer.is_human_readable() {
+        serializer.serialize_str(&hex::encode(bytes))
+    } else {
+        serializer.serialize_bytes(&bytes)
+    }
+}
+
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a byte array")
+    }
+
+    fn visit_bytes<E: DeError>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(v.to_vec())
+    }
+
+    fn visit_byte_buf<E: DeError>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(v)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Ok(bytes)
+    }
+}
+
+fn deserialize_via_bytes<'de, T: FromBytes, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    let bytes = if deserializer.is_human_readable() {
+        let s = String::deserialize(deserializer)?;
+        hex::decode(s).map_err(D::Error::custom)?
+    } else {
+        deserializer.deserialize_bytes(BytesVisitor)?
+    };
+    let mut reader = bytes.as_slice();
+    let t = T::read(&mut reader).map_err(D::Error::custom)?;
+    if !reader.is_empty() {
+        return Err(D::Error::custom("trailing bytes after serialized element"));
+    }
+    Ok(t)
+}
+
+macro_rules! impl_serde_via_bytes {
+    ($ty:ident, $params:ident) => {
+        impl<P: $params> Serialize for $ty<P> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serialize_via_bytes(self, serializer)
+            }
+        }
+
+        impl<'de, P: $params> Deserialize<'de> for $ty<P> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserialize_via_bytes(deserializer)
+            }
+        }
+    };
+}
+
+impl_serde_via_bytes!(Fp256, Fp256Parameters);
+impl_serde_via_bytes!(Fp320, Fp320Parameters);
+impl_serde_via_bytes!(Fp384, Fp384Parameters);
+impl_serde_via_bytes!(Fp768, Fp768Parameters);
+impl_serde_via_bytes!(Fp832, Fp832Parameters);
+impl_serde_via_bytes!(Fp2, Fp2Parameters);
+impl_serde_via_bytes!(Fp3, Fp3Parameters);
+impl_serde_via_bytes!(Fp4, Fp4Parameters);
+impl_serde_via_bytes!(Fp6_2over3, Fp6_2over3Parameters);
+impl_serde_via_bytes!(Fp6_3over2, Fp6_3over2Parameters);
+impl_serde_via_bytes!(Fp12, Fp12Parameters);
+
+impl_serde_via_bytes!(SWJacobianAffine, SWModelParameters);
+impl_serde_via_bytes!(SWJacobianProjective, SWModelParameters);
+impl_serde_via_bytes!(SWProjectiveAffine, SWModelParameters);
+impl_serde_via_bytes!(SWProjectiveProjective, SWModelParameters);
+impl_serde_via_bytes!(TEAffine, TEModelParameters);
+impl_serde_via_bytes!(TEProjective, TEModelParameters);
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        curves::{
+            bls12_381::G1Projective as BlsG1, mnt4753::G2Projective as MNT4G2,
+            jubjub::JubJubAffine,
+        },
+        fields::{bls12_381::Fq12, mnt4753::Fr as MNT4Fr, FpParameters, PrimeField},
+        to_bytes, ProjectiveCurve, ToBytes, UniformRand,
+    };
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+    use serde::{de::DeserializeOwned, Serialize};
+    use std::fmt::Debug;
+
+    fn serde_round_trip<T: Serialize + DeserializeOwned + PartialEq + Debug>(t: T) {
+        let bytes = bincode::serialize(&t).unwrap();
+        assert_eq!(bincode::deserialize::<T>(&bytes).unwrap(), t);
+
+        let json = serde_json::to_string(&t).unwrap();
+        assert_eq!(serde_json::from_str::<T>(&json).unwrap(), t);
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+        for _ in 0..10 {
+            serde_round_trip(MNT4Fr::rand(rng));
+            serde_round_trip(Fq12::rand(rng));
+            serde_round_trip(BlsG1::rand(rng));
+            serde_round_trip(BlsG1::rand(rng).into_affine());
+            serde_round_trip(MNT4G2::rand(rng).into_affine());
+            serde_round_trip(JubJubAffine::rand(rng));
+        }
+    }
+
+    #[test]
+    fn test_serde_format() {
+        let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+        let f = MNT4Fr::rand(rng);
+        let bytes = to_bytes!(f).unwrap();
+
+        // Hex string in human readable formats
+        let json = serde_json::to_string(&f).unwrap();
+        assert_eq!(json, format!("\"{}\"", hex::encode(&bytes)));
+
+        // Raw bytes (with length prefix) in bincode
+        let encoded = bincode::serialize(&f).unwrap();
+        assert_eq!(&encoded[8..], bytes.as_slice());
+    }
+
+    #[test]
+    fn test_serde_rejects_invalid() {
+        // Non canonical field element
+        let modulus = to_bytes!(<MNT4Fr as PrimeField>::Params::MODULUS).unwrap();
+        let json = format!("\"{}\"", hex::encode(&modulus));
+        assert!(serde_json::from_str::<MNT4Fr>(&json).is_err());
+        assert!(bincode::deserialize::<MNT4Fr>(&bincode::serialize(&serde_bytes_wrapper(&modulus)).unwrap()).is_err());
+
+        // Truncated and overlong encodings
+        let f = MNT4Fr::from(7u64);
+        let bytes = to_bytes!(f).unwrap();
+        let json = format!("\"{}\"", hex::encode(&bytes[1..]));
+        assert!(serde_json::from_str::<MNT4Fr>(&json).is_err());
+        let mut overlong = bytes.clone();
+        overlong.push(0);
+        let json = format!("\"{}\"", hex::encode(&overlong));
+        assert!(serde_json::from_str::<MNT4Fr>(&json).is_err());
+
+        // Invalid hex
+        assert!(serde_json::from_str::<MNT4Fr>("\"zz\"").is_err());
+    }
+
+    // Serializes a byte slice as serde bytes, as done for field elements.
+    fn serde_bytes_wrapper(bytes: &[u8]) -> impl Serialize + '_ {
+        struct Bytes<'a>(&'a [u8]);
+        impl<'a> Serialize for Bytes<'a> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_bytes(self.0)
+            }
+        }
+        Bytes(bytes)
+    }
+}
@@ -1,3 +1,4 @@
+use alloc::{boxed::Box, vec::Vec};
 use crate::{
     curves::{
         models::{SWModelParameters, TEModelParameters},
@@ -9,7 +10,7 @@ use crate::{
     Fp2, Fp2Parameters, FpParameters, Field, PrimeField,
 };
 
-type Error = Box<dyn std::error::Error>;
+type Error = Box<dyn crate::ErrorTrait>;
 
 /// Types that can be converted to a vector of `F` elements. Useful for specifying
 /// how public inputs to a constraint system should be represented inside
@@ -137,14 +138,13 @@ impl<ConstraintF: PrimeField> ToConstraintField<ConstraintF> for [u8] {
     fn to_field_elements(&self) -> Result<Vec<ConstraintF>, Error> {
         let max_size = <ConstraintF as PrimeField>::Params::CAPACITY / 8;
         let max_size = max_size as usize;
+        // Chunks are zero-padded up to the serialized size of the field element
+        let repr_size = 8 * <ConstraintF as PrimeField>::BigInt::default().as_ref().len();
         let fes = self
             .chunks(max_size)
             .map(|chunk| {
                 let mut chunk = chunk.to_vec();
-                let len = chunk.len();
-                for _ in len..(max_size + 1) {
-                    chunk.push(0u8);
-                }
+                chunk.resize(repr_size, 0u8);
                 ConstraintF::read(chunk.as_slice())
             })
             .collect::<Result<Vec<_>, _>>()?;
@@ -160,11 +160,11 @@ impl<ConstraintF: PrimeField> ToConstraintField<ConstraintF> for [bool] {
         let fes = self
             .chunks(max_size)
             .map(|chunk| {
+                // Big-endian, padded with zeros up to `CAPACITY` bits, below an unset
+                // most significant bit: the element never exceeds the modulus
                 let mut chunk = chunk.to_vec();
-                let len = chunk.len();
-                for _ in len..(max_size + 1) {
-                    chunk.push(false);
-                }
+                chunk.resize(max_size, false);
+                chunk.insert(0, false);
                 ConstraintF::read_bits(chunk)
             })
             .collect::<Result<Vec<_>, _>>()?;
@@ -0,0 +1,75 @@
+//! Field and curve arithmetic through the crate's public API only, so that it can
+//! be run against a `no_std` build of the library:
+//!
+//! cargo test -p algebra --no-default-features --test no_std
+
+use algebra::{
+    bytes::{FromBytes, ToBytes},
+    curves::{bls12_381::G1Projective, mnt4753::G1Projective as MNT4G1Projective},
+    fields::{bls12_381::Fr, mnt4753::Fr as MNT4Fr, Field},
+    groups::Group,
+    io, AffineCurve, ProjectiveCurve, UniformRand,
+};
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+fn field_arithmetic<F: Field>() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    for _ in 0..100 {
+        let a = F::rand(rng);
+        let b = F::rand(rng);
+
+        assert_eq!(a + &b, b + &a);
+        assert_eq!(a * &b, b * &a);
+        assert_eq!((a + &b) - &b, a);
+        assert_eq!(a * &(b + &F::one()), a * &b + &a);
+        assert_eq!(a.square(), a * &a);
+
+        match a.inverse() {
+            Some(a_inv) => assert_eq!(a * &a_inv, F::one()),
+            None => assert!(a.is_zero()),
+        }
+    }
+    assert!(F::zero().inverse().is_none());
+}
+
+fn bytes_round_trip<T: ToBytes + FromBytes + PartialEq + core::fmt::Debug>(t: T) {
+    let mut buf = Vec::new();
+    t.write(&mut buf).unwrap();
+    let mut reader = &buf[..];
+    assert_eq!(T::read(&mut reader).unwrap(), t);
+    assert!(reader.is_empty());
+
+    // Truncated input is an error, not a panic
+    let truncated = &buf[..buf.len() - 1];
+    let err: io::Error = T::read(truncated).unwrap_err();
+    let _ = err.kind();
+}
+
+#[test]
+fn test_field_arithmetic() {
+    field_arithmetic::<Fr>();
+    field_arithmetic::<MNT4Fr>();
+}
+
+#[test]
+fn test_group_arithmetic() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let g = G1Projective::rand(rng);
+    let a = Fr::rand(rng);
+    let b = Fr::rand(rng);
+    assert_eq!(g.mul(&a) + &g.mul(&b), g.mul(&(a + &b)));
+    assert_eq!(g.into_affine().mul(a), g.mul(&a));
+
+    let h = MNT4G1Projective::rand(rng);
+    assert_eq!(ProjectiveCurve::double(&h), h + &h);
+}
+
+#[test]
+fn test_bytes_round_trip() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    bytes_round_trip(Fr::rand(rng));
+    bytes_round_trip(MNT4Fr::rand(rng));
+    bytes_round_trip(G1Projective::rand(rng).into_affine());
+    bytes_round_trip(MNT4G1Projective::rand(rng));
+}
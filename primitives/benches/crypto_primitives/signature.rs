@@ -119,10 +119,7 @@ mod field_impl {
         let message = MNT4Fr::rand(rng);
 
         c.bench_function("FieldSchnorrMNT4: Sign", move |b| {
-            b.iter(|| {
-                let mut rng = &mut rand::thread_rng();
-                SchnorrMNT4Fr::sign(&mut rng, &pk, &sk, &[message]).unwrap()
-            })
+            b.iter(|| SchnorrMNT4Fr::sign(&pk, &sk, &[message]).unwrap())
         });
     }
 
@@ -130,7 +127,7 @@ mod field_impl {
         let mut rng = &mut rand::thread_rng();
         let (pk, sk) = SchnorrMNT4Fr::keygen(&mut rng);
         let message = MNT4Fr::rand(rng);
-        let signature = SchnorrMNT4Fr::sign(&mut rng, &pk, &sk, &[message]).unwrap();
+        let signature = SchnorrMNT4Fr::sign(&pk, &sk, &[message]).unwrap();
 
         c.bench_function("FieldSchnorrMNT4: Verify", move |b| {
             b.iter(|| SchnorrMNT4Fr::verify(&pk, &[message], &signature).unwrap())
@@ -48,6 +48,15 @@ pub struct PedersenCRHCompressor<G: Group, I: InjectiveMap<G>, W: PedersenWindow
     _crh:        PedersenCRH<G, W>,
 }
 
+impl<G: Group, I: InjectiveMap<G>, W: PedersenWindow> PedersenCRHCompressor<G, I, W> {
+    /// Evaluates the hash on a bit string, see `PedersenCRH::evaluate_bits`,
+    /// and compresses the result.
+    pub fn evaluate_bits(parameters: &PedersenParameters<G>, input: &[bool]) -> Result<I::Output, Error> {
+        let result = I::injective_map(&PedersenCRH::<G, W>::evaluate_bits(parameters, input)?)?;
+        Ok(result)
+    }
+}
+
 impl<G: Group, I: InjectiveMap<G>, W: PedersenWindow> FixedLengthCRH
     for PedersenCRHCompressor<G, I, W>
 {
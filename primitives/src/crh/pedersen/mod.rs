@@ -35,37 +35,35 @@ impl<G: Group, W: PedersenWindow> PedersenCRH<G, W> {
     }
 
     pub fn generator_powers<R: Rng>(num_powers: usize, rng: &mut R) -> Vec<G> {
+        Self::powers_of(G::rand(rng), num_powers)
+    }
+
+    /// Deterministically derives the generators: the base of the i-th window is
+    /// the hash to the group, via `GH`, of the index i as little-endian u64.
+    pub fn create_generators_from_index<GH: FixedLengthCRH<Output = G>>(
+        gh_params: &GH::Parameters,
+    ) -> Result<Vec<Vec<G>>, Error> {
+        (0..W::NUM_WINDOWS)
+            .map(|i| {
+                let base = GH::evaluate(gh_params, &(i as u64).to_le_bytes())?;
+                Ok(Self::powers_of(base, W::WINDOW_SIZE))
+            })
+            .collect()
+    }
+
+    fn powers_of(mut base: G, num_powers: usize) -> Vec<G> {
         let mut cur_gen_powers = Vec::with_capacity(num_powers);
-        let mut base = G::rand(rng);
         for _ in 0..num_powers {
             cur_gen_powers.push(base);
             base.double_in_place();
         }
         cur_gen_powers
     }
-}
-
-impl<G: Group, W: PedersenWindow> FixedLengthCRH for PedersenCRH<G, W> {
-    const INPUT_SIZE_BITS: usize = W::WINDOW_SIZE * W::NUM_WINDOWS;
-    type Output = G;
-    type Parameters = PedersenParameters<G>;
 
-    fn setup<R: Rng>(rng: &mut R) -> Result<Self::Parameters, Error> {
-        let time = start_timer!(|| format!(
-            "PedersenCRH::Setup: {} {}-bit windows; {{0,1}}^{{{}}} -> G",
-            W::NUM_WINDOWS,
-            W::WINDOW_SIZE,
-            W::NUM_WINDOWS * W::WINDOW_SIZE
-        ));
-        let generators = Self::create_generators(rng);
-        end_timer!(time);
-        Ok(Self::Parameters { generators })
-    }
-
-    fn evaluate(parameters: &Self::Parameters, input: &[u8]) -> Result<Self::Output, Error> {
-        let eval_time = start_timer!(|| "PedersenCRH::Eval");
-
-        if (input.len() * 8) > W::WINDOW_SIZE * W::NUM_WINDOWS {
+    /// Evaluates the hash on a bit string, padded with zeros up to
+    /// `W::WINDOW_SIZE * W::NUM_WINDOWS` bits.
+    pub fn evaluate_bits(parameters: &PedersenParameters<G>, input: &[bool]) -> Result<G, Error> {
+        if input.len() > W::WINDOW_SIZE * W::NUM_WINDOWS {
             panic!(
                 "incorrect input length {:?} for window params {:?}x{:?}",
                 input.len(),
@@ -74,18 +72,6 @@ impl<G: Group, W: PedersenWindow> FixedLengthCRH for PedersenCRH<G, W> {
             );
         }
 
-        let mut padded_input = Vec::with_capacity(input.len());
-        let mut input = input;
-        // Pad the input if it is not the current length.
-        if (input.len() * 8) < W::WINDOW_SIZE * W::NUM_WINDOWS {
-            let current_length = input.len();
-            padded_input.extend_from_slice(input);
-            for _ in current_length..((W::WINDOW_SIZE * W::NUM_WINDOWS) / 8) {
-                padded_input.push(0u8);
-            }
-            input = padded_input.as_slice();
-        }
-
         assert_eq!(
             parameters.generators.len(),
             W::NUM_WINDOWS,
@@ -96,8 +82,11 @@ impl<G: Group, W: PedersenWindow> FixedLengthCRH for PedersenCRH<G, W> {
             W::NUM_WINDOWS
         );
 
+        let mut padded_input = input.to_vec();
+        padded_input.resize(W::WINDOW_SIZE * W::NUM_WINDOWS, false);
+
         // Compute sum of h_i^{m_i} for all i.
-        let result = bytes_to_bits(input)
+        let result = padded_input
             .par_chunks(W::WINDOW_SIZE)
             .zip(&parameters.generators)
             .map(|(bits, generator_powers)| {
@@ -110,6 +99,41 @@ impl<G: Group, W: PedersenWindow> FixedLengthCRH for PedersenCRH<G, W> {
                 encoded
             })
             .reduce(|| G::zero(), |a, b| a + &b);
+
+        Ok(result)
+    }
+}
+
+impl<G: Group, W: PedersenWindow> FixedLengthCRH for PedersenCRH<G, W> {
+    const INPUT_SIZE_BITS: usize = W::WINDOW_SIZE * W::NUM_WINDOWS;
+    type Output = G;
+    type Parameters = PedersenParameters<G>;
+
+    fn setup<R: Rng>(rng: &mut R) -> Result<Self::Parameters, Error> {
+        let time = start_timer!(|| format!(
+            "PedersenCRH::Setup: {} {}-bit windows; {{0,1}}^{{{}}} -> G",
+            W::NUM_WINDOWS,
+            W::WINDOW_SIZE,
+            W::NUM_WINDOWS * W::WINDOW_SIZE
+        ));
+        let generators = Self::create_generators(rng);
+        end_timer!(time);
+        Ok(Self::Parameters { generators })
+    }
+
+    fn evaluate(parameters: &Self::Parameters, input: &[u8]) -> Result<Self::Output, Error> {
+        let eval_time = start_timer!(|| "PedersenCRH::Eval");
+
+        if (input.len() * 8) > W::WINDOW_SIZE * W::NUM_WINDOWS {
+            panic!(
+                "incorrect input length {:?} for window params {:?}x{:?}",
+                input.len(),
+                W::WINDOW_SIZE,
+                W::NUM_WINDOWS
+            );
+        }
+
+        let result = Self::evaluate_bits(parameters, &bytes_to_bits(input))?;
         end_timer!(eval_time);
 
         Ok(result)
@@ -134,3 +158,89 @@ impl<ConstraintF: Field, G: Group + ToConstraintField<ConstraintF>> ToConstraint
         Ok(Vec::new())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        bytes_to_bits,
+        crh::{
+            injective_map::{PedersenCRHCompressor, TECompressor},
+            pedersen::{PedersenCRH, PedersenParameters, PedersenWindow},
+            FixedLengthCRH,
+        },
+        Error,
+    };
+    use algebra::{
+        curves::{jubjub::JubJubProjective as JubJub, ProjectiveCurve},
+        fields::jubjub::fr::Fr,
+        groups::Group,
+        Field,
+    };
+    use rand::Rng;
+
+    #[derive(Clone)]
+    struct Window;
+
+    impl PedersenWindow for Window {
+        const WINDOW_SIZE: usize = 8;
+        const NUM_WINDOWS: usize = 4;
+    }
+
+    /// Maps the index i to (i + 1) * G, with G the prime order subgroup generator,
+    /// so that the hash of a bit string is known in closed form.
+    struct IndexToGenerator;
+
+    impl FixedLengthCRH for IndexToGenerator {
+        const INPUT_SIZE_BITS: usize = 64;
+        type Output = JubJub;
+        type Parameters = ();
+
+        fn setup<R: Rng>(_: &mut R) -> Result<Self::Parameters, Error> {
+            Ok(())
+        }
+
+        fn evaluate(_: &Self::Parameters, input: &[u8]) -> Result<Self::Output, Error> {
+            let mut index = [0u8; 8];
+            index.copy_from_slice(input);
+            let scalar = Fr::from(u64::from_le_bytes(index) + 1);
+            Ok(<JubJub as Group>::mul(&JubJub::prime_subgroup_generator(), &scalar))
+        }
+    }
+
+    #[test]
+    fn pedersen_hash_kat() {
+        let generators = PedersenCRH::<JubJub, Window>::create_generators_from_index::<IndexToGenerator>(&())
+            .unwrap();
+        assert_eq!(generators.len(), Window::NUM_WINDOWS);
+        let parameters = PedersenParameters { generators };
+
+        // 27 bits, padded with zeros to 32 bits
+        let mut input = bytes_to_bits(&[0x9f, 0x3a, 0xc5, 0x6e]);
+        input.truncate(27);
+
+        // The j-th bit of the i-th window contributes 2^j * (i + 1) * G
+        let mut scalar = Fr::zero();
+        for (i, bits) in input.chunks(Window::WINDOW_SIZE).enumerate() {
+            let window_factor = Fr::from(i as u64 + 1);
+            for (j, bit) in bits.iter().enumerate() {
+                if *bit {
+                    scalar += &(Fr::from(1u64 << j) * &window_factor);
+                }
+            }
+        }
+        let expected = <JubJub as Group>::mul(&JubJub::prime_subgroup_generator(), &scalar);
+
+        assert_eq!(PedersenCRH::<JubJub, Window>::evaluate_bits(&parameters, &input).unwrap(), expected);
+        assert_eq!(
+            PedersenCRHCompressor::<JubJub, TECompressor, Window>::evaluate_bits(&parameters, &input).unwrap(),
+            expected.into_affine().x
+        );
+
+        // Evaluating on bytes is the same as evaluating on their bits
+        let bytes = [0x9f, 0x3a, 0xc5];
+        assert_eq!(
+            PedersenCRH::<JubJub, Window>::evaluate(&parameters, &bytes).unwrap(),
+            PedersenCRH::<JubJub, Window>::evaluate_bits(&parameters, &bytes_to_bits(&bytes)).unwrap()
+        );
+    }
+}
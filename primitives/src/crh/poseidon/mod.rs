@@ -19,6 +19,9 @@ use crate::Error;
 
 pub mod parameters;
 
+pub mod transcript;
+pub use self::transcript::*;
+
 pub struct PoseidonHash<F: PrimeField, P: PoseidonParameters<Fr = F>>{
     _field:      PhantomData<F>,
     _parameters: PhantomData<P>,
@@ -616,6 +619,80 @@ impl<F: PrimeField + MulShort, P: PoseidonParameters<Fr = F>> FieldBasedHash for
     }
 }
 
+/// Incremental version of `PoseidonHash`, allowing to absorb the input in chunks without
+/// materializing it as a whole. The rate buffer is permuted only once it is full (or, if
+/// partially filled, when squeezing), so that absorbing `a` and then `b` and squeezing a
+/// single element gives the same result as `PoseidonHash::evaluate` on `a || b`.
+pub struct PoseidonSponge<F: PrimeField, P: PoseidonParameters<Fr = F>>{
+    state:       Vec<F>,
+    // elements absorbed but not yet added to the state
+    pending:     Vec<F>,
+    // position of the next element to squeeze from the rate, if squeezing
+    squeeze_idx: Option<usize>,
+    _parameters: PhantomData<P>,
+}
+
+impl<F: PrimeField + MulShort, P: PoseidonParameters<Fr = F>> PoseidonSponge<F, P> {
+
+    pub fn new() -> Self {
+        // same initial state as `PoseidonHash::evaluate`
+        Self {
+            state:       P::AFTER_ZERO_PERM[..P::T].to_vec(),
+            pending:     Vec::with_capacity(P::R),
+            squeeze_idx: None,
+            _parameters: PhantomData,
+        }
+    }
+
+    // add the pending elements to the state and apply the permutation
+    fn update(&mut self) {
+        for (s, x) in self.state.iter_mut().zip(self.pending.drain(..)) {
+            *s += &x;
+        }
+        self.state[P::R] += &P::C2;
+        PoseidonHash::<F, P>::poseidon_perm(&mut self.state);
+    }
+
+    pub fn absorb(&mut self, elems: &[F]) {
+        // absorbing nothing doesn't end the squeezing phase
+        if elems.is_empty() {
+            return;
+        }
+        self.squeeze_idx = None;
+        for elem in elems.iter() {
+            self.pending.push(*elem);
+            if self.pending.len() == P::R {
+                self.update();
+            }
+        }
+    }
+
+    pub fn squeeze(&mut self, n: usize) -> Vec<F> {
+        if !self.pending.is_empty() {
+            self.update();
+        }
+        let mut idx = self.squeeze_idx.unwrap_or(0);
+        let mut output = Vec::with_capacity(n);
+        for _ in 0..n {
+            // the rate has been entirely squeezed, permute to get fresh elements
+            if idx == P::R {
+                PoseidonHash::<F, P>::poseidon_perm(&mut self.state);
+                idx = 0;
+            }
+            output.push(self.state[idx]);
+            idx += 1;
+        }
+        self.squeeze_idx = Some(idx);
+        output
+    }
+}
+
+impl<F: PrimeField + MulShort, P: PoseidonParameters<Fr = F>> Default for PoseidonSponge<F, P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<F: PrimeField + MulShort, P: PoseidonParameters<Fr = F>> BatchFieldBasedHash for PoseidonBatchHash<F, P> {
     type Data = F;
     type Parameters = P;
@@ -671,6 +748,8 @@ impl<F: PrimeField + MulShort, P: PoseidonParameters<Fr = F>> BatchFieldBasedHas
 
 pub type MNT4PoseidonHash = PoseidonHash<MNT4753Fr, MNT4753PoseidonParameters>;
 pub type MNT6PoseidonHash = PoseidonHash<MNT6753Fr, MNT6753PoseidonParameters>;
+pub type MNT4PoseidonSponge = PoseidonSponge<MNT4753Fr, MNT4753PoseidonParameters>;
+pub type MNT6PoseidonSponge = PoseidonSponge<MNT6753Fr, MNT6753PoseidonParameters>;
 
 #[cfg(test)]
 mod test {
@@ -683,14 +762,22 @@ mod test {
     use algebra::UniformRand;
     use std::time::Instant;
 
+    // Known-answer tests for the shipped parameter sets (T = 3, R = 2, R_F = 4, R_P = 57,
+    // inversion S-box). They pin down both a full-rate input and an input requiring
+    // padding, and are meant to be cross-checked against the gadget in r1cs-crypto.
     #[test]
     fn test_poseidon_hash_mnt4() {
         let mut input = Vec::new();
         input.push(MNT4753Fr::from_str("1").unwrap());
         input.push(MNT4753Fr::from_str("2").unwrap());
-        let output = MNT4PoseidonHash::evaluate(&input);
-
-        println!("{:?}", output);
+        let output = MNT4PoseidonHash::evaluate(&input).unwrap();
+        let expected_output = MNT4753Fr::from_str("11874958407780363076337004251304774775056538711781159442264435767641723986277458709218753946385507555155099359997412219062062063588601743151631829199384295674725107468104449097464682543362372996667704722374975362222811262579782").unwrap();
+        assert_eq!(output, expected_output, "Poseidon hash of (1, 2) for MNT4 differs from the known answer.");
+
+        input.push(MNT4753Fr::from_str("3").unwrap());
+        let output = MNT4PoseidonHash::evaluate(&input).unwrap();
+        let expected_output = MNT4753Fr::from_str("22498543737294783811937905681090097744628901900400405063475269534089865051102770131147819076750603053262266421342236116577637714747897946158528630688316016064056678629841125257435984811831356610918993366608513659196155124323207").unwrap();
+        assert_eq!(output, expected_output, "Poseidon hash of (1, 2, 3) for MNT4 differs from the known answer.");
     }
 
 
@@ -699,9 +786,60 @@ mod test {
         let mut input = Vec::new();
         input.push(MNT6753Fr::from_str("1").unwrap());
         input.push(MNT6753Fr::from_str("2").unwrap());
-        let output = MNT6PoseidonHash::evaluate(&mut input);
+        let output = MNT6PoseidonHash::evaluate(&input).unwrap();
+        let expected_output = MNT6753Fr::from_str("21408375517597963855057929007529369577773023346260131867668921218798665720482563219676919195907664574180651671219289619909423892623794572610888171094814799372564348586574645854001656750640838792013979146108328177709227621730857").unwrap();
+        assert_eq!(output, expected_output, "Poseidon hash of (1, 2) for MNT6 differs from the known answer.");
+
+        input.push(MNT6753Fr::from_str("3").unwrap());
+        let output = MNT6PoseidonHash::evaluate(&input).unwrap();
+        let expected_output = MNT6753Fr::from_str("25883338865791986191352686738981973155122689729298322612928848162615738284576745559742653323725426676992098839158176218703970777137317614279443798076929313646106972470855446232360853159340711713837165733233328058076292208857203").unwrap();
+        assert_eq!(output, expected_output, "Poseidon hash of (1, 2, 3) for MNT6 differs from the known answer.");
+    }
 
-        println!("{:?}", output);
+    fn poseidon_sponge_test<F: PrimeField + MulShort, P: PoseidonParameters<Fr = F>>() {
+        let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+
+        for len in 0..10 {
+            let input: Vec<F> = (0..len).map(|_| F::rand(rng)).collect();
+            let expected = PoseidonHash::<F, P>::evaluate(&input).unwrap();
+
+            // absorb in chunks of any size
+            for chunk_size in 1..4 {
+                let mut sponge = PoseidonSponge::<F, P>::default();
+                for chunk in input.chunks(chunk_size) {
+                    sponge.absorb(chunk);
+                }
+                assert_eq!(sponge.squeeze(1), vec![expected]);
+            }
+
+            // squeezing is deterministic
+            let mut sponge_1 = PoseidonSponge::<F, P>::new();
+            let mut sponge_2 = PoseidonSponge::<F, P>::new();
+            sponge_1.absorb(&input);
+            sponge_2.absorb(&input);
+            let output = sponge_1.squeeze(5);
+            assert_eq!(output[0], expected);
+            assert_eq!(output[..2], sponge_2.squeeze(2)[..]);
+            assert_eq!(output[2..], sponge_2.squeeze(3)[..]);
+
+            // further absorb/squeeze phases
+            let extra = F::rand(rng);
+            sponge_1.absorb(&[extra]);
+            sponge_2.absorb(&[extra]);
+            assert_eq!(sponge_1.squeeze(3), sponge_2.squeeze(3));
+
+            // absorbing nothing doesn't affect the squeezed elements
+            let output = sponge_1.squeeze(2);
+            sponge_2.squeeze(1);
+            sponge_2.absorb(&[]);
+            assert_eq!(output[1..], sponge_2.squeeze(1)[..]);
+        }
+    }
+
+    #[test]
+    fn test_poseidon_sponge() {
+        poseidon_sponge_test::<MNT4753Fr, MNT4753PoseidonParameters>();
+        poseidon_sponge_test::<MNT6753Fr, MNT6753PoseidonParameters>();
     }
 
     #[test]
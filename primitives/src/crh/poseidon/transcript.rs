@@ -0,0 +1,93 @@
+use algebra::{PrimeField, MulShort, ToConstraintField};
+use algebra::fields::mnt6753::Fr as MNT6753Fr;
+use algebra::fields::mnt4753::Fr as MNT4753Fr;
+
+use crate::crh::poseidon::{
+    PoseidonParameters, PoseidonSponge,
+    parameters::{MNT4753PoseidonParameters, MNT6753PoseidonParameters},
+};
+use crate::Error;
+
+/// A Fiat-Shamir transcript backed by the Poseidon sponge. Every append absorbs
+/// its label (see `label_to_field_elements`) and then the data; `challenge`
+/// squeezes a single field element. The same absorb/squeeze order is enforced in-circuit by
+/// `PoseidonTranscriptGadget` in r1cs-crypto.
+pub struct PoseidonTranscript<F: PrimeField, P: PoseidonParameters<Fr = F>> {
+    sponge: PoseidonSponge<F, P>,
+}
+
+impl<F: PrimeField + MulShort, P: PoseidonParameters<Fr = F>> PoseidonTranscript<F, P> {
+
+    pub fn new() -> Self {
+        Self { sponge: PoseidonSponge::new() }
+    }
+
+    /// Encodes `label` as its byte length followed by its bytes packed into field
+    /// elements. Exposed so that the gadget can absorb labels as constants.
+    pub fn label_to_field_elements(label: &[u8]) -> Vec<F> {
+        let mut fes = vec![F::from(label.len() as u64)];
+        // Chunks are below the capacity, hence always canonical
+        fes.extend(ToConstraintField::<F>::to_field_elements(label).unwrap());
+        fes
+    }
+
+    pub fn append_field(&mut self, label: &[u8], fe: &F) {
+        self.sponge.absorb(&Self::label_to_field_elements(label));
+        self.sponge.absorb(&[*fe]);
+    }
+
+    /// Appends a point, given by its representation as field elements (e.g. the affine
+    /// coordinates of a curve point defined over `F`).
+    pub fn append_point<G: ToConstraintField<F>>(&mut self, label: &[u8], point: &G) -> Result<(), Error> {
+        let point_fes = point.to_field_elements()?;
+        self.sponge.absorb(&Self::label_to_field_elements(label));
+        self.sponge.absorb(&point_fes);
+        Ok(())
+    }
+
+    pub fn challenge(&mut self) -> F {
+        self.sponge.squeeze(1)[0]
+    }
+}
+
+pub type MNT4PoseidonTranscript = PoseidonTranscript<MNT4753Fr, MNT4753PoseidonParameters>;
+pub type MNT6PoseidonTranscript = PoseidonTranscript<MNT6753Fr, MNT6753PoseidonParameters>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algebra::{
+        curves::mnt6753::{G1Affine as MNT6G1Affine, G1Projective as MNT6G1Projective},
+        ProjectiveCurve, UniformRand,
+    };
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    #[test]
+    fn test_poseidon_transcript_order() {
+        let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+
+        let a = MNT4753Fr::rand(rng);
+        let b = MNT4753Fr::rand(rng);
+        let p: MNT6G1Affine = MNT6G1Projective::rand(rng).into_affine();
+
+        let transcript = |first: &MNT4753Fr, second: &MNT4753Fr, label: &[u8]| {
+            let mut t = MNT4PoseidonTranscript::new();
+            t.append_field(b"first", first);
+            t.append_point(b"point", &p).unwrap();
+            t.append_field(label, second);
+            let c_1 = t.challenge();
+            let c_2 = t.challenge();
+            assert_ne!(c_1, c_2);
+            (c_1, c_2)
+        };
+
+        // Deterministic
+        let challenges = transcript(&a, &b, b"second");
+        assert_eq!(challenges, transcript(&a, &b, b"second"));
+
+        // Reordering the appends, or changing a label, changes the challenges
+        assert_ne!(challenges, transcript(&b, &a, b"second"));
+        assert_ne!(challenges.0, transcript(&a, &b, b"other").0);
+    }
+}
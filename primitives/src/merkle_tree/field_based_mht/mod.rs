@@ -251,6 +251,128 @@ impl<P: FieldBasedMerkleTreeConfig> FieldBasedMerkleHashTree<P> {
     }
 }
 
+/// Append-only Merkle Tree of fixed height `P::HEIGHT` whose leaves are field elements.
+/// Only the nodes depending on already appended leaves are stored: the ones depending
+/// only on empty leaves are the roots of empty subtrees, precomputed once per level.
+/// Appending a leaf updates just its path to the root, i.e. `P::HEIGHT - 1` hashes.
+/// NOTE: Empty subtrees are hashed level by level, hence the root differs from the
+/// one of a `FieldBasedMerkleHashTree` built from the same (not full) set of leaves.
+pub struct FieldBasedAppendOnlyMerkleTree<P: FieldBasedMerkleTreeConfig> {
+    // nodes[i] holds the non-empty nodes at level i, starting from the leaves
+    nodes:       Vec<Vec<<P::H as FieldBasedHash>::Data>>,
+    // empty_nodes[i] is the root of an empty subtree of height i + 1
+    empty_nodes: Vec<<P::H as FieldBasedHash>::Data>,
+}
+
+impl<P: FieldBasedMerkleTreeConfig> FieldBasedAppendOnlyMerkleTree<P> {
+    pub const HEIGHT: u8 = P::HEIGHT as u8;
+
+    pub fn new() -> Result<Self, Error> {
+        assert!(P::HEIGHT > 0);
+
+        let mut empty_nodes = Vec::with_capacity(P::HEIGHT);
+        let mut empty_hash = hash_empty::<P::H>()?;
+        empty_nodes.push(empty_hash);
+        for _ in 1..P::HEIGHT {
+            empty_hash = hash_inner_node::<P::H>(empty_hash, empty_hash)?;
+            empty_nodes.push(empty_hash);
+        }
+
+        Ok(FieldBasedAppendOnlyMerkleTree {
+            nodes: vec![Vec::new(); P::HEIGHT],
+            empty_nodes,
+        })
+    }
+
+    /// Maximum number of leaves the tree can hold.
+    #[inline]
+    pub fn capacity() -> usize {
+        1 << (P::HEIGHT - 1)
+    }
+
+    #[inline]
+    pub fn num_leaves(&self) -> usize {
+        self.nodes[0].len()
+    }
+
+    #[inline]
+    pub fn leaves(&self) -> &[<P::H as FieldBasedHash>::Data] {
+        self.nodes[0].as_slice()
+    }
+
+    /// Appends `leaf` to the tree, returning its index, and updates the path from it
+    /// to the root.
+    pub fn append(
+        &mut self,
+        leaf: <P::H as FieldBasedHash>::Data,
+    ) -> Result<usize, Error>
+    {
+        let index = self.num_leaves();
+        if index == Self::capacity() {
+            return Err(MerkleTreeError::TooManyLeaves(Self::capacity()))?
+        }
+        self.nodes[0].push(leaf);
+
+        let mut current_index = index;
+        for level in 0..P::HEIGHT - 1 {
+            let left_index = current_index & !1;
+            let left = self.nodes[level][left_index];
+            let right = self.node(level, left_index + 1);
+            let parent_hash = hash_inner_node::<P::H>(left, right)?;
+
+            current_index >>= 1;
+            if current_index == self.nodes[level + 1].len() {
+                self.nodes[level + 1].push(parent_hash);
+            } else {
+                self.nodes[level + 1][current_index] = parent_hash;
+            }
+        }
+
+        Ok(index)
+    }
+
+    #[inline]
+    pub fn root(&self) -> <P::H as FieldBasedHash>::Data {
+        self.node(P::HEIGHT - 1, 0)
+    }
+
+    pub fn generate_proof(
+        &self,
+        index: usize,
+    ) -> Result<FieldBasedMerkleTreePath<P>, Error>
+    {
+        if index >= self.num_leaves() {
+            return Err(MerkleTreeError::IncorrectLeafIndex(index))?
+        }
+
+        let mut path = Vec::with_capacity(P::HEIGHT - 1);
+        let mut current_index = index;
+        for level in 0..P::HEIGHT - 1 {
+            let sibling_hash = self.node(level, current_index ^ 1);
+            path.push((sibling_hash, current_index & 1 == 1));
+            current_index >>= 1;
+        }
+
+        Ok(FieldBasedMerkleTreePath { path })
+    }
+
+    /// Checks that `path` is a valid Merkle Path from `leaf` to `root`.
+    #[inline]
+    pub fn verify_proof(
+        root: &<P::H as FieldBasedHash>::Data,
+        leaf: &<P::H as FieldBasedHash>::Data,
+        path: &FieldBasedMerkleTreePath<P>,
+    ) -> Result<bool, Error>
+    {
+        path.verify(root, leaf)
+    }
+
+    #[inline]
+    fn node(&self, level: usize, index: usize) -> <P::H as FieldBasedHash>::Data {
+        self.nodes[level].get(index).cloned().unwrap_or(self.empty_nodes[level])
+    }
+}
+
 /// Returns the output hash, given a left and right hash value.
 pub(crate) fn hash_inner_node<H: FieldBasedHash>(
     left: H::Data,
@@ -381,4 +503,36 @@ mod test {
         }
         bad_merkle_tree_verify(&leaves);
     }
-}
\ No newline at end of file
+
+    type MNT4753FieldBasedAppendOnlyMerkleTree = FieldBasedAppendOnlyMerkleTree<MNT4753FieldBasedMerkleTreeParams>;
+
+    #[test]
+    fn append_only_merkle_tree_test() {
+        let mut rng = XorShiftRng::seed_from_u64(9174123u64);
+
+        let mut tree = MNT4753FieldBasedAppendOnlyMerkleTree::new().unwrap();
+        let mut leaves = Vec::new();
+
+        for i in 0..MNT4753FieldBasedAppendOnlyMerkleTree::capacity() {
+            let leaf = Fr::rand(&mut rng);
+            assert_eq!(tree.append(leaf).unwrap(), i);
+            leaves.push(leaf);
+
+            //All the proofs must verify against the updated root
+            let root = tree.root();
+            for (j, leaf) in leaves.iter().enumerate() {
+                let proof = tree.generate_proof(j).unwrap();
+                assert!(MNT4753FieldBasedAppendOnlyMerkleTree::verify_proof(&root, leaf, &proof).unwrap());
+                assert!(!MNT4753FieldBasedAppendOnlyMerkleTree::verify_proof(&Fr::zero(), leaf, &proof).unwrap());
+            }
+            assert!(tree.generate_proof(i + 1).is_err());
+        }
+        assert_eq!(tree.leaves(), leaves.as_slice());
+
+        //The full tree must be the same as the one built from all the leaves at once
+        assert_eq!(tree.root(), MNT4753FieldBasedMerkleTree::new(&leaves).unwrap().root());
+
+        //No more room for other leaves
+        assert!(tree.append(Fr::rand(&mut rng)).is_err());
+    }
+}
@@ -233,6 +233,7 @@ impl<P: MerkleTreeConfig> MerkleHashTree<P> {
 pub enum MerkleTreeError {
     IncorrectLeafIndex(usize),
     IncorrectPathLength(usize),
+    TooManyLeaves(usize),
 }
 
 impl std::fmt::Display for MerkleTreeError {
@@ -242,6 +243,7 @@ impl std::fmt::Display for MerkleTreeError {
                 format!("incorrect leaf index: {}", index)
             },
             MerkleTreeError::IncorrectPathLength(len) => format!("incorrect path length: {}", len),
+            MerkleTreeError::TooManyLeaves(max) => format!("reached maximum number of leaves: {}", max),
         };
         write!(f, "{}", msg)
     }
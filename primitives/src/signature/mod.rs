@@ -66,8 +66,9 @@ pub trait FieldBasedSignatureScheme {
         sk: &Self::SecretKey
     ) -> Self::PublicKey;
 
-    fn sign<R: Rng>(
-        rng: &mut R,
+    /// Signing is deterministic: the nonce is derived from the secret key and the
+    /// message, hence no randomness is needed.
+    fn sign(
         pk: &Self::PublicKey,
         sk: &Self::SecretKey,
         message: &[Self::Data],
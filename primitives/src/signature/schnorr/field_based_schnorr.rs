@@ -1,10 +1,13 @@
 use crate::{crh::FieldBasedHash, signature::FieldBasedSignatureScheme, CryptoError, Error, compute_truncation_size};
-use algebra::{Field, PrimeField, Group, UniformRand, ProjectiveCurve,
+use algebra::{Field, PrimeField, FpParameters, Group, UniformRand, ProjectiveCurve,
               convert, leading_zeros, ToBits, ToConstraintField, ToBytes, FromBytes};
 use std::marker::PhantomData;
 use rand::Rng;
 use std::io::{Write, Read, Result as IoResult};
 
+/// Statistical security, in bits, of the uniformity of the signing nonce.
+const NONCE_SECURITY_BITS: usize = 128;
+
 #[allow(dead_code)]
 pub struct FieldBasedSchnorrSignatureScheme<
     F: PrimeField,
@@ -46,6 +49,53 @@ impl<F: PrimeField> FromBytes for FieldBasedSchnorrSignature<F> {
     }
 }
 
+impl<F: PrimeField, G: ProjectiveCurve + ToConstraintField<F>, H: FieldBasedHash<Data = F>>
+FieldBasedSchnorrSignatureScheme<F, G, H>
+{
+    /// Deterministically derives the signing nonce from the secret key and the message,
+    /// in the spirit of RFC 6979. The hashes H(sk || m || pk.x || counter || i), for
+    /// i = 0, 1, .., contribute their CAPACITY - 128 least significant bits each (which
+    /// are 2^-128-close to uniform), until MODULUS_BITS + 128 bits are collected. These
+    /// are reduced modulo the order of G, so that the nonce is statistically close to
+    /// uniform: truncating a single hash to the capacity of G::ScalarField would bias it.
+    /// The counter allows to derive a fresh nonce whenever the current one must be rejected.
+    fn derive_nonce(
+        sk_coords: &[F],
+        message:   &[F],
+        pk_x:      F,
+        counter:   u64,
+    ) -> Result<G::ScalarField, Error>
+    {
+        let capacity = <F as PrimeField>::Params::CAPACITY as usize;
+        if capacity <= NONCE_SECURITY_BITS {
+            return Err(Box::new(CryptoError::IncorrectInputLength("hash capacity".to_owned(), capacity)))
+        }
+        let bits_per_hash = capacity - NONCE_SECURITY_BITS;
+        let required_bits = <G::ScalarField as PrimeField>::Params::MODULUS_BITS as usize + NONCE_SECURITY_BITS;
+
+        let mut nonce_bits = Vec::with_capacity(required_bits + bits_per_hash);
+        let mut i = 0u64;
+        while nonce_bits.len() < required_bits {
+            let mut hash_input = Vec::new();
+            hash_input.extend_from_slice(sk_coords);
+            hash_input.extend_from_slice(message);
+            hash_input.push(pk_x);
+            hash_input.push(F::from(counter));
+            hash_input.push(F::from(i));
+            let h_bits = H::evaluate(hash_input.as_ref())?.write_bits();
+            nonce_bits.extend_from_slice(&h_bits[h_bits.len() - bits_per_hash..]);
+            i += 1;
+        }
+
+        //Reduce the (big endian) bits modulo the order of G
+        let one = G::ScalarField::one();
+        Ok(nonce_bits.into_iter().fold(G::ScalarField::zero(), |acc, bit| {
+            let acc = acc.double();
+            if bit { acc + &one } else { acc }
+        }))
+    }
+}
+
 impl<F: PrimeField, G: ProjectiveCurve + ToConstraintField<F>, H: FieldBasedHash<Data = F>> FieldBasedSignatureScheme for
 FieldBasedSchnorrSignatureScheme<F, G, H>
 {
@@ -66,19 +116,21 @@ FieldBasedSchnorrSignatureScheme<F, G, H>
         G::prime_subgroup_generator().mul(sk)
     }
 
-    fn sign<R: Rng>(
-        rng: &mut R,
+    fn sign(
         pk: &Self::PublicKey,
         sk: &Self::SecretKey,
         message: &[Self::Data],
     )-> Result<Self::Signature, Error>
     {
         let pk_coords = pk.to_field_elements()?;
+        let sk_coords = sk.write_bits().as_slice().to_field_elements()?;
+        let mut counter = 0u64;
 
         let (e, s) = loop {
 
-            //Sample random element
-            let k = G::ScalarField::rand(rng);
+            //Derive nonce from sk and message
+            let k = Self::derive_nonce(sk_coords.as_slice(), message, pk_coords[0], counter)?;
+            counter += 1;
 
             if k.is_zero() {continue};
 
@@ -199,7 +251,7 @@ mod test {
         let (pk, sk) = S::keygen(rng);
         assert!(S::keyverify(&pk));
         assert_eq!(pk, S::get_public_key(&sk));
-        let sig = S::sign(rng, &pk, &sk, &message).unwrap();
+        let sig = S::sign(&pk, &sk, &message).unwrap();
         assert!(S::verify(&pk, &message, &sig).unwrap());
 
         //Serialization/deserialization test
@@ -215,11 +267,11 @@ mod test {
         assert_eq!(pk, S::get_public_key(&sk));
 
         //Attempt to verify a signature for a different message
-        let sig = S::sign(rng, &pk, &sk, message).unwrap();
+        let sig = S::sign(&pk, &sk, message).unwrap();
         assert!(!S::verify(&pk, bad_message, &sig).unwrap());
 
         //Attempt to verify a different signature for a message
-        let bad_sig = S::sign(rng, &pk, &sk, bad_message).unwrap();
+        let bad_sig = S::sign(&pk, &sk, bad_message).unwrap();
         assert!(!S::verify(&pk, message, &bad_sig).unwrap());
 
         //Attempt to verify a signature for a message but with different public key
@@ -227,6 +279,19 @@ mod test {
         assert!(!S::verify(&new_pk, message, &sig).unwrap());
     }
 
+    fn deterministic_signing<S: FieldBasedSignatureScheme, R: Rng>(rng: &mut R, message: &[S::Data], other_message: &[S::Data]) {
+        let (pk, sk) = S::keygen(rng);
+
+        //Signing twice the same message must produce the same signature
+        let sig = S::sign(&pk, &sk, message).unwrap();
+        assert_eq!(sig, S::sign(&pk, &sk, message).unwrap());
+
+        //The nonce depends on the message
+        let other_sig = S::sign(&pk, &sk, other_message).unwrap();
+        assert_ne!(sig, other_sig);
+        assert!(S::verify(&pk, other_message, &other_sig).unwrap());
+    }
+
     #[test]
     fn mnt4_schnorr_test() {
         let rng = &mut thread_rng();
@@ -236,6 +301,7 @@ mod test {
             let g: MNT4Fr = rng.gen();
             sign_and_verify::<SchnorrMNT4, _>(rng, &[f, g]);
             failed_verification::<SchnorrMNT4, _>(rng, &[f], &[g]);
+            deterministic_signing::<SchnorrMNT4, _>(rng, &[f], &[g]);
         }
     }
 
@@ -248,6 +314,7 @@ mod test {
             let g: MNT6Fr = rng.gen();
             sign_and_verify::<SchnorrMNT6, _>(rng,&[f, g]);
             failed_verification::<SchnorrMNT6, _>(rng, &[f], &[g]);
+            deterministic_signing::<SchnorrMNT6, _>(rng, &[f], &[g]);
         }
     }
 }
\ No newline at end of file
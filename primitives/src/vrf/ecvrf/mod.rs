@@ -294,6 +294,11 @@ mod test {
         let proof_deserialized = <S as FieldBasedVrf>::Proof::read(proof_serialized.as_slice()).unwrap();
         assert_eq!(proof, proof_deserialized);
         assert!(S::proof_to_hash(pp, &pk, &message, &proof_deserialized).is_ok());
+
+        //Different proofs for the same message must lead to the same VRF output
+        let output = S::proof_to_hash(pp, &pk, &message, &proof).unwrap();
+        let new_proof = S::prove(rng, pp, &pk, &sk, &message).unwrap();
+        assert_eq!(output, S::proof_to_hash(pp, &pk, &message, &new_proof).unwrap());
     }
 
     fn failed_verification<S: FieldBasedVrf, R: Rng>(rng: &mut R, message: &[S::Data], bad_message: &[S::Data], pp: &S::GHParams) {
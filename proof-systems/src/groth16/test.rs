@@ -39,6 +39,7 @@ mod bls12_377 {
     use crate::groth16::{
         Parameters, Proof, VerifyingKey, PreparedVerifyingKey,
         create_random_proof, generate_random_parameters, prepare_verifying_key, verify_proof,
+        batch_verify_proof,
     };
 
     use algebra::{curves::bls12_377::Bls12_377, fields::bls12_377::Fr, UniformRand,
@@ -78,6 +79,55 @@ mod bls12_377 {
         }
     }
 
+    #[test]
+    fn batch_verify() {
+        let rng = &mut thread_rng();
+
+        let params =
+            generate_random_parameters::<Bls12_377, _, _>(MySillyCircuit { a: None, b: None }, rng)
+                .unwrap();
+
+        let pvk = prepare_verifying_key::<Bls12_377>(&params.vk);
+
+        let mut proofs_and_inputs = Vec::new();
+        for _ in 0..10 {
+            let a = Fr::rand(rng);
+            let b = Fr::rand(rng);
+            let c = a * &b;
+
+            let proof = create_random_proof(
+                MySillyCircuit {
+                    a: Some(a),
+                    b: Some(b),
+                },
+                &params,
+                rng,
+            )
+            .unwrap();
+            proofs_and_inputs.push((proof, vec![c]));
+        }
+
+        assert!(batch_verify_proof(&pvk, &proofs_and_inputs, rng).unwrap());
+
+        // A single proof with wrong public inputs must invalidate the whole batch
+        let mut bad_batch = proofs_and_inputs.clone();
+        bad_batch[5].1 = vec![Fr::rand(rng)];
+        assert!(!batch_verify_proof(&pvk, &bad_batch, rng).unwrap());
+
+        // Same for a single forged proof
+        let mut bad_batch = proofs_and_inputs.clone();
+        bad_batch[3].0.c = bad_batch[4].0.c;
+        assert!(!batch_verify_proof(&pvk, &bad_batch, rng).unwrap());
+
+        // Malformed public inputs
+        let mut bad_batch = proofs_and_inputs;
+        bad_batch[0].1.push(Fr::rand(rng));
+        assert!(batch_verify_proof(&pvk, &bad_batch, rng).is_err());
+
+        // The empty batch is accepted
+        assert!(batch_verify_proof(&pvk, &[], rng).unwrap());
+    }
+
     #[test]
     fn serialize_deserialize() {
 
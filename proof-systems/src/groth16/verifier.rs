@@ -1,9 +1,10 @@
-use algebra::{AffineCurve, PairingCurve, PairingEngine, PrimeField, ProjectiveCurve};
+use algebra::{AffineCurve, Field, PairingCurve, PairingEngine, PrimeField, ProjectiveCurve, UniformRand};
 
 use super::{PreparedVerifyingKey, Proof, VerifyingKey};
 
 use crate::groth16::SynthesisError;
 
+use rand::Rng;
 use std::ops::{AddAssign, Neg};
 
 pub fn prepare_verifying_key<E: PairingEngine>(vk: &VerifyingKey<E>) -> PreparedVerifyingKey<E> {
@@ -41,4 +42,59 @@ pub fn verify_proof<E: PairingEngine>(
     let test = E::final_exponentiation(&qap).ok_or(SynthesisError::UnexpectedIdentity)?;
 
     Ok(test == pvk.alpha_g1_beta_g2)
-}
\ No newline at end of file
+}
+
+/// Verify a batch of proofs sharing the same verifying key. The pairing checks
+/// are combined into a single one by means of random scalars r_i sampled from
+/// `rng`, i.e. it is checked that
+/// prod_i e(r_i * A_i, B_i) * e(sum_i r_i * g_ic_i, -gamma) * e(sum_i r_i * C_i, -delta)
+/// equals alpha_g1_beta_g2^(sum_i r_i). If any of the proofs is invalid, the check
+/// fails with overwhelming probability. An empty batch contains no invalid proof,
+/// hence it is accepted without computing any pairing.
+pub fn batch_verify_proof<E: PairingEngine, R: Rng>(
+    pvk: &PreparedVerifyingKey<E>,
+    proofs_and_inputs: &[(Proof<E>, Vec<E::Fr>)],
+    rng: &mut R,
+) -> Result<bool, SynthesisError> {
+    if proofs_and_inputs.is_empty() {
+        return Ok(true);
+    }
+
+    let mut r_a_b = Vec::with_capacity(proofs_and_inputs.len());
+    let mut acc_g_ic = E::G1Projective::zero();
+    let mut acc_c = E::G1Projective::zero();
+    let mut r_sum = E::Fr::zero();
+
+    for (proof, public_inputs) in proofs_and_inputs.iter() {
+        if (public_inputs.len() + 1) != pvk.gamma_abc_g1.len() {
+            return Err(SynthesisError::MalformedVerifyingKey);
+        }
+
+        let r = E::Fr::rand(rng);
+        let r_repr = r.into_repr();
+
+        let mut g_ic = pvk.gamma_abc_g1[0].into_projective();
+        for (i, b) in public_inputs.iter().zip(pvk.gamma_abc_g1.iter().skip(1)) {
+            g_ic.add_assign(&b.mul(i.into_repr()));
+        }
+
+        ProjectiveCurve::mul_assign(&mut g_ic, r_repr);
+        acc_g_ic.add_assign(&g_ic);
+        acc_c.add_assign(&proof.c.mul(r_repr));
+        r_a_b.push((proof.a.mul(r_repr).into_affine().prepare(), proof.b.prepare()));
+        r_sum.add_assign(&r);
+    }
+
+    let acc_g_ic = acc_g_ic.into_affine().prepare();
+    let acc_c = acc_c.into_affine().prepare();
+
+    let mut pairs = r_a_b.iter().map(|(a, b)| (a, b)).collect::<Vec<_>>();
+    pairs.push((&acc_g_ic, &pvk.gamma_g2_neg_pc));
+    pairs.push((&acc_c, &pvk.delta_g2_neg_pc));
+
+    let qap = E::miller_loop(pairs.iter());
+
+    let test = E::final_exponentiation(&qap).ok_or(SynthesisError::UnexpectedIdentity)?;
+
+    Ok(test == pvk.alpha_g1_beta_g2.pow(r_sum.into_repr()))
+}
@@ -230,3 +230,113 @@ impl<F: Field, CS: ConstraintSystem<F>> ConstraintSystem<F> for &mut CS {
         (**self).num_constraints()
     }
 }
+
+/// A constraint system wrapping another one, and counting the constraints and the
+/// variables allocated through it, so as to measure the cost of a (sub-)gadget:
+/// ```ignore
+/// let mut counter = ConstraintCounter::new(&mut cs);
+/// G2PreparedGadget::from_affine(counter.ns(|| "prepare g2"), &q)?;
+/// println!("{} constraints", counter.num_constraints());
+/// ```
+/// All calls are forwarded to the wrapped constraint system, including namespaces.
+pub struct ConstraintCounter<'a, F: Field, CS: ConstraintSystem<F>> {
+    cs:                    &'a mut CS,
+    num_constraints:       usize,
+    num_witness_variables: usize,
+    num_input_variables:   usize,
+    _field:                PhantomData<F>,
+}
+
+impl<'a, F: Field, CS: ConstraintSystem<F>> ConstraintCounter<'a, F, CS> {
+    /// Wraps `cs`, starting all counts at zero.
+    pub fn new(cs: &'a mut CS) -> Self {
+        ConstraintCounter {
+            cs,
+            num_constraints: 0,
+            num_witness_variables: 0,
+            num_input_variables: 0,
+            _field: PhantomData,
+        }
+    }
+
+    /// Output the number of private variables allocated through this wrapper.
+    pub fn num_witness_variables(&self) -> usize {
+        self.num_witness_variables
+    }
+
+    /// Output the number of public variables allocated through this wrapper.
+    pub fn num_input_variables(&self) -> usize {
+        self.num_input_variables
+    }
+}
+
+impl<F: Field, CS: ConstraintSystem<F>> ConstraintSystem<F> for ConstraintCounter<'_, F, CS> {
+    type Root = Self;
+
+    #[inline]
+    fn one() -> Variable {
+        CS::one()
+    }
+
+    #[inline]
+    fn alloc<FN, A, AR>(&mut self, annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        let var = self.cs.alloc(annotation, f)?;
+        self.num_witness_variables += 1;
+        Ok(var)
+    }
+
+    #[inline]
+    fn alloc_input<FN, A, AR>(&mut self, annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        let var = self.cs.alloc_input(annotation, f)?;
+        self.num_input_variables += 1;
+        Ok(var)
+    }
+
+    #[inline]
+    fn enforce<A, AR, LA, LB, LC>(&mut self, annotation: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        self.cs.enforce(annotation, a, b, c);
+        self.num_constraints += 1;
+    }
+
+    #[inline]
+    fn push_namespace<NR, N>(&mut self, name_fn: N)
+    where
+        NR: Into<String>,
+        N: FnOnce() -> NR,
+    {
+        self.cs.get_root().push_namespace(name_fn)
+    }
+
+    #[inline]
+    fn pop_namespace(&mut self) {
+        self.cs.get_root().pop_namespace()
+    }
+
+    #[inline]
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    /// Output the number of constraints enforced through this wrapper.
+    #[inline]
+    fn num_constraints(&self) -> usize {
+        self.num_constraints
+    }
+}
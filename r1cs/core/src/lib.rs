@@ -14,7 +14,7 @@ mod error;
 mod impl_lc;
 mod impl_constraint_var;
 
-pub use constraint_system::{ConstraintSystem, ConstraintSynthesizer, Namespace};
+pub use constraint_system::{ConstraintCounter, ConstraintSystem, ConstraintSynthesizer, Namespace};
 pub use error::SynthesisError;
 pub use algebra::ToConstraintField;
 
@@ -73,12 +73,12 @@ impl Ord for Index {
 /// in the field `F`.
 /// The `(coeff, var)` pairs in a `LinearCombination` are kept sorted according
 /// to the index of the variable in its constraint system.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct LinearCombination<F: Field>(pub SmallVec<F>);
 
 
 /// Either a `Variable` or a `LinearCombination`.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum ConstraintVar<F: Field> {
     /// A wrapper around a `LinearCombination`.
     LC(LinearCombination<F>),
@@ -250,4 +250,77 @@ mod test {
         assert_eq!(primitive_result.y, gadget_result.y.value.unwrap());
         assert!(cs.is_satisfied());
     }
+
+    #[test]
+    fn commitment_gadget_binding_test() {
+        #[derive(Clone, PartialEq, Eq, Hash)]
+        pub(super) struct Window;
+
+        impl PedersenWindow for Window {
+            const WINDOW_SIZE: usize = 4;
+            const NUM_WINDOWS: usize = 8;
+        }
+
+        type TestCOMM = PedersenCommitment<JubJub, Window>;
+        type TestCOMMGadget = PedersenCommitmentGadget<JubJub, Fq, JubJubGadget>;
+
+        let rng = &mut thread_rng();
+        let parameters = TestCOMM::setup(rng).unwrap();
+        // One generator per message bit
+        assert_eq!(
+            parameters.generators.iter().map(|g| g.len()).sum::<usize>(),
+            Window::WINDOW_SIZE * Window::NUM_WINDOWS
+        );
+
+        let randomness = PedersenRandomness(Fr::rand(rng));
+        let other_randomness = PedersenRandomness(Fr::rand(rng));
+        let input = [1u8, 2, 3, 4];
+        // Differs from `input` in a single bit
+        let other_input = [1u8, 2, 3, 5];
+
+        let commit_gadget = |cs: &mut TestConstraintSystem<Fq>, input: &[u8], randomness: &PedersenRandomness<JubJub>| {
+            let input_bytes = UInt8::alloc_vec(cs.ns(|| "input"), input).unwrap();
+            let randomness =
+                <TestCOMMGadget as CommitmentGadget<TestCOMM, Fq>>::RandomnessGadget::alloc(
+                    cs.ns(|| "gadget_randomness"),
+                    || Ok(randomness),
+                )
+                .unwrap();
+            let gadget_parameters =
+                <TestCOMMGadget as CommitmentGadget<TestCOMM, Fq>>::ParametersGadget::alloc(
+                    cs.ns(|| "gadget_parameters"),
+                    || Ok(&parameters),
+                )
+                .unwrap();
+            <TestCOMMGadget as CommitmentGadget<TestCOMM, Fq>>::check_commitment_gadget(
+                cs.ns(|| "gadget_evaluation"),
+                &gadget_parameters,
+                &input_bytes,
+                &randomness,
+            )
+            .unwrap()
+        };
+
+        let mut commitments = vec![];
+        for (input, randomness) in vec![
+            (&input, &randomness),
+            (&other_input, &randomness),
+            (&input, &other_randomness),
+        ] {
+            let mut cs = TestConstraintSystem::<Fq>::new();
+            let primitive_result = TestCOMM::commit(&parameters, input, randomness)
+                .unwrap()
+                .into_affine();
+            let gadget_result = commit_gadget(&mut cs, input, randomness);
+            assert_eq!(primitive_result.x, gadget_result.x.value.unwrap());
+            assert_eq!(primitive_result.y, gadget_result.y.value.unwrap());
+            assert!(cs.is_satisfied());
+            commitments.push(primitive_result);
+        }
+
+        // Changing either the message or the randomness changes the commitment
+        assert_ne!(commitments[0], commitments[1]);
+        assert_ne!(commitments[0], commitments[2]);
+        assert_ne!(commitments[1], commitments[2]);
+    }
 }
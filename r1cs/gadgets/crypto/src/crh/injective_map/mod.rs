@@ -102,6 +102,29 @@ where
     _crh:               PedersenCRHGadget<G, ConstraintF, GG>,
 }
 
+impl<G, I, ConstraintF, GG, IG> PedersenCRHCompressorGadget<G, I, ConstraintF, GG, IG>
+where
+    G: Group,
+    I: InjectiveMap<G>,
+    ConstraintF: Field,
+    GG: GroupGadget<G, ConstraintF>,
+    IG: InjectiveMapGadget<G, I, ConstraintF, GG>,
+{
+    /// Enforces the compressed hash of a bit string, see `PedersenCRHCompressor::evaluate_bits`.
+    pub fn check_evaluation_gadget_bits<W: PedersenWindow, CS: ConstraintSystem<ConstraintF>>(
+        mut cs: CS,
+        parameters: &PedersenCRHGadgetParameters<G, W, ConstraintF, GG>,
+        input: &[Boolean],
+    ) -> Result<IG::OutputGadget, SynthesisError> {
+        let result = PedersenCRHGadget::<G, ConstraintF, GG>::check_evaluation_gadget_bits(
+            cs.ns(|| "PedCRH"),
+            parameters,
+            input,
+        )?;
+        IG::evaluate_map(cs.ns(|| "InjectiveMap"), &result)
+    }
+}
+
 impl<G, I, ConstraintF, GG, IG, W> FixedLengthCRHGadget<PedersenCRHCompressor<G, I, W>, ConstraintF>
     for PedersenCRHCompressorGadget<G, I, ConstraintF, GG, IG>
 where
@@ -128,3 +151,87 @@ where
         IG::evaluate_map(cs.ns(|| "InjectiveMap"), &result)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use algebra::{
+        curves::{jubjub::JubJubProjective as JubJub, ProjectiveCurve},
+        fields::bls12_381::fr::Fr,
+    };
+    use rand::{thread_rng, Rng};
+
+    use primitives::{
+        bytes_to_bits,
+        crh::{
+            injective_map::{PedersenCRHCompressor, TECompressor},
+            pedersen::{PedersenCRH, PedersenWindow},
+            FixedLengthCRH,
+        },
+    };
+    use crate::crh::{
+        injective_map::{PedersenCRHCompressorGadget, TECompressorGadget},
+        FixedLengthCRHGadget,
+    };
+    use r1cs_core::ConstraintSystem;
+    use r1cs_std::{
+        groups::curves::twisted_edwards::jubjub::JubJubGadget, prelude::*,
+        test_constraint_system::TestConstraintSystem,
+    };
+
+    type TestCRH = PedersenCRHCompressor<JubJub, TECompressor, Window>;
+    type TestCRHGadget = PedersenCRHCompressorGadget<JubJub, TECompressor, Fr, JubJubGadget, TECompressorGadget>;
+
+    #[derive(Clone, PartialEq, Eq, Hash)]
+    pub(super) struct Window;
+
+    impl PedersenWindow for Window {
+        const WINDOW_SIZE: usize = 64;
+        const NUM_WINDOWS: usize = 4;
+    }
+
+    #[test]
+    fn compressed_pedersen_bits_gadget_test() {
+        let rng = &mut thread_rng();
+        let parameters = TestCRH::setup(rng).unwrap();
+
+        // Not a multiple of the window size, nor of 8
+        let input = (0..203).map(|_| rng.gen()).collect::<Vec<bool>>();
+        let primitive_result = TestCRH::evaluate_bits(&parameters, &input).unwrap();
+        assert_eq!(
+            primitive_result,
+            PedersenCRH::<JubJub, Window>::evaluate_bits(&parameters, &input).unwrap().into_affine().x
+        );
+
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let input_bits = Vec::<Boolean>::alloc(cs.ns(|| "input"), || Ok(input.clone())).unwrap();
+        let gadget_parameters =
+            <TestCRHGadget as FixedLengthCRHGadget<TestCRH, Fr>>::ParametersGadget::alloc(
+                &mut cs.ns(|| "gadget_parameters"),
+                || Ok(&parameters),
+            )
+            .unwrap();
+        let gadget_result = TestCRHGadget::check_evaluation_gadget_bits(
+            cs.ns(|| "gadget_evaluation"),
+            &gadget_parameters,
+            &input_bits,
+        )
+        .unwrap();
+        assert_eq!(gadget_result.value.unwrap(), primitive_result);
+        assert!(cs.is_satisfied());
+
+        // Agrees with the byte oriented gadget
+        let bytes = [0x9fu8, 0x3a, 0xc5];
+        let input_bytes = UInt8::alloc_vec(cs.ns(|| "input bytes"), &bytes).unwrap();
+        let bytes_result = <TestCRHGadget as FixedLengthCRHGadget<TestCRH, Fr>>::check_evaluation_gadget(
+            cs.ns(|| "gadget_evaluation on bytes"),
+            &gadget_parameters,
+            &input_bytes,
+        )
+        .unwrap();
+        assert_eq!(
+            bytes_result.value.unwrap(),
+            TestCRH::evaluate_bits(&parameters, &bytes_to_bits(&bytes)).unwrap()
+        );
+        assert!(cs.is_satisfied());
+    }
+}
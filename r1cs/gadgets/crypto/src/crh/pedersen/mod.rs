@@ -30,6 +30,30 @@ pub struct PedersenCRHGadget<G: Group, ConstraintF: Field, GG: GroupGadget<G, Co
     _engine: PhantomData<ConstraintF>,
 }
 
+impl<ConstraintF, G, GG> PedersenCRHGadget<G, ConstraintF, GG>
+where
+    ConstraintF: Field,
+    G: Group,
+    GG: GroupGadget<G, ConstraintF>,
+{
+    /// Enforces the hash of a bit string, see `PedersenCRH::evaluate_bits`.
+    pub fn check_evaluation_gadget_bits<W: PedersenWindow, CS: ConstraintSystem<ConstraintF>>(
+        cs: CS,
+        parameters: &PedersenCRHGadgetParameters<G, W, ConstraintF, GG>,
+        input: &[Boolean],
+    ) -> Result<GG, SynthesisError> {
+        assert!(input.len() <= W::WINDOW_SIZE * W::NUM_WINDOWS);
+        assert_eq!(parameters.params.generators.len(), W::NUM_WINDOWS);
+
+        // Pad the input if it is not the current length.
+        let mut padded_input = input.to_vec();
+        padded_input.resize(W::WINDOW_SIZE * W::NUM_WINDOWS, Boolean::constant(false));
+
+        let input_in_bits = padded_input.chunks(W::WINDOW_SIZE);
+        GG::precomputed_base_multiscalar_mul(cs, &parameters.params.generators, input_in_bits)
+    }
+}
+
 impl<ConstraintF, G, GG, W> FixedLengthCRHGadget<PedersenCRH<G, W>, ConstraintF>
     for PedersenCRHGadget<G, ConstraintF, GG>
 where
@@ -46,25 +70,9 @@ where
         parameters: &Self::ParametersGadget,
         input: &[UInt8],
     ) -> Result<Self::OutputGadget, SynthesisError> {
-        let mut padded_input = input.to_vec();
-        // Pad the input if it is not the current length.
-        if input.len() * 8 < W::WINDOW_SIZE * W::NUM_WINDOWS {
-            let current_length = input.len();
-            for _ in current_length..(W::WINDOW_SIZE * W::NUM_WINDOWS / 8) {
-                padded_input.push(UInt8::constant(0u8));
-            }
-        }
-        assert_eq!(padded_input.len() * 8, W::WINDOW_SIZE * W::NUM_WINDOWS);
-        assert_eq!(parameters.params.generators.len(), W::NUM_WINDOWS);
-
-        // Allocate new variable for the result.
-        let input_in_bits: Vec<_> = padded_input
-            .iter()
-            .flat_map(|byte| byte.into_bits_le())
-            .collect();
-        let input_in_bits = input_in_bits.chunks(W::WINDOW_SIZE);
-        let result =
-            GG::precomputed_base_multiscalar_mul(cs, &parameters.params.generators, input_in_bits)?;
+        assert!(input.len() * 8 <= W::WINDOW_SIZE * W::NUM_WINDOWS);
+        let input_in_bits: Vec<_> = input.iter().flat_map(|byte| byte.into_bits_le()).collect();
+        let result = Self::check_evaluation_gadget_bits(cs, parameters, &input_in_bits)?;
 
         Ok(result)
     }
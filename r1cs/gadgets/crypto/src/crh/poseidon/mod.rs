@@ -24,6 +24,9 @@ use r1cs_std::eq::ConditionalEqGadget;
 pub type MNT4PoseidonHashGadget = PoseidonHashGadget<MNT4753Fr, MNT4753PoseidonParameters>;
 pub type MNT6PoseidonHashGadget = PoseidonHashGadget<MNT6753Fr, MNT6753PoseidonParameters>;
 
+pub mod transcript;
+pub use self::transcript::*;
+
 pub struct PoseidonHashGadget
 <
     ConstraintF: PrimeField,
@@ -283,6 +286,90 @@ impl<ConstraintF, P> FieldBasedHashGadget<PoseidonHash<ConstraintF, P>, Constrai
     }
 }
 
+/// In-circuit counterpart of `PoseidonSponge`, absorbing and squeezing in the same
+/// order and hence producing the same outputs.
+pub struct PoseidonSpongeGadget
+<
+    ConstraintF: PrimeField,
+    P:           PoseidonParameters<Fr = ConstraintF>,
+>
+{
+    state:       Vec<FpGadget<ConstraintF>>,
+    // elements absorbed but not yet added to the state
+    pending:     Vec<FpGadget<ConstraintF>>,
+    // position of the next element to squeeze from the rate, if squeezing
+    squeeze_idx: Option<usize>,
+    _parameters: PhantomData<P>,
+}
+
+impl<ConstraintF: PrimeField + MulShort, P: PoseidonParameters<Fr = ConstraintF>> PoseidonSpongeGadget<ConstraintF, P> {
+
+    pub fn new<CS: ConstraintSystem<ConstraintF>>(mut cs: CS) -> Self {
+        let state = (0..P::T)
+            .map(|i| FpGadget::<ConstraintF>::from_value(cs.ns(|| format!("hardcode_state_{}", i)), &P::AFTER_ZERO_PERM[i]))
+            .collect();
+        Self {
+            state,
+            pending:     Vec::with_capacity(P::R),
+            squeeze_idx: None,
+            _parameters: PhantomData,
+        }
+    }
+
+    // add the pending elements to the state and apply the permutation
+    fn update<CS: ConstraintSystem<ConstraintF>>(&mut self, mut cs: CS) -> Result<(), SynthesisError> {
+        for (j, x) in self.pending.drain(..).enumerate() {
+            self.state[j].add_in_place(cs.ns(|| format!("add_input_{}", j)), &x)?;
+        }
+        self.state[P::R].add_constant_in_place(cs.ns(|| "add_constant_C2"), &P::C2)?;
+        PoseidonHashGadget::<ConstraintF, P>::poseidon_perm(cs.ns(|| "poseidon_perm"), &mut self.state)
+    }
+
+    pub fn absorb<CS: ConstraintSystem<ConstraintF>>(
+        &mut self,
+        mut cs: CS,
+        elems: &[FpGadget<ConstraintF>],
+    ) -> Result<(), SynthesisError>
+    {
+        // absorbing nothing doesn't end the squeezing phase
+        if elems.is_empty() {
+            return Ok(());
+        }
+        self.squeeze_idx = None;
+        for (i, elem) in elems.iter().enumerate() {
+            self.pending.push(elem.clone());
+            if self.pending.len() == P::R {
+                self.update(cs.ns(|| format!("update_{}", i)))?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn squeeze<CS: ConstraintSystem<ConstraintF>>(
+        &mut self,
+        mut cs: CS,
+        n: usize,
+    ) -> Result<Vec<FpGadget<ConstraintF>>, SynthesisError>
+    {
+        if !self.pending.is_empty() {
+            self.update(cs.ns(|| "update"))?;
+        }
+        let mut idx = self.squeeze_idx.unwrap_or(0);
+        let mut output = Vec::with_capacity(n);
+        for i in 0..n {
+            // the rate has been entirely squeezed, permute to get fresh elements
+            if idx == P::R {
+                PoseidonHashGadget::<ConstraintF, P>::poseidon_perm(cs.ns(|| format!("poseidon_perm_{}", i)), &mut self.state)?;
+                idx = 0;
+            }
+            output.push(self.state[idx].clone());
+            idx += 1;
+        }
+        self.squeeze_idx = Some(idx);
+        Ok(output)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use rand::thread_rng;
@@ -0,0 +1,141 @@
+use algebra::{
+    fields::{
+        mnt4753::Fr as MNT4753Fr,
+        mnt6753::Fr as MNT6753Fr,
+    }, PrimeField, MulShort,
+};
+use primitives::crh::poseidon::{
+    PoseidonParameters, PoseidonTranscript,
+    parameters::{MNT4753PoseidonParameters, MNT6753PoseidonParameters},
+};
+use r1cs_core::{ConstraintSystem, SynthesisError};
+use r1cs_std::{
+    alloc::ConstantGadget,
+    fields::fp::FpGadget,
+    to_field_gadget_vec::ToConstraintFieldGadget,
+};
+use crate::crh::poseidon::PoseidonSpongeGadget;
+
+pub type MNT4PoseidonTranscriptGadget = PoseidonTranscriptGadget<MNT4753Fr, MNT4753PoseidonParameters>;
+pub type MNT6PoseidonTranscriptGadget = PoseidonTranscriptGadget<MNT6753Fr, MNT6753PoseidonParameters>;
+
+/// In-circuit counterpart of `PoseidonTranscript`: labels are absorbed as constants,
+/// in the same order as the native transcript, so that both produce the same challenges.
+pub struct PoseidonTranscriptGadget
+<
+    ConstraintF: PrimeField,
+    P:           PoseidonParameters<Fr = ConstraintF>,
+>
+{
+    sponge: PoseidonSpongeGadget<ConstraintF, P>,
+}
+
+impl<ConstraintF: PrimeField + MulShort, P: PoseidonParameters<Fr = ConstraintF>> PoseidonTranscriptGadget<ConstraintF, P> {
+
+    pub fn new<CS: ConstraintSystem<ConstraintF>>(mut cs: CS) -> Self {
+        Self { sponge: PoseidonSpongeGadget::new(cs.ns(|| "init sponge")) }
+    }
+
+    fn append_label<CS: ConstraintSystem<ConstraintF>>(
+        &mut self,
+        mut cs: CS,
+        label: &[u8],
+    ) -> Result<(), SynthesisError>
+    {
+        let label_g = PoseidonTranscript::<ConstraintF, P>::label_to_field_elements(label)
+            .iter()
+            .enumerate()
+            .map(|(i, fe)| FpGadget::<ConstraintF>::from_value(cs.ns(|| format!("hardcode_label_{}", i)), fe))
+            .collect::<Vec<_>>();
+        self.sponge.absorb(cs.ns(|| "absorb label"), &label_g)
+    }
+
+    pub fn append_field<CS: ConstraintSystem<ConstraintF>>(
+        &mut self,
+        mut cs: CS,
+        label: &[u8],
+        fe: &FpGadget<ConstraintF>,
+    ) -> Result<(), SynthesisError>
+    {
+        self.append_label(cs.ns(|| "append label"), label)?;
+        self.sponge.absorb(cs.ns(|| "absorb field element"), &[fe.clone()])
+    }
+
+    pub fn append_point<G, CS>(
+        &mut self,
+        mut cs: CS,
+        label: &[u8],
+        point: &G,
+    ) -> Result<(), SynthesisError>
+    where
+        G:  ToConstraintFieldGadget<ConstraintF, FieldGadget = FpGadget<ConstraintF>>,
+        CS: ConstraintSystem<ConstraintF>,
+    {
+        self.append_label(cs.ns(|| "append label"), label)?;
+        self.sponge.absorb(cs.ns(|| "absorb point"), &point.to_field_gadget_elements()?)
+    }
+
+    pub fn challenge<CS: ConstraintSystem<ConstraintF>>(
+        &mut self,
+        mut cs: CS,
+    ) -> Result<FpGadget<ConstraintF>, SynthesisError>
+    {
+        Ok(self.sponge.squeeze(cs.ns(|| "squeeze challenge"), 1)?.pop().unwrap())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algebra::{
+        curves::mnt6753::{G1Affine as MNT6G1Affine, G1Projective as MNT6G1Projective},
+        AffineCurve, ProjectiveCurve, UniformRand,
+    };
+    use primitives::crh::poseidon::MNT4PoseidonTranscript;
+    use r1cs_std::{
+        alloc::AllocGadget,
+        groups::curves::short_weierstrass::mnt::mnt6::mnt6753::MNT6G1Gadget,
+        test_constraint_system::TestConstraintSystem,
+    };
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    #[test]
+    fn poseidon_transcript_native_gadget_test() {
+        let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+        // A label packed into more than one field element
+        let long_label = [7u8; 150];
+
+        for num_fields in 0..5 {
+            let fes: Vec<MNT4753Fr> = (0..num_fields).map(|_| MNT4753Fr::rand(rng)).collect();
+            let p: MNT6G1Affine = MNT6G1Projective::rand(rng).into_affine();
+
+            let mut native = MNT4PoseidonTranscript::new();
+            for fe in fes.iter() {
+                native.append_field(b"field", fe);
+            }
+            native.append_point(&long_label, &p).unwrap();
+            let native_challenges = vec![native.challenge(), native.challenge()];
+
+            let mut cs = TestConstraintSystem::<MNT4753Fr>::new();
+            let mut gadget = MNT4PoseidonTranscriptGadget::new(cs.ns(|| "new transcript"));
+            for (i, fe) in fes.iter().enumerate() {
+                let fe_g = FpGadget::<MNT4753Fr>::alloc(cs.ns(|| format!("alloc fe {}", i)), || Ok(fe)).unwrap();
+                gadget.append_field(cs.ns(|| format!("append fe {}", i)), b"field", &fe_g).unwrap();
+            }
+            let p_g = MNT6G1Gadget::alloc(cs.ns(|| "alloc p"), || Ok(p.into_projective())).unwrap();
+            gadget.append_point(
+                cs.ns(|| "append p"),
+                &long_label,
+                &p_g
+            ).unwrap();
+            let gadget_challenges = vec![
+                gadget.challenge(cs.ns(|| "challenge 1")).unwrap().value.unwrap(),
+                gadget.challenge(cs.ns(|| "challenge 2")).unwrap().value.unwrap(),
+            ];
+
+            assert_eq!(native_challenges, gadget_challenges);
+            assert!(cs.is_satisfied());
+        }
+    }
+}
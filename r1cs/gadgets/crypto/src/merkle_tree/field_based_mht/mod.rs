@@ -53,39 +53,114 @@ impl<P, HGadget, ConstraintF> FieldBasedMerkleTreePathGadget<P, HGadget, Constra
 
         debug_assert!(self.path.len() == P::HEIGHT - 1);
 
-        let mut previous_hash = (*leaf).clone();
+        let previous_hash = self.compute_root(cs.ns(|| "compute root"), leaf)?;
 
-        for (i, &(ref sibling_hash, ref direction)) in self.path.iter().enumerate() {
+        root.conditional_enforce_equal(
+            &mut cs.ns(|| "root_is_last"),
+            &previous_hash,
+            should_enforce,
+        )
+    }
 
-            //Select left hash based on direction
-            let lhs = HGadget::DataGadget::conditionally_select(cs.ns(|| format!("Choose left hash {}", i)),
-                                                                  direction,
-                                                                  &sibling_hash,
-                                                                  &previous_hash
-            )?;
+    /// Recomputes the root of the (binary) Merkle Tree from `leaf` and this path.
+    pub fn compute_root<
+        CS: ConstraintSystem<ConstraintF>,
+    >(
+        &self,
+        cs: CS,
+        leaf: &HGadget::DataGadget,
+    ) -> Result<HGadget::DataGadget, SynthesisError> {
+        let (siblings, directions): (Vec<_>, Vec<_>) = self.path.iter().cloned().unzip();
+        Self::compute_root_from_path(cs, 2, leaf, siblings.as_slice(), directions.as_slice())
+    }
 
-            //Select right hash based on direction
-            let rhs = HGadget::DataGadget::conditionally_select(cs.ns(|| format!("Choose right hash {}", i)),
-                                                                  direction,
-                                                                  &previous_hash,
-                                                                  &sibling_hash
-            )?;
+    /// Recomputes the root of a Merkle Tree with `arity` children per node, starting from
+    /// `leaf` and going up to the root. For each level, `siblings` contains the `arity - 1`
+    /// siblings of the current node, in the order they appear among the children, while
+    /// `directions` contains the position of the current node among the children, as
+    /// `log2(arity)` little-endian bits. An empty path returns the leaf itself.
+    /// `arity` must be a power of two, and the lengths of `siblings` and `directions`
+    /// must be consistent with it, otherwise `SynthesisError::Unsatisfiable` is returned.
+    pub fn compute_root_from_path<
+        CS: ConstraintSystem<ConstraintF>,
+    >(
+        mut cs: CS,
+        arity: usize,
+        leaf: &HGadget::DataGadget,
+        siblings: &[HGadget::DataGadget],
+        directions: &[Boolean],
+    ) -> Result<HGadget::DataGadget, SynthesisError> {
+
+        if arity < 2 || !arity.is_power_of_two() {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+        let siblings_per_level = arity - 1;
+        let bits_per_level = arity.trailing_zeros() as usize;
+
+        if siblings.len() % siblings_per_level != 0 ||
+            directions.len() != (siblings.len() / siblings_per_level) * bits_per_level {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        let mut previous_hash = (*leaf).clone();
 
-            previous_hash = hash_inner_node_gadget::<P::H, HGadget, ConstraintF, _>(
-                &mut cs.ns(|| format!("hash_inner_node_{}", i)),
-                lhs,
-                rhs,
+        for (i, (level_siblings, position)) in siblings
+            .chunks(siblings_per_level)
+            .zip(directions.chunks(bits_per_level))
+            .enumerate()
+        {
+            let mut cs = cs.ns(|| format!("level_{}", i));
+
+            //is_at[j] is true iff the current node is the j-th child
+            let mut is_at = Vec::with_capacity(arity);
+            for j in 0..arity {
+                let selectors = position.iter().enumerate().map(|(k, bit)| {
+                    if (j >> k) & 1 == 1 { *bit } else { bit.not() }
+                }).collect::<Vec<_>>();
+                is_at.push(Boolean::kary_and(cs.ns(|| format!("is_child_{}", j)), &selectors)?);
+            }
+
+            //Place the current node in its position and the siblings in the remaining ones
+            let mut children = Vec::with_capacity(arity);
+            let mut is_before = Boolean::constant(false);
+            for j in 0..arity {
+                let sibling = if j == 0 {
+                    level_siblings[0].clone()
+                } else if j == arity - 1 {
+                    level_siblings[j - 1].clone()
+                } else {
+                    //If the current node comes before slot j, the siblings are shifted by one
+                    HGadget::DataGadget::conditionally_select(
+                        cs.ns(|| format!("Choose sibling {}", j)),
+                        &is_before,
+                        &level_siblings[j - 1],
+                        &level_siblings[j],
+                    )?
+                };
+
+                children.push(HGadget::DataGadget::conditionally_select(
+                    cs.ns(|| format!("Choose child {}", j)),
+                    &is_at[j],
+                    &previous_hash,
+                    &sibling,
+                )?);
+
+                if j < arity - 2 {
+                    is_before = Boolean::or(cs.ns(|| format!("is_before_{}", j + 1)), &is_before, &is_at[j])?;
+                }
+            }
+
+            previous_hash = HGadget::check_evaluation_gadget(
+                cs.ns(|| "hash_children"),
+                children.as_slice(),
             )?;
         }
 
-        root.conditional_enforce_equal(
-            &mut cs.ns(|| "root_is_last"),
-            &previous_hash,
-            should_enforce,
-        )
+        Ok(previous_hash)
     }
 }
 
+
 pub struct FieldBasedMerkleTreeGadget<P, HGadget, ConstraintF>
     where
         P: FieldBasedMerkleTreeConfig,
@@ -162,20 +237,6 @@ impl<P, HGadget, ConstraintF> FieldBasedMerkleTreeGadget<P, HGadget, ConstraintF
     }
 }
 
-pub(crate) fn hash_inner_node_gadget<H, HG, ConstraintF, CS>(
-    cs: CS,
-    left_child: HG::DataGadget,
-    right_child: HG::DataGadget,
-) -> Result<HG::DataGadget, SynthesisError>
-    where
-        ConstraintF: Field,
-        CS: ConstraintSystem<ConstraintF>,
-        H: FieldBasedHash<Data = ConstraintF>,
-        HG: FieldBasedHashGadget<H, ConstraintF>,
-{
-    HG::check_evaluation_gadget(cs, &[left_child, right_child])
-}
-
 impl<P, HGadget, ConstraintF> AllocGadget<FieldBasedMerkleTreePath<P>, ConstraintF>
 for FieldBasedMerkleTreePathGadget<P, HGadget, ConstraintF>
     where
@@ -234,7 +295,7 @@ for FieldBasedMerkleTreePathGadget<P, HGadget, ConstraintF>
 #[cfg(test)]
 mod test {
     use primitives::{
-        crh::MNT4PoseidonHash,
+        crh::{MNT4PoseidonHash, FieldBasedHash},
         merkle_tree::field_based_mht::*,
     };
     use crate::crh::MNT4PoseidonHashGadget;
@@ -416,4 +477,107 @@ mod test {
         assert!(!check_merkle_paths(&leaves, true));
         assert!(!check_leaves(&leaves, true));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn compute_root_test() {
+        let mut rng = XorShiftRng::seed_from_u64(9174123u64);
+
+        let mut leaves = Vec::new();
+        for _ in 0..32 {
+            let f: Fr = rng.gen();
+            leaves.push(f);
+        }
+        let tree = MNT4753FieldBasedMerkleTree::new(&leaves).unwrap();
+        let root = tree.root();
+        let proof = tree.generate_proof(3, &leaves[3]).unwrap();
+
+        //Valid path
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let leaf_g = FqGadget::alloc(cs.ns(|| "alloc leaf"), || Ok(leaves[3])).unwrap();
+        let path_g = FieldBasedMerkleTreePathGadget::<MNT4753FieldBasedMerkleTreeParams, HG, _>::alloc(
+            cs.ns(|| "alloc path"),
+            || Ok(proof.clone()),
+        ).unwrap();
+        let root_g = path_g.compute_root(cs.ns(|| "compute root"), &leaf_g).unwrap();
+        assert_eq!(root_g.get_value().unwrap(), root);
+        assert!(cs.is_satisfied());
+
+        //Tampered sibling
+        let mut bad_proof = proof.clone();
+        bad_proof.path[2].0 = rng.gen();
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let leaf_g = FqGadget::alloc(cs.ns(|| "alloc leaf"), || Ok(leaves[3])).unwrap();
+        let path_g = FieldBasedMerkleTreePathGadget::<MNT4753FieldBasedMerkleTreeParams, HG, _>::alloc(
+            cs.ns(|| "alloc path"),
+            || Ok(bad_proof),
+        ).unwrap();
+        let root_g = path_g.compute_root(cs.ns(|| "compute root"), &leaf_g).unwrap();
+        assert_ne!(root_g.get_value().unwrap(), root);
+
+        //Empty path: the root is the leaf itself
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let leaf_g = FqGadget::alloc(cs.ns(|| "alloc leaf"), || Ok(leaves[3])).unwrap();
+        let root_g = FieldBasedMerkleTreePathGadget::<MNT4753FieldBasedMerkleTreeParams, HG, _>::compute_root_from_path(
+            cs.ns(|| "compute root"), 2, &leaf_g, &[], &[],
+        ).unwrap();
+        assert_eq!(root_g.get_value().unwrap(), leaves[3]);
+
+        //Mismatched siblings and directions
+        let sibling_g = FqGadget::alloc(cs.ns(|| "alloc sibling"), || Ok(leaves[2])).unwrap();
+        assert!(FieldBasedMerkleTreePathGadget::<MNT4753FieldBasedMerkleTreeParams, HG, _>::compute_root_from_path(
+            cs.ns(|| "compute root with mismatched lengths"), 2, &leaf_g, &[sibling_g], &[],
+        ).is_err());
+    }
+
+    #[test]
+    fn compute_root_quaternary_test() {
+        let mut rng = XorShiftRng::seed_from_u64(9174123u64);
+
+        //Two levels of a 4-ary tree: the leaf is the third child at the first level and
+        //the second child at the second one.
+        let leaf: Fr = rng.gen();
+        let siblings = (0..6).map(|_| rng.gen()).collect::<Vec<Fr>>();
+        let node = MNT4PoseidonHash::evaluate(&[siblings[0], siblings[1], leaf, siblings[2]]).unwrap();
+        let root = MNT4PoseidonHash::evaluate(&[siblings[3], node, siblings[4], siblings[5]]).unwrap();
+        let directions = vec![false, true, true, false];
+
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let leaf_g = FqGadget::alloc(cs.ns(|| "alloc leaf"), || Ok(leaf)).unwrap();
+        let siblings_g = siblings.iter().enumerate().map(|(i, sibling)| {
+            FqGadget::alloc(cs.ns(|| format!("alloc sibling {}", i)), || Ok(sibling)).unwrap()
+        }).collect::<Vec<_>>();
+        let directions_g = Vec::<Boolean>::alloc(cs.ns(|| "alloc directions"), || Ok(directions)).unwrap();
+
+        let root_g = FieldBasedMerkleTreePathGadget::<MNT4753FieldBasedMerkleTreeParams, HG, _>::compute_root_from_path(
+            cs.ns(|| "compute root"), 4, &leaf_g, &siblings_g, &directions_g,
+        ).unwrap();
+        assert_eq!(root_g.get_value().unwrap(), root);
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn append_only_merkle_tree_paths_test() {
+        let mut rng = XorShiftRng::seed_from_u64(9174123u64);
+
+        let mut tree = FieldBasedAppendOnlyMerkleTree::<MNT4753FieldBasedMerkleTreeParams>::new().unwrap();
+        for _ in 0..5 {
+            tree.append(rng.gen()).unwrap();
+        }
+        let root = tree.root();
+
+        for (i, leaf) in tree.leaves().iter().enumerate() {
+            let mut cs = TestConstraintSystem::<Fr>::new();
+            let proof = tree.generate_proof(i).unwrap();
+
+            let root_g = FqGadget::alloc(cs.ns(|| "alloc root"), || Ok(root)).unwrap();
+            let leaf_g = FqGadget::alloc(cs.ns(|| "alloc leaf"), || Ok(leaf)).unwrap();
+            let path_g = FieldBasedMerkleTreePathGadget::<_, HG, _>::alloc(
+                cs.ns(|| "alloc path"),
+                || Ok(proof),
+            ).unwrap();
+
+            path_g.check_membership(cs.ns(|| "check membership"), &root_g, &leaf_g).unwrap();
+            assert!(cs.is_satisfied());
+        }
+    }
+}
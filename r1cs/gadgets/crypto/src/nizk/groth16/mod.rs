@@ -1,4 +1,4 @@
-use algebra::{AffineCurve, Field, PairingEngine, ToConstraintField};
+use algebra::{AffineCurve, Field, PairingCurve, PairingEngine, ProjectiveCurve, ToConstraintField};
 use proof_systems::groth16::{
     Parameters, PreparedVerifyingKey, Proof, VerifyingKey,
 };
@@ -129,7 +129,7 @@ for Groth16VerifierGadget<PairingE, ConstraintF, P>
     fn check_verify<'a, CS, I, T>(
         mut cs: CS,
         vk: &Self::VerificationKeyGadget,
-        mut public_inputs: I,
+        public_inputs: I,
         proof: &Self::ProofGadget,
     ) -> Result<(), SynthesisError>
         where
@@ -138,7 +138,30 @@ for Groth16VerifierGadget<PairingE, ConstraintF, P>
             T: 'a + ToBitsGadget<ConstraintF> + ?Sized,
     {
         let pvk = vk.prepare(&mut cs.ns(|| "Prepare vk"))?;
+        Self::check_verify_prepared(cs, &pvk, public_inputs, proof)
+    }
+}
 
+impl<PairingE, ConstraintF, P> Groth16VerifierGadget<PairingE, ConstraintF, P>
+    where
+        PairingE: PairingEngine,
+        ConstraintF: Field,
+        P: PairingGadget<PairingE, ConstraintF>,
+{
+    /// Same as `check_verify`, but with an already prepared verifying key, e.g. one
+    /// hardcoded via `PreparedVerifyingKeyGadget::from_value`, whose preparation
+    /// doesn't cost any constraint.
+    pub fn check_verify_prepared<'a, CS, I, T>(
+        mut cs: CS,
+        pvk: &PreparedVerifyingKeyGadget<PairingE, ConstraintF, P>,
+        mut public_inputs: I,
+        proof: &ProofGadget<PairingE, ConstraintF, P>,
+    ) -> Result<(), SynthesisError>
+        where
+            CS: ConstraintSystem<ConstraintF>,
+            I: Iterator<Item = &'a T>,
+            T: 'a + ToBitsGadget<ConstraintF> + ?Sized,
+    {
         let g_ic = {
             let mut cs = cs.ns(|| "Process input");
             let mut g_ic = pvk.gamma_abc_g1[0].clone();
@@ -278,6 +301,113 @@ for VerifyingKeyGadget<PairingE, ConstraintF, P>
     }
 }
 
+impl<PairingE, ConstraintF, P> ConstantGadget<VerifyingKey<PairingE>, ConstraintF>
+for VerifyingKeyGadget<PairingE, ConstraintF, P>
+    where
+        PairingE: PairingEngine,
+        ConstraintF: Field,
+        P: PairingGadget<PairingE, ConstraintF>,
+{
+    /// Hardcode the verifying key into the circuit, so that it doesn't need
+    /// to be allocated (and checked) as a witness or as a public input.
+    /// Note that `check_verify` still prepares it in-circuit: hardcode a
+    /// `PreparedVerifyingKeyGadget` instead to skip the preparation as well.
+    #[inline]
+    fn from_value<CS: ConstraintSystem<ConstraintF>>(
+        mut cs: CS,
+        value: &VerifyingKey<PairingE>,
+    ) -> Self
+    {
+        let alpha_g1_beta_g2 =
+            P::GTGadget::from_value(cs.ns(|| "hardcode alpha_g1_beta_g2"), &value.alpha_g1_beta_g2);
+        let gamma_g2 =
+            P::G2Gadget::from_value(cs.ns(|| "hardcode gamma_g2"), &value.gamma_g2.into_projective());
+        let delta_g2 =
+            P::G2Gadget::from_value(cs.ns(|| "hardcode delta_g2"), &value.delta_g2.into_projective());
+
+        let gamma_abc_g1 = value.gamma_abc_g1
+            .iter()
+            .enumerate()
+            .map(|(i, gamma_abc_i)| {
+                P::G1Gadget::from_value(cs.ns(|| format!("hardcode gamma_abc_{}", i)), &gamma_abc_i.into_projective())
+            })
+            .collect();
+
+        Self {
+            alpha_g1_beta_g2,
+            gamma_g2,
+            delta_g2,
+            gamma_abc_g1,
+        }
+    }
+
+    #[inline]
+    fn get_constant(&self) -> VerifyingKey<PairingE> {
+        VerifyingKey {
+            alpha_g1_beta_g2: self.alpha_g1_beta_g2.get_constant(),
+            gamma_g2: self.gamma_g2.get_constant().into_affine(),
+            delta_g2: self.delta_g2.get_constant().into_affine(),
+            gamma_abc_g1: self.gamma_abc_g1
+                .iter()
+                .map(|gamma_abc_i| gamma_abc_i.get_constant().into_affine())
+                .collect(),
+        }
+    }
+}
+
+impl<PairingE, ConstraintF, P> ConstantGadget<PreparedVerifyingKey<PairingE>, ConstraintF>
+for PreparedVerifyingKeyGadget<PairingE, ConstraintF, P>
+    where
+        PairingE: PairingEngine,
+        ConstraintF: Field,
+        P: PairingGadget<PairingE, ConstraintF>,
+        P::G2PreparedGadget: ConstantGadget<<PairingE::G2Affine as PairingCurve>::Prepared, ConstraintF>,
+{
+    /// Hardcode the natively prepared verifying key into the circuit, so that
+    /// neither its allocation nor its preparation cost any constraint.
+    #[inline]
+    fn from_value<CS: ConstraintSystem<ConstraintF>>(
+        mut cs: CS,
+        value: &PreparedVerifyingKey<PairingE>,
+    ) -> Self
+    {
+        let alpha_g1_beta_g2 =
+            P::GTGadget::from_value(cs.ns(|| "hardcode alpha_g1_beta_g2"), &value.alpha_g1_beta_g2);
+        let gamma_g2_neg_pc =
+            P::G2PreparedGadget::from_value(cs.ns(|| "hardcode gamma_g2_neg_pc"), &value.gamma_g2_neg_pc);
+        let delta_g2_neg_pc =
+            P::G2PreparedGadget::from_value(cs.ns(|| "hardcode delta_g2_neg_pc"), &value.delta_g2_neg_pc);
+
+        let gamma_abc_g1 = value.gamma_abc_g1
+            .iter()
+            .enumerate()
+            .map(|(i, gamma_abc_i)| {
+                P::G1Gadget::from_value(cs.ns(|| format!("hardcode gamma_abc_{}", i)), &gamma_abc_i.into_projective())
+            })
+            .collect();
+
+        Self {
+            alpha_g1_beta_g2,
+            gamma_g2_neg_pc,
+            delta_g2_neg_pc,
+            gamma_abc_g1,
+        }
+    }
+
+    #[inline]
+    fn get_constant(&self) -> PreparedVerifyingKey<PairingE> {
+        PreparedVerifyingKey {
+            alpha_g1_beta_g2: self.alpha_g1_beta_g2.get_constant(),
+            gamma_g2_neg_pc: self.gamma_g2_neg_pc.get_constant(),
+            delta_g2_neg_pc: self.delta_g2_neg_pc.get_constant(),
+            gamma_abc_g1: self.gamma_abc_g1
+                .iter()
+                .map(|gamma_abc_i| gamma_abc_i.get_constant().into_affine())
+                .collect(),
+        }
+    }
+}
+
 impl<PairingE, ConstraintF, P> AllocGadget<Proof<PairingE>, ConstraintF>
 for ProofGadget<PairingE, ConstraintF, P>
     where
@@ -373,7 +503,7 @@ mod test {
 
     use super::*;
     use algebra::{
-        BitIterator, PrimeField,
+        to_bytes, BitIterator, PrimeField, ToBytes, UniformRand,
     };
     use r1cs_std::{
         boolean::Boolean, test_constraint_system::TestConstraintSystem
@@ -687,4 +817,120 @@ mod test {
             assert!(cs.is_satisfied());
         }
     }
+
+    // Proves a circuit on `E` and verifies the proof inside a circuit defined over
+    // the base field of `E`, with the verifying key hardcoded into the circuit.
+    fn groth16_verifier_hardcoded_vk_test<E, ConstraintF, P>()
+        where
+            E: PairingEngine,
+            ConstraintF: Field,
+            P: PairingGadget<E, ConstraintF>,
+            P::G2PreparedGadget: ConstantGadget<<E::G2Affine as PairingCurve>::Prepared, ConstraintF>,
+    {
+        type TestProofSystem<E> = Groth16<E, Bench<<E as PairingEngine>::Fr>, <E as PairingEngine>::Fr>;
+
+        let num_inputs = 2;
+        let num_constraints = num_inputs;
+        let rng = &mut thread_rng();
+        let inputs: Vec<E::Fr> = (0..num_inputs).map(|_| E::Fr::rand(rng)).collect();
+        let params = {
+            let c = Bench::<E::Fr> {
+                inputs: vec![None; num_inputs],
+                num_constraints,
+            };
+
+            generate_random_parameters::<E, _, _>(c, rng).unwrap()
+        };
+
+        let proof = {
+            let c = Bench {
+                inputs: inputs.iter().map(|&input| Some(input)).collect(),
+                num_constraints,
+            };
+            create_random_proof(c, &params, rng).unwrap()
+        };
+
+        let mut cs = TestConstraintSystem::<ConstraintF>::new();
+
+        let mut input_gadgets = Vec::new();
+        {
+            let mut cs = cs.ns(|| "Allocate Input");
+            for (i, input) in inputs.into_iter().enumerate() {
+                let mut input_bits = BitIterator::new(input.into_repr()).collect::<Vec<_>>();
+                // Input must be in little-endian, but BitIterator outputs in big-endian.
+                input_bits.reverse();
+
+                let input_bits =
+                    Vec::<Boolean>::alloc_input(cs.ns(|| format!("Input {}", i)), || {
+                        Ok(input_bits)
+                    })
+                        .unwrap();
+                input_gadgets.push(input_bits);
+            }
+        }
+
+        // Hardcoding the vk, prepared or not, must not cost any constraint
+        let num_constraints_before = cs.num_constraints();
+        let vk_gadget = VerifyingKeyGadget::<E, ConstraintF, P>::from_value(cs.ns(|| "Hardcode vk"), &params.vk);
+        let pvk = prepare_verifying_key(&params.vk);
+        let pvk_gadget = PreparedVerifyingKeyGadget::<E, ConstraintF, P>::from_value(cs.ns(|| "Hardcode pvk"), &pvk);
+        assert_eq!(cs.num_constraints(), num_constraints_before);
+        assert_eq!(vk_gadget.get_constant(), params.vk);
+        assert_eq!(to_bytes!(pvk_gadget.get_constant()).unwrap(), to_bytes!(pvk).unwrap());
+
+        let proof_gadget =
+            ProofGadget::<E, ConstraintF, P>::alloc(cs.ns(|| "Proof"), || Ok(proof.clone())).unwrap();
+
+        let num_constraints_before = cs.num_constraints();
+        <Groth16VerifierGadget<E, ConstraintF, P> as NIZKVerifierGadget<TestProofSystem<E>, ConstraintF>>::check_verify(
+            cs.ns(|| "Verify"),
+            &vk_gadget,
+            input_gadgets.iter(),
+            &proof_gadget,
+        )
+            .unwrap();
+        let verify_cost = cs.num_constraints() - num_constraints_before;
+
+        let num_constraints_before = cs.num_constraints();
+        Groth16VerifierGadget::<E, ConstraintF, P>::check_verify_prepared(
+            cs.ns(|| "Verify prepared"),
+            &pvk_gadget,
+            input_gadgets.iter(),
+            &proof_gadget,
+        )
+            .unwrap();
+        let verify_prepared_cost = cs.num_constraints() - num_constraints_before;
+
+        // The hardcoded prepared vk saves exactly the in-circuit preparation
+        let num_constraints_before = cs.num_constraints();
+        vk_gadget.prepare(cs.ns(|| "Prepare vk")).unwrap();
+        let prepare_cost = cs.num_constraints() - num_constraints_before;
+        assert!(prepare_cost > 0);
+        assert_eq!(verify_cost, verify_prepared_cost + prepare_cost);
+
+        if !cs.is_satisfied() {
+            println!("=========================================================");
+            println!("Unsatisfied constraints:");
+            println!("{:?}", cs.which_is_unsatisfied().unwrap());
+            println!("=========================================================");
+        }
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn mnt4753_groth16_verifier_hardcoded_vk_test() {
+        use algebra::{curves::mnt4753::MNT4, fields::mnt4753::Fq};
+        use r1cs_std::pairing::mnt4753::MNT4753PairingGadget;
+
+        groth16_verifier_hardcoded_vk_test::<MNT4, Fq, MNT4753PairingGadget>();
+    }
+
+    #[ignore]
+    #[test]
+    fn mnt6753_groth16_verifier_hardcoded_vk_test() {
+        use algebra::{curves::mnt6753::MNT6, fields::mnt6753::Fq};
+        use r1cs_std::pairing::mnt6753::MNT6753PairingGadget;
+
+        groth16_verifier_hardcoded_vk_test::<MNT6, Fq, MNT6753PairingGadget>();
+    }
 }
\ No newline at end of file
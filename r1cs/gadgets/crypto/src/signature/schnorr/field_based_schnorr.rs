@@ -249,6 +249,7 @@ mod test {
         mnt4753::Fr as MNT4Fr,
         mnt6753::Fr as MNT6Fr,
     };
+    use algebra::Field;
 
     use primitives::{
         signature::{
@@ -292,7 +293,7 @@ mod test {
     {
         let (pk, sk) = S::keygen(rng);
         assert!(S::keyverify(&pk));
-        let sig = S::sign(rng, &pk, &sk, &message).unwrap();
+        let sig = S::sign(&pk, &sk, &message).unwrap();
         (sig, pk)
     }
 
@@ -359,6 +360,11 @@ mod test {
         //Change sig
         let (wrong_sig, _) = sign::<SchnorrMNT4, _>(rng, &[wrong_message]);
         assert!(!mnt4_schnorr_gadget_generate_constraints(message, pk, wrong_sig));
+
+        //Change s
+        let mut wrong_sig = sig;
+        wrong_sig.s += &MNT4Fr::one();
+        assert!(!mnt4_schnorr_gadget_generate_constraints(message, pk, wrong_sig));
     }
 
     fn mnt6_schnorr_gadget_generate_constraints(message: MNT6Fr, pk: MNT4G1Projective, sig: SchnorrMNT6Sig) -> bool {
@@ -424,6 +430,11 @@ mod test {
         //Change sig
         let (wrong_sig, _) = sign::<SchnorrMNT6, _>(rng, &[wrong_message]);
         assert!(!mnt6_schnorr_gadget_generate_constraints(message, pk, wrong_sig));
+
+        //Change s
+        let mut wrong_sig = sig;
+        wrong_sig.s += &MNT6Fr::one();
+        assert!(!mnt6_schnorr_gadget_generate_constraints(message, pk, wrong_sig));
     }
 
     #[ignore]
@@ -340,6 +340,7 @@ mod test {
 
     use r1cs_core::ConstraintSystem;
     use r1cs_std::alloc::AllocGadget;
+    use r1cs_std::fields::FieldGadget;
     use r1cs_std::groups::curves::short_weierstrass::mnt::{
         mnt4::mnt4753::MNT4G1Gadget,
         mnt6::mnt6753::MNT6G1Gadget,
@@ -417,7 +418,7 @@ mod test {
         ).unwrap();
 
         //Verify proof
-        EcVrfMNT4Gadget::enforce_proof_to_hash_verification(
+        let output_g = EcVrfMNT4Gadget::enforce_proof_to_hash_verification(
             cs.ns(|| "verify proof1"),
             &pp_g,
             &pk_g,
@@ -428,9 +429,14 @@ mod test {
         if !cs.is_satisfied() {
             println!("**********Unsatisfied constraints***********");
             println!("{:?}", cs.which_is_unsatisfied());
+            return false;
         }
 
-        cs.is_satisfied()
+        //The VRF output computed by the gadget must match the native one
+        let output = EcVrfMNT4::proof_to_hash(pp, &pk, &[message], &proof).unwrap();
+        assert_eq!(output, output_g.get_value().unwrap());
+
+        true
     }
 
     #[test]
@@ -473,7 +479,7 @@ mod test {
         ).unwrap();
 
         //Verify proof
-        EcVrfMNT6Gadget::enforce_proof_to_hash_verification(
+        let output_g = EcVrfMNT6Gadget::enforce_proof_to_hash_verification(
             cs.ns(|| "verify proof1"),
             &pp_g,
             &pk_g,
@@ -484,9 +490,14 @@ mod test {
         if !cs.is_satisfied() {
             println!("**********Unsatisfied constraints***********");
             println!("{:?}", cs.which_is_unsatisfied());
+            return false;
         }
 
-        cs.is_satisfied()
+        //The VRF output computed by the gadget must match the native one
+        let output = EcVrfMNT6::proof_to_hash(pp, &pk, &[message], &proof).unwrap();
+        assert_eq!(output, output_g.get_value().unwrap());
+
+        true
     }
 
     #[ignore]
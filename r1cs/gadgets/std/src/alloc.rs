@@ -1,6 +1,6 @@
 use algebra::Field;
 use r1cs_core::{ConstraintSystem, SynthesisError};
-use std::borrow::Borrow;
+use std::{borrow::Borrow, convert::TryInto};
 
 pub trait AllocGadget<V, ConstraintF: Field>
 where
@@ -123,6 +123,108 @@ impl<I, ConstraintF: Field, A: AllocGadget<I, ConstraintF>> AllocGadget<[I], Con
     }
 }
 
+fn vec_to_array<A, const N: usize>(vec: Vec<A>) -> [A; N] {
+    match vec.try_into() {
+        Ok(array) => array,
+        Err(_) => unreachable!(),
+    }
+}
+
+macro_rules! array_alloc_method {
+    ($method:ident) => {
+        fn $method<F, T, CS: ConstraintSystem<ConstraintF>>(
+            mut cs: CS,
+            f: F,
+        ) -> Result<Self, SynthesisError>
+        where
+            F: FnOnce() -> Result<T, SynthesisError>,
+            T: Borrow<[I; N]>,
+        {
+            let values = f();
+            let mut vec = Vec::with_capacity(N);
+            for i in 0..N {
+                vec.push(A::$method(cs.ns(|| format!("value_{}", i)), || {
+                    values
+                        .as_ref()
+                        .map(|values| &values.borrow()[i])
+                        .map_err(|_| SynthesisError::AssignmentMissing)
+                })?);
+            }
+            Ok(vec_to_array(vec))
+        }
+    };
+}
+
+/// Allocates the elements of the array one by one. Unlike for `Vec`, the length is
+/// known in advance, hence a missing assignment is passed on to each element as
+/// `SynthesisError::AssignmentMissing`.
+impl<I, ConstraintF: Field, A: AllocGadget<I, ConstraintF>, const N: usize>
+    AllocGadget<[I; N], ConstraintF> for [A; N]
+{
+    array_alloc_method!(alloc);
+    array_alloc_method!(alloc_without_check);
+    array_alloc_method!(alloc_checked);
+    array_alloc_method!(alloc_input);
+    array_alloc_method!(alloc_input_checked);
+}
+
+macro_rules! tuple_alloc_method {
+    ($method:ident; $($a:ident: $i:ident: $idx:tt),+) => {
+        fn $method<F, T, CS: ConstraintSystem<ConstraintF>>(
+            mut cs: CS,
+            f: F,
+        ) -> Result<Self, SynthesisError>
+        where
+            F: FnOnce() -> Result<T, SynthesisError>,
+            T: Borrow<($($i,)+)>,
+        {
+            let values = f();
+            Ok(($(
+                $a::$method(cs.ns(|| format!("value_{}", $idx)), || {
+                    values
+                        .as_ref()
+                        .map(|values| &values.borrow().$idx)
+                        .map_err(|_| SynthesisError::AssignmentMissing)
+                })?,
+            )+))
+        }
+    };
+}
+
+macro_rules! impl_alloc_gadget_for_tuple {
+    ($($a:ident: $i:ident: $idx:tt),+) => {
+        /// Allocates the components of the tuple one by one, as for arrays.
+        impl<ConstraintF: Field, $($i, $a: AllocGadget<$i, ConstraintF>),+>
+            AllocGadget<($($i,)+), ConstraintF> for ($($a,)+)
+        {
+            tuple_alloc_method!(alloc; $($a: $i: $idx),+);
+            tuple_alloc_method!(alloc_without_check; $($a: $i: $idx),+);
+            tuple_alloc_method!(alloc_checked; $($a: $i: $idx),+);
+            tuple_alloc_method!(alloc_input; $($a: $i: $idx),+);
+            tuple_alloc_method!(alloc_input_checked; $($a: $i: $idx),+);
+        }
+
+        impl<ConstraintF: Field, $($i, $a: ConstantGadget<$i, ConstraintF>),+>
+            ConstantGadget<($($i,)+), ConstraintF> for ($($a,)+)
+        {
+            fn from_value<CS: ConstraintSystem<ConstraintF>>(
+                mut cs: CS,
+                value: &($($i,)+),
+            ) -> Self {
+                ($($a::from_value(cs.ns(|| format!("value_{}", $idx)), &value.$idx),)+)
+            }
+
+            fn get_constant(&self) -> ($($i,)+) {
+                ($(self.$idx.get_constant(),)+)
+            }
+        }
+    };
+}
+
+impl_alloc_gadget_for_tuple!(A0: I0: 0, A1: I1: 1);
+impl_alloc_gadget_for_tuple!(A0: I0: 0, A1: I1: 1, A2: I2: 2);
+impl_alloc_gadget_for_tuple!(A0: I0: 0, A1: I1: 1, A2: I2: 2, A3: I3: 3);
+
 /// Get a Gadget from the corresponding constant. At low level, the constant
 /// will be the coefficient of the CS::one() variable.
 pub trait ConstantGadget<V, ConstraintF: Field>
@@ -136,4 +238,63 @@ pub trait ConstantGadget<V, ConstraintF: Field>
     ) -> Self;
 
     fn get_constant(&self) -> V;
-}
\ No newline at end of file
+}
+
+impl<V, ConstraintF: Field, A: ConstantGadget<V, ConstraintF>, const N: usize>
+    ConstantGadget<[V; N], ConstraintF> for [A; N]
+{
+    fn from_value<CS: ConstraintSystem<ConstraintF>>(mut cs: CS, value: &[V; N]) -> Self {
+        std::array::from_fn(|i| A::from_value(cs.ns(|| format!("value_{}", i)), &value[i]))
+    }
+
+    fn get_constant(&self) -> [V; N] {
+        std::array::from_fn(|i| self[i].get_constant())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{fields::fp::FpGadget, prelude::*, test_constraint_system::TestConstraintSystem};
+    use algebra::{fields::mnt4753::Fq, UniformRand};
+    use r1cs_core::{ConstraintSystem, SynthesisError};
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    #[test]
+    fn alloc_array_and_tuple_test() {
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+        let mut cs = TestConstraintSystem::<Fq>::new();
+
+        let array_native = [Fq::rand(&mut rng), Fq::rand(&mut rng), Fq::rand(&mut rng), Fq::rand(&mut rng)];
+        let array = <[FpGadget<Fq>; 4]>::alloc(cs.ns(|| "alloc array"), || Ok(array_native)).unwrap();
+        let array_input = <[FpGadget<Fq>; 4]>::alloc_input(cs.ns(|| "alloc input array"), || Ok(&array_native)).unwrap();
+        for i in 0..4 {
+            assert_eq!(array[i].get_value().unwrap(), array_native[i]);
+            assert_eq!(array_input[i].get_value().unwrap(), array_native[i]);
+        }
+        let array_constant = <[FpGadget<Fq>; 4]>::from_value(cs.ns(|| "array constant"), &array_native);
+        assert_eq!(array_constant.get_constant(), array_native);
+
+        let tuple_native = (Fq::rand(&mut rng), Fq::rand(&mut rng));
+        let tuple = <(FpGadget<Fq>, FpGadget<Fq>)>::alloc(cs.ns(|| "alloc tuple"), || Ok(tuple_native)).unwrap();
+        assert_eq!(tuple.0.get_value().unwrap(), tuple_native.0);
+        assert_eq!(tuple.1.get_value().unwrap(), tuple_native.1);
+
+        let mixed = <(FpGadget<Fq>, Boolean, [Boolean; 2])>::alloc_input(
+            cs.ns(|| "alloc mixed tuple"),
+            || Ok((tuple_native.0, true, [false, true])),
+        ).unwrap();
+        assert_eq!(mixed.0.get_value().unwrap(), tuple_native.0);
+        assert_eq!(mixed.1.get_value(), Some(true));
+        assert_eq!(mixed.2[0].get_value(), Some(false));
+        assert_eq!(mixed.2[1].get_value(), Some(true));
+        assert!(cs.is_satisfied());
+
+        // A missing assignment is reported by the components
+        let missing = <[FpGadget<Fq>; 2]>::alloc(
+            cs.ns(|| "alloc missing"),
+            || Err::<[Fq; 2], _>(SynthesisError::AssignmentMissing),
+        );
+        assert!(missing.is_err());
+    }
+}
@@ -452,7 +452,7 @@ impl Boolean {
             // packs field elements up to the penultimate bit.
             // That is, the most significant bit (`ConstraintF::NUM_BITS`-th bit) is
             // unset, so we can just pop it off.
-            allocated_bits.extend_from_slice(&fe_bits[0..max_size]);
+            allocated_bits.extend_from_slice(&fe_bits[1..=max_size]);
         }
 
         Ok(allocated_bits[0..values_len].to_vec())
@@ -689,6 +689,38 @@ impl Boolean {
 
         Ok(())
     }
+
+    /// Enforces that `bits` is the *little-endian* binary decomposition of `value`,
+    /// i.e. that `sum_i bits[i] * 2^i == value`, by a single linear constraint.
+    /// At most `MODULUS_BITS` bits are accepted, otherwise `SynthesisError::Unsatisfiable`
+    /// is returned. Up to `CAPACITY` bits, the sum can't wrap around the modulus, hence
+    /// the decomposition is unique. With `MODULUS_BITS` bits, also the decomposition of
+    /// `value + modulus` may fit: use `enforce_in_field` to exclude it.
+    pub fn enforce_is_decomposition_of<ConstraintF, CS>(
+        mut cs: CS,
+        bits: &[Self],
+        value: &FpGadget<ConstraintF>,
+    ) -> Result<(), SynthesisError>
+        where
+            ConstraintF: PrimeField,
+            CS: ConstraintSystem<ConstraintF>,
+    {
+        if bits.len() > ConstraintF::Params::MODULUS_BITS as usize {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        let mut lc = LinearCombination::zero();
+        let mut coeff = ConstraintF::one();
+        for bit in bits.iter() {
+            lc = lc + &bit.lc(CS::one(), coeff);
+            coeff.double_in_place();
+        }
+        lc = &value.variable - lc;
+
+        cs.enforce(|| "packing constraint", |lc| lc, |lc| lc, |_| lc);
+
+        Ok(())
+    }
 }
 
 impl PartialEq for Boolean {
@@ -1956,6 +1988,63 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_enforce_is_decomposition_of() {
+        use crate::fields::fp::FpGadget;
+
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        for _ in 0..10 {
+            let r = Fr::rand(&mut rng);
+            let mut cs = TestConstraintSystem::<Fr>::new();
+
+            let value = FpGadget::<Fr>::alloc(cs.ns(|| "value"), || Ok(r)).unwrap();
+            let mut bits = vec![];
+            // Little-endian bits of `r`, without the unused most significant bit of the repr
+            let mut bit_values = BitIterator::new(r.into_repr()).skip(1).collect::<Vec<_>>();
+            bit_values.reverse();
+            for (i, b) in bit_values.into_iter().enumerate() {
+                bits.push(Boolean::from(
+                    AllocatedBit::alloc(cs.ns(|| format!("bit_gadget {}", i)), || Ok(b)).unwrap(),
+                ));
+            }
+
+            Boolean::enforce_is_decomposition_of(cs.ns(|| "decomposition"), &bits, &value).unwrap();
+            assert!(cs.is_satisfied());
+
+            // Any other bit vector doesn't decompose `value`
+            let bit = cs.get("bit_gadget 7/boolean");
+            cs.set("bit_gadget 7/boolean", Fr::one() - &bit);
+            assert!(!cs.is_satisfied());
+            assert_eq!(cs.which_is_unsatisfied().unwrap(), "decomposition/packing constraint");
+        }
+
+        // Constant and negated bits are supported as well: 0b1101 = 13
+        {
+            let mut cs = TestConstraintSystem::<Fr>::new();
+            let value = FpGadget::<Fr>::alloc(cs.ns(|| "value"), || Ok(Fr::from(13u64))).unwrap();
+            let bit = AllocatedBit::alloc(cs.ns(|| "bit"), || Ok(false)).unwrap();
+            let bits = [
+                Boolean::constant(true),
+                Boolean::from(bit),
+                Boolean::constant(true),
+                Boolean::from(bit).not(),
+            ];
+            Boolean::enforce_is_decomposition_of(cs.ns(|| "decomposition"), &bits, &value).unwrap();
+            assert!(cs.is_satisfied());
+            cs.set("bit/boolean", Fr::one());
+            assert!(!cs.is_satisfied());
+        }
+
+        // More than MODULUS_BITS bits are rejected
+        {
+            let mut cs = TestConstraintSystem::<Fr>::new();
+            let value = FpGadget::<Fr>::alloc(cs.ns(|| "value"), || Ok(Fr::zero())).unwrap();
+            let bits = vec![Boolean::constant(false); 256];
+            assert!(Boolean::enforce_is_decomposition_of(cs.ns(|| "decomposition"), &bits, &value).is_err());
+        }
+    }
+
     #[test]
     fn test_enforce_in_field() {
         {
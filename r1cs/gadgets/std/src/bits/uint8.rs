@@ -68,10 +68,15 @@ impl UInt8 {
         Ok(output_vec)
     }
 
-    /// Allocates a vector of `u8`'s by first converting (chunks of) them to
-    /// `ConstraintF` elements, (thus reducing the number of input allocations),
-    /// and then converts this list of `ConstraintF` gadgets back into
-    /// bytes.
+    /// Allocates a vector of `u8`'s as public input, packing them into the minimum
+    /// number of `ConstraintF` elements (thus reducing the number of input allocations):
+    /// each field element holds `ConstraintF::Params::CAPACITY / 8` bytes in little-endian
+    /// form. The bytes themselves are allocated as witnesses, and a "packing constraint"
+    /// enforces each input field element to be equal to the weighted sum of the bits of
+    /// its chunk of bytes. Since a chunk fits into `CAPACITY` bits, such a sum can't wrap
+    /// around the modulus, i.e. the decomposition is unique.
+    /// The verifier computes the same public inputs from the native bytes via
+    /// `ToConstraintField::<ConstraintF>::to_field_elements(values)`.
     pub fn alloc_input_vec<ConstraintF, CS>(
         mut cs: CS,
         values: &[u8],
@@ -80,35 +85,29 @@ impl UInt8 {
         ConstraintF: PrimeField,
         CS: ConstraintSystem<ConstraintF>,
     {
-        let values_len = values.len();
         let field_elements: Vec<ConstraintF> =
             ToConstraintField::<ConstraintF>::to_field_elements(values).unwrap();
 
-        let max_size = 8 * (ConstraintF::Params::CAPACITY / 8) as usize;
-        let mut allocated_bits = Vec::new();
-        for (i, field_element) in field_elements.into_iter().enumerate() {
+        let max_size = (ConstraintF::Params::CAPACITY / 8) as usize;
+        let mut allocated_bytes = Vec::with_capacity(values.len());
+        for (i, (chunk, field_element)) in values.chunks(max_size).zip(field_elements).enumerate() {
             let fe = FpGadget::alloc_input(&mut cs.ns(|| format!("Field element {}", i)), || {
                 Ok(field_element)
             })?;
-            let mut fe_bits = fe.to_bits(cs.ns(|| format!("Convert fe to bits {}", i)))?;
-            // FpGadget::to_bits outputs a big-endian binary representation of
-            // fe_gadget's value, so we have to reverse it to get the little-endian
-            // form.
-            fe_bits.reverse();
-
-            // Remove the most significant bit, because we know it should be zero
-            // because `values.to_field_elements()` only
-            // packs field elements up to the penultimate bit.
-            // That is, the most significant bit (`ConstraintF::NUM_BITS`-th bit) is
-            // unset, so we can just pop it off.
-            allocated_bits.extend_from_slice(&fe_bits[0..max_size]);
+            let bytes = Self::alloc_vec(cs.ns(|| format!("Bytes {}", i)), chunk)?;
+
+            // Bytes, and bits within them, are in little-endian form.
+            let bits = bytes.iter().flat_map(|byte| byte.bits.iter().cloned()).collect::<Vec<_>>();
+            Boolean::enforce_is_decomposition_of(
+                cs.ns(|| format!("Unpack field element {}", i)),
+                &bits,
+                &fe,
+            )?;
+
+            allocated_bytes.extend(bytes);
         }
 
-        // Chunk up slices of 8 bit into bytes.
-        Ok(allocated_bits[0..8 * values_len]
-            .chunks(8)
-            .map(Self::from_bits_le)
-            .collect())
+        Ok(allocated_bytes)
     }
 
     /// Turns this `UInt8` into its little-endian byte order representation.
@@ -328,6 +327,30 @@ mod test {
     use rand::{Rng, SeedableRng};
     use rand_xorshift::XorShiftRng;
 
+    #[test]
+    fn test_uint8_alloc_input_vec_cost() {
+        use algebra::Field;
+        use r1cs_core::ConstraintCounter;
+
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        cs.alloc(|| "unrelated", || Ok(Fr::one())).unwrap();
+        let num_constraints = cs.num_constraints();
+
+        // 40 bytes are packed into two field elements of 31 bytes each. Every
+        // bit costs a witness and a boolean constraint, and every field element
+        // a packing constraint.
+        let byte_vals = [0xabu8; 40];
+        let mut counter = ConstraintCounter::new(&mut cs);
+        UInt8::alloc_input_vec(counter.ns(|| "alloc value"), &byte_vals).unwrap();
+        assert_eq!(counter.num_input_variables(), 2);
+        assert_eq!(counter.num_witness_variables(), 320);
+        assert_eq!(counter.num_constraints(), 322);
+
+        assert_eq!(cs.num_constraints() - num_constraints, 322);
+        assert_eq!(cs.num_aux(), 321);
+        assert!(cs.is_satisfied());
+    }
+
     #[test]
     fn test_uint8_from_bits_to_bits() {
         let mut cs = TestConstraintSystem::<Fr>::new();
@@ -350,6 +373,49 @@ mod test {
                 assert_eq!(bit.get_value().unwrap(), (native_byte >> i) & 1 == 1)
             }
         }
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn test_uint8_alloc_input_vec_packing() {
+        use algebra::{
+            fields::mnt4753::Fq, to_bytes, Field, FpParameters, PrimeField, ToBytes,
+            ToConstraintField,
+        };
+
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+        let mut cs = TestConstraintSystem::<Fq>::new();
+
+        // Three field elements, the last one partially filled
+        let chunk_size = (<Fq as PrimeField>::Params::CAPACITY / 8) as usize;
+        let byte_vals = (0..2 * chunk_size + 12).map(|_| rng.gen()).collect::<Vec<u8>>();
+        let bytes = UInt8::alloc_input_vec(cs.ns(|| "alloc value"), &byte_vals).unwrap();
+        assert_eq!(bytes.len(), byte_vals.len());
+        assert_eq!(bytes.iter().map(|b| b.get_value().unwrap()).collect::<Vec<_>>(), byte_vals);
+        assert!(cs.is_satisfied());
+
+        // The public inputs are the natively packed field elements, which unpack to
+        // the original bytes
+        let field_elements: Vec<Fq> = byte_vals.to_field_elements().unwrap();
+        assert_eq!(field_elements.len(), 3);
+        let mut unpacked = vec![];
+        for (i, fe) in field_elements.iter().enumerate() {
+            assert_eq!(cs.get(&format!("alloc value/Field element {}/alloc", i)), *fe);
+            unpacked.extend_from_slice(&to_bytes!(fe.into_repr()).unwrap()[..chunk_size]);
+        }
+        assert!(unpacked[byte_vals.len()..].iter().all(|b| *b == 0));
+        unpacked.truncate(byte_vals.len());
+        assert_eq!(unpacked, byte_vals);
+
+        // Any change in the bytes is detected by the packing constraint
+        let path = "alloc value/Bytes 1/byte_3/allocated bit_gadget 5/boolean";
+        let bit = cs.get(path);
+        cs.set(path, Fq::one() - &bit);
+        assert!(!cs.is_satisfied());
+        assert_eq!(
+            cs.which_is_unsatisfied().unwrap(),
+            "alloc value/Unpack field element 1/packing constraint"
+        );
     }
 
     #[test]
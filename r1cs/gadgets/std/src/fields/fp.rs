@@ -1,11 +1,11 @@
-use algebra::{bytes::ToBytes, FpParameters, PrimeField};
+use algebra::{bytes::ToBytes, BigInteger, FpParameters, PrimeField};
 use r1cs_core::{
     ConstraintSystem,
     ConstraintVar::{self, *},
     LinearCombination, SynthesisError,
 };
 
-use std::borrow::Borrow;
+use std::{borrow::Borrow, cmp::Ordering};
 
 use crate::{boolean::AllocatedBit, prelude::*, Assignment};
 
@@ -68,6 +68,174 @@ impl<F: PrimeField> FpGadget<F> {
         Ok(bits.into_iter().map(Boolean::from).collect())
     }
 
+    /// Outputs the `MODULUS_BITS` bits of `self` in *big-endian* order, i.e. most
+    /// significant bit first (the same order as `to_bits`). The bits are only
+    /// constrained to pack to `self`: they are not enforced to be the canonical
+    /// representation, see `to_bits_strict` for that.
+    #[inline]
+    pub fn to_bits_be<CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+    ) -> Result<Vec<Boolean>, SynthesisError> {
+        self.to_bits_with_length_restriction(cs, 0)
+    }
+
+    /// Outputs the `MODULUS_BITS` bits of `self` in *little-endian* order, i.e.
+    /// least significant bit first. As for `to_bits_be`, the bits are not enforced
+    /// to be the canonical representation: use `to_bits_le_strict` if needed.
+    #[inline]
+    pub fn to_bits_le<CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+    ) -> Result<Vec<Boolean>, SynthesisError> {
+        let mut bits = self.to_bits_be(cs)?;
+        bits.reverse();
+        Ok(bits)
+    }
+
+    /// Outputs the `MODULUS_BITS` bits of `self` in *little-endian* order, additionally
+    /// enforcing that they represent an integer smaller than the modulus, i.e. that
+    /// they are the unique canonical representation of `self`.
+    #[inline]
+    pub fn to_bits_le_strict<CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+    ) -> Result<Vec<Boolean>, SynthesisError> {
+        let mut bits = self.to_bits_strict(cs)?;
+        bits.reverse();
+        Ok(bits)
+    }
+
+    /// Packs the *little-endian* `bits` into a field element, i.e. the inverse of
+    /// `to_bits_le`, at the cost of a single constraint. At most `MODULUS_BITS` bits
+    /// are accepted, otherwise `SynthesisError::Unsatisfiable` is returned. Note that
+    /// `MODULUS_BITS` bits may represent an integer bigger than the modulus, which is
+    /// then reduced.
+    pub fn from_bits_le<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        bits: &[Boolean],
+    ) -> Result<Self, SynthesisError> {
+        if bits.len() > F::Params::MODULUS_BITS as usize {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        let mut value = Some(F::zero());
+        let mut coeff = F::one();
+
+        for bit in bits.iter() {
+            value = match (value, bit.get_value()) {
+                (Some(v), Some(true)) => Some(v + &coeff),
+                (Some(v), Some(false)) => Some(v),
+                _ => None,
+            };
+            coeff.double_in_place();
+        }
+
+        let result = Self::alloc(cs.ns(|| "packed value"), || value.get())?;
+        Boolean::enforce_is_decomposition_of(&mut cs, bits, &result)?;
+
+        Ok(result)
+    }
+
+    /// Outputs `self / other`, enforced by the single constraint `result * other = self`.
+    /// `SynthesisError::Unsatisfiable` is returned if `other` is the constant zero, or if
+    /// its value is zero. Note that the constraint alone doesn't exclude `other = 0`
+    /// (then satisfied by any `result` if `self = 0`), hence the caller must make sure
+    /// that the divisor is nonzero.
+    pub fn mul_by_inverse<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+    ) -> Result<Self, SynthesisError> {
+        if let LC(ref lc) = other.variable {
+            if lc.as_ref().is_empty() {
+                return Err(SynthesisError::Unsatisfiable);
+            }
+        }
+
+        let result = Self::alloc(cs.ns(|| "alloc result"), || {
+            let other_inv = other.value.get()?.inverse().ok_or(SynthesisError::Unsatisfiable)?;
+            Ok(self.value.get()? * &other_inv)
+        })?;
+        result.mul_equals(cs.ns(|| "result * other = self"), other, self)?;
+        Ok(result)
+    }
+
+    /// Enforces `self < 2^num_bits`, by decomposing `self` into `num_bits` bits, at
+    /// the cost of `num_bits + 1` constraints. Returns `SynthesisError::Unsatisfiable`
+    /// if `num_bits` is not smaller than `MODULUS_BITS`.
+    pub fn enforce_bit_length<CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+        num_bits: usize,
+    ) -> Result<(), SynthesisError> {
+        let modulus_bits = F::Params::MODULUS_BITS as usize;
+        if num_bits >= modulus_bits {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+        self.to_bits_with_length_restriction(cs, modulus_bits - num_bits)?;
+        Ok(())
+    }
+
+    /// Enforces `self < other` for `ordering = Less`, `self > other` for `ordering =
+    /// Greater`, and `self == other` for `ordering = Equal`, comparing the values as
+    /// integers. With `should_also_check_equal`, the strict inequalities are relaxed
+    /// to `<=` and `>=`. The difference of the two values is enforced to have at most
+    /// `MODULUS_BITS - 2` bits, at the cost of `MODULUS_BITS - 1` constraints.
+    ///
+    /// This is sound only if both values are smaller than `2^(MODULUS_BITS - 2)`:
+    /// otherwise the difference may wrap around the modulus. This is not enforced
+    /// here, see `enforce_bit_length`. Returns `SynthesisError::Unsatisfiable` if
+    /// the assigned values exceed this bound.
+    pub fn enforce_cmp<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+        ordering: Ordering,
+        should_also_check_equal: bool,
+    ) -> Result<(), SynthesisError> {
+        let num_bits = F::Params::MODULUS_BITS as usize - 2;
+        for value in [self.value, other.value].iter() {
+            if let Some(value) = value {
+                if value.into_repr().num_bits() as usize > num_bits {
+                    return Err(SynthesisError::Unsatisfiable);
+                }
+            }
+        }
+
+        let (smaller, bigger) = match ordering {
+            Ordering::Less => (self, other),
+            Ordering::Greater => (other, self),
+            Ordering::Equal => return self.enforce_equal(cs.ns(|| "self == other"), other),
+        };
+
+        // bigger - smaller (- 1, for a strict inequality) is non-negative, and hence
+        // smaller than 2^num_bits, iff the inequality holds
+        let mut diff = bigger.sub(cs.ns(|| "bigger - smaller"), smaller)?;
+        if !should_also_check_equal {
+            diff = diff.sub_constant(cs.ns(|| "bigger - smaller - 1"), &F::one())?;
+        }
+        diff.enforce_bit_length(cs.ns(|| "enforce diff bit length"), num_bits)
+    }
+
+    /// Allocates the value both as public input and as witness, and enforces their
+    /// equality. Useful to expose as public input a value computed in-circuit.
+    /// Returns `(input, witness)`.
+    pub fn alloc_input_and_witness_equal<FN, T, CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        value_gen: FN,
+    ) -> Result<(Self, Self), SynthesisError>
+        where
+            FN: FnOnce() -> Result<T, SynthesisError>,
+            T: Borrow<F>,
+    {
+        let value = value_gen().ok().map(|v| *v.borrow());
+        let input = Self::alloc_input(cs.ns(|| "alloc input"), || value.get())?;
+        let witness = Self::alloc(cs.ns(|| "alloc witness"), || value.get())?;
+        input.enforce_equal(cs.ns(|| "input = witness"), &witness)?;
+        Ok((input, witness))
+    }
+
     #[inline]
     pub fn to_bytes_with_length_restriction<CS: ConstraintSystem<F>>(
         &self,
@@ -411,7 +579,17 @@ impl<F: PrimeField> NEqGadget<F> for FpGadget<F> {
         other: &Self,
     ) -> Result<(), SynthesisError> {
         let a_minus_b = self.sub(cs.ns(|| "A - B"), other)?;
-        a_minus_b.inverse(cs.ns(|| "Enforce inverse exists"))?;
+        // If `self == other`, there is no inverse of `self - other` to witness
+        let inverse = Self::alloc(cs.ns(|| "alloc inverse"), || {
+            a_minus_b.value.get()?.inverse().ok_or(SynthesisError::Unsatisfiable)
+        })?;
+
+        cs.enforce(
+            || "(A - B) * inverse = 1",
+            |lc| &a_minus_b.variable + lc,
+            |lc| &inverse.variable + lc,
+            |lc| lc + CS::one(),
+        );
         Ok(())
     }
 
@@ -474,6 +652,8 @@ impl<F: PrimeField> ToBitsGadget<F> for FpGadget<F> {
         self.to_bits_with_length_restriction(&mut cs, 0)
     }
 
+    /// Outputs the binary representation of the value in `self` in *big-endian*
+    /// form, enforcing it to be the canonical one (i.e. smaller than the modulus).
     fn to_bits_strict<CS: ConstraintSystem<F>>(
         &self,
         mut cs: CS,
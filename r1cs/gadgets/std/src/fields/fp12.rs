@@ -10,7 +10,7 @@ use algebra::{
 };
 use std::{borrow::Borrow, marker::PhantomData};
 
-use crate::{prelude::*, Assignment};
+use crate::{fields::enforce_not_equal_by_inverse, prelude::*, Assignment};
 
 type Fp2Gadget<P, ConstraintF> = super::fp2::Fp2Gadget<
     <<P as Fp12Parameters>::Fp6Params as Fp6Parameters>::Fp2Params,
@@ -63,6 +63,29 @@ where
         Ok(Fp6Gadget::<P, ConstraintF>::new(new_c0, new_c1, new_c2))
     }
 
+    /// Returns the conjugate c0 - c1 * w of `self`, i.e. `self` raised to p^6.
+    /// It's a negation of c1, hence it doesn't add any constraint.
+    #[inline]
+    pub fn conjugate<CS: ConstraintSystem<ConstraintF>>(
+        &self,
+        cs: CS,
+    ) -> Result<Self, SynthesisError> {
+        let mut result = self.clone();
+        result.conjugate_in_place(cs)?;
+        Ok(result)
+    }
+
+    /// Inverse of an element of the cyclotomic subgroup (i.e. of norm 1), for
+    /// which it coincides with the conjugate. For any other element the result
+    /// differs from `inverse`.
+    #[inline]
+    pub fn unitary_inverse<CS: ConstraintSystem<ConstraintF>>(
+        &self,
+        cs: CS,
+    ) -> Result<Self, SynthesisError> {
+        self.conjugate(cs)
+    }
+
     #[inline]
     pub fn conjugate_in_place<CS: ConstraintSystem<ConstraintF>>(
         &mut self,
@@ -711,16 +734,14 @@ where
     #[inline]
     fn enforce_not_equal<CS: ConstraintSystem<ConstraintF>>(
         &self,
-        mut cs: CS,
+        cs: CS,
         other: &Self,
     ) -> Result<(), SynthesisError> {
-        self.c0.enforce_not_equal(&mut cs.ns(|| "c0"), &other.c0)?;
-        self.c1.enforce_not_equal(&mut cs.ns(|| "c1"), &other.c1)?;
-        Ok(())
+        enforce_not_equal_by_inverse::<Fp12<P>, _, _, _>(cs, self, other)
     }
 
     fn cost() -> usize {
-        2 * <Fp6Gadget<P, ConstraintF> as NEqGadget<ConstraintF>>::cost()
+        Self::cost_of_inv()
     }
 }
 
@@ -5,7 +5,7 @@ use algebra::{
 use r1cs_core::{ConstraintSystem, ConstraintVar, SynthesisError};
 use std::{borrow::Borrow, marker::PhantomData};
 
-use crate::{fields::fp::FpGadget, prelude::*, Assignment};
+use crate::{fields::{enforce_not_equal_by_inverse, fp::FpGadget}, prelude::*, Assignment};
 
 #[derive(Derivative)]
 #[derivative(Debug(bound = "P: Fp2Parameters, ConstraintF: PrimeField + SquareRootField"))]
@@ -530,16 +530,14 @@ impl<P: Fp2Parameters<Fp = ConstraintF>, ConstraintF: PrimeField + SquareRootFie
     #[inline]
     fn enforce_not_equal<CS: ConstraintSystem<ConstraintF>>(
         &self,
-        mut cs: CS,
+        cs: CS,
         other: &Self,
     ) -> Result<(), SynthesisError> {
-        self.c0.enforce_not_equal(&mut cs.ns(|| "c0"), &other.c0)?;
-        self.c1.enforce_not_equal(&mut cs.ns(|| "c1"), &other.c1)?;
-        Ok(())
+        enforce_not_equal_by_inverse::<Fp2<P>, _, _, _>(cs, self, other)
     }
 
     fn cost() -> usize {
-        2
+        Self::cost_of_inv()
     }
 }
 
@@ -5,7 +5,7 @@ use algebra::{
 use r1cs_core::{ConstraintSystem, ConstraintVar, SynthesisError};
 use std::{borrow::Borrow, marker::PhantomData};
 
-use crate::{fields::fp::FpGadget, prelude::*, Assignment};
+use crate::{fields::{enforce_not_equal_by_inverse, fp::FpGadget}, prelude::*, Assignment};
 
 
 #[derive(Derivative)]
@@ -54,6 +54,30 @@ impl<P: Fp3Parameters<Fp = ConstraintF>, ConstraintF: PrimeField + SquareRootFie
         Ok(self)
     }
 
+    /// Multiply a Fp3Gadget by an element of fp.
+    #[inline]
+    pub fn mul_by_fp_constant_in_place<CS: ConstraintSystem<ConstraintF>>(
+        &mut self,
+        mut cs: CS,
+        fe: &P::Fp,
+    ) -> Result<&mut Self, SynthesisError> {
+        self.c0.mul_by_constant_in_place(cs.ns(|| "c0"), fe)?;
+        self.c1.mul_by_constant_in_place(cs.ns(|| "c1"), fe)?;
+        self.c2.mul_by_constant_in_place(cs.ns(|| "c2"), fe)?;
+        Ok(self)
+    }
+
+    /// Multiply a Fp3Gadget by an element of fp.
+    #[inline]
+    pub fn mul_by_fp_constant<CS: ConstraintSystem<ConstraintF>>(
+        &self,
+        cs: CS,
+        fe: &P::Fp,
+    ) -> Result<Self, SynthesisError> {
+        let mut result = self.clone();
+        result.mul_by_fp_constant_in_place(cs, fe)?;
+        Ok(result)
+    }
 }
 
 impl<P: Fp3Parameters<Fp = ConstraintF>, ConstraintF: PrimeField + SquareRootField> FieldGadget<Fp3<P>, ConstraintF> for Fp3Gadget<P, ConstraintF>
@@ -708,17 +732,14 @@ impl <P: Fp3Parameters<Fp = ConstraintF>, ConstraintF: PrimeField + SquareRootFi
     #[inline]
     fn enforce_not_equal<CS: ConstraintSystem<ConstraintF>>(
         &self,
-        mut cs: CS,
+        cs: CS,
         other: &Self,
     ) -> Result<(), SynthesisError> {
-        self.c0.enforce_not_equal(&mut cs.ns(|| "c0"), &other.c0)?;
-        self.c1.enforce_not_equal(&mut cs.ns(|| "c1"), &other.c1)?;
-        self.c2.enforce_not_equal(&mut cs.ns(|| "c2"), &other.c2)?;
-        Ok(())
+        enforce_not_equal_by_inverse::<Fp3<P>, _, _, _>(cs, self, other)
     }
 
     fn cost() -> usize {
-        3 * <FpGadget<ConstraintF> as NEqGadget<ConstraintF>>::cost()
+        Self::cost_of_inv()
     }
 }
 
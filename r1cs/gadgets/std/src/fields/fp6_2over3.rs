@@ -5,7 +5,7 @@ use algebra::{fields::{
 use r1cs_core::{ConstraintSystem, ConstraintVar, SynthesisError};
 use std::{borrow::Borrow, marker::PhantomData};
 
-use crate::{prelude::*, Assignment};
+use crate::{fields::enforce_not_equal_by_inverse, prelude::*, Assignment};
 
 type Fp3Gadget<P, ConstraintF> = super::fp3::Fp3Gadget<<P as Fp6Parameters>::Fp3Params, ConstraintF>;
 
@@ -670,16 +670,14 @@ impl<P, ConstraintF: PrimeField + SquareRootField> NEqGadget<ConstraintF> for Fp
     #[inline]
     fn enforce_not_equal<CS: ConstraintSystem<ConstraintF>>(
         &self,
-        mut cs: CS,
+        cs: CS,
         other: &Self,
     ) -> Result<(), SynthesisError> {
-        self.c0.enforce_not_equal(&mut cs.ns(|| "c0"), &other.c0)?;
-        self.c1.enforce_not_equal(&mut cs.ns(|| "c1"), &other.c1)?;
-        Ok(())
+        enforce_not_equal_by_inverse::<Fp6<P>, _, _, _>(cs, self, other)
     }
 
     fn cost() -> usize {
-        2 * <Fp3Gadget<P, ConstraintF> as NEqGadget<ConstraintF>>::cost()
+        Self::cost_of_inv()
     }
 }
 
@@ -12,6 +12,7 @@ pub mod fp3;
 pub mod fp4;
 pub mod fp6_3over2;
 pub mod fp6_2over3;
+pub mod nonnative;
 
 pub mod bls12_377;
 pub mod edwards_bls12;
@@ -243,6 +244,30 @@ pub trait FieldGadget<F: Field, ConstraintF: Field>:
     fn cost_of_inv() -> usize;
 }
 
+/// Enforces `a != b` by witnessing the inverse of `a - b`, which exists if and only if
+/// the two elements differ. Returns `SynthesisError::Unsatisfiable` if `a == b`.
+pub(crate) fn enforce_not_equal_by_inverse<F, ConstraintF, FG, CS>(
+    mut cs: CS,
+    a: &FG,
+    b: &FG,
+) -> Result<(), SynthesisError>
+where
+    F: Field,
+    ConstraintF: Field,
+    FG: FieldGadget<F, ConstraintF>,
+    CS: ConstraintSystem<ConstraintF>,
+{
+    // If `a == b`, there is no inverse of `a - b` to witness
+    if let (Some(a), Some(b)) = (a.get_value(), b.get_value()) {
+        if a == b {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+    }
+    let a_minus_b = a.sub(cs.ns(|| "A - B"), b)?;
+    a_minus_b.inverse(cs.ns(|| "Enforce inverse exists"))?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod test {
     use rand::{self, thread_rng, SeedableRng, Rng};
@@ -250,7 +275,7 @@ mod test {
 
     use crate::{prelude::*, test_constraint_system::TestConstraintSystem, fields::fp::FpGadget};
     use algebra::{BitIterator, Field, UniformRand, PrimeField, leading_zeros};
-    use r1cs_core::ConstraintSystem;
+    use r1cs_core::{ConstraintSystem, SynthesisError};
 
     fn field_test<
         FE: Field,
@@ -294,6 +319,15 @@ mod test {
         // a == a
         assert_eq!(a, a);
 
+        // a != b, also if only some of the coordinates differ
+        let a_plus_one = a.add(cs.ns(|| "a_plus_one"), &one).unwrap();
+        a.enforce_not_equal(cs.ns(|| "a != b"), &b).unwrap();
+        a.enforce_not_equal(cs.ns(|| "a != a + 1"), &a_plus_one).unwrap();
+        match a.enforce_not_equal(cs.ns(|| "a != a"), &a) {
+            Err(SynthesisError::Unsatisfiable) => (),
+            _ => panic!("enforce_not_equal should fail for equal values"),
+        }
+
         // a + 0 = a
         let a_plus_zero = a.add(cs.ns(|| "a_plus_zero"), &zero).unwrap();
         assert_eq!(a_plus_zero, a);
@@ -609,6 +643,99 @@ mod test {
         assert!(!cs.is_satisfied());
     }
 
+    fn endianness_bits_fp_gadget_test<ConstraintF: PrimeField>(){
+        use crate::algebra::{BigInteger, FpParameters};
+
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+        let num_bits = ConstraintF::Params::MODULUS_BITS as usize;
+
+        for (i, a) in [
+            ConstraintF::zero(),
+            ConstraintF::one(),
+            -ConstraintF::one(),
+            ConstraintF::rand(&mut rng),
+        ].iter().enumerate() {
+            let mut cs = TestConstraintSystem::<ConstraintF>::new();
+            let a_g = FpGadget::<ConstraintF>::alloc(cs.ns(|| "alloc a"), || Ok(*a)).unwrap();
+
+            // Native big-endian bits, trimmed to MODULUS_BITS
+            let a_bits_be = a.into_repr().to_bits();
+            let a_bits_be = a_bits_be[a_bits_be.len() - num_bits..].to_vec();
+            let mut a_bits_le = a_bits_be.clone();
+            a_bits_le.reverse();
+
+            let be = a_g.to_bits_be(cs.ns(|| "to_bits_be")).unwrap();
+            let le = a_g.to_bits_le(cs.ns(|| "to_bits_le")).unwrap();
+            let le_strict = a_g.to_bits_le_strict(cs.ns(|| "to_bits_le_strict")).unwrap();
+
+            let values = |bits: &[Boolean]| bits.iter().map(|b| b.get_value().unwrap()).collect::<Vec<_>>();
+            assert_eq!(be.len(), num_bits, "case {}", i);
+            assert_eq!(le.len(), num_bits, "case {}", i);
+            assert_eq!(le_strict.len(), num_bits, "case {}", i);
+            assert_eq!(values(&be), a_bits_be, "case {}", i);
+            assert_eq!(values(&le), a_bits_le, "case {}", i);
+            assert_eq!(values(&le_strict), a_bits_le, "case {}", i);
+            assert_eq!(values(&le)[0], a.into_repr().is_odd(), "case {}", i);
+
+            // Packing back the little-endian bits costs a single constraint
+            let num_constraints = cs.num_constraints();
+            let a_read = FpGadget::<ConstraintF>::from_bits_le(cs.ns(|| "from_bits_le"), &le).unwrap();
+            assert_eq!(cs.num_constraints(), num_constraints + 1, "case {}", i);
+            assert_eq!(a_read.get_value().unwrap(), *a, "case {}", i);
+            a_read.enforce_equal(cs.ns(|| "a_read == a"), &a_g).unwrap();
+            assert!(cs.is_satisfied(), "case {}", i);
+        }
+
+        // Packing more bits than MODULUS_BITS is refused
+        let mut cs = TestConstraintSystem::<ConstraintF>::new();
+        let bits = vec![Boolean::constant(false); num_bits + 1];
+        assert!(FpGadget::<ConstraintF>::from_bits_le(cs.ns(|| "too many bits"), &bits).is_err());
+
+        // Packing a shorter sequence of bits, and tampering with the packed value
+        let bits = Vec::<Boolean>::alloc(
+            cs.ns(|| "alloc bits"),
+            || Ok((0..num_bits - 1).map(|_| rng.gen()).collect::<Vec<bool>>())
+        ).unwrap();
+        let packed = FpGadget::<ConstraintF>::from_bits_le(cs.ns(|| "pack bits"), &bits).unwrap();
+        let mut bits_be = bits.iter().map(|b| b.get_value().unwrap()).collect::<Vec<_>>();
+        bits_be.reverse();
+        assert_eq!(packed.get_value().unwrap(), ConstraintF::read_bits(bits_be).unwrap());
+        assert!(cs.is_satisfied());
+        cs.set("pack bits/packed value/alloc", ConstraintF::rand(&mut rng));
+        assert!(!cs.is_satisfied());
+        assert_eq!("pack bits/packing constraint", cs.which_is_unsatisfied().unwrap());
+
+        // The non-strict decomposition accepts the non-canonical representation
+        // a + p, whenever it fits in MODULUS_BITS bits, while the strict one must not.
+        let a = ConstraintF::one();
+        let mut a_plus_p = ConstraintF::Params::MODULUS;
+        a_plus_p.add_nocarry(&a.into_repr());
+        let a_plus_p_bits = a_plus_p.to_bits();
+        let (leading, a_plus_p_bits) = a_plus_p_bits.split_at(a_plus_p_bits.len() - num_bits);
+        if leading.iter().all(|b| !b) {
+            let mut cs = TestConstraintSystem::<ConstraintF>::new();
+            let a_g = FpGadget::<ConstraintF>::alloc(cs.ns(|| "alloc a"), || Ok(a)).unwrap();
+            a_g.to_bits_le(cs.ns(|| "to_bits_le")).unwrap();
+            for (j, b) in a_plus_p_bits.iter().enumerate() {
+                let value = if *b { ConstraintF::one() } else { ConstraintF::zero() };
+                cs.set(format!("to_bits_le/bit {}/boolean", j).as_ref(), value);
+            }
+            assert!(cs.is_satisfied());
+
+            // Strict: every bit assignment passing the range check must be canonical
+            let mut cs = TestConstraintSystem::<ConstraintF>::new();
+            let a_plus_p_bits = Vec::<Boolean>::alloc(
+                cs.ns(|| "alloc a + p bits"),
+                || Ok(a_plus_p_bits.to_vec())
+            ).unwrap();
+            Boolean::enforce_in_field::<_, _, ConstraintF>(
+                cs.ns(|| "enforce in field"),
+                &a_plus_p_bits,
+            ).unwrap();
+            assert!(!cs.is_satisfied());
+        }
+    }
+
     fn equ_verdict_fp_gadget_test<ConstraintF: PrimeField>()
     {
         let mut rng = thread_rng();
@@ -678,6 +805,100 @@ mod test {
         }
     }
 
+    fn not_equal_fp_gadget_test<ConstraintF: PrimeField>()
+    {
+        let mut rng = thread_rng();
+        let a = ConstraintF::rand(&mut rng);
+
+        //Case a != b
+        {
+            let mut cs = TestConstraintSystem::<ConstraintF>::new();
+
+            let a_gadget = FpGadget::<ConstraintF>::alloc(cs.ns(|| "alloc a"), || Ok(a)).unwrap();
+            let b_gadget = FpGadget::<ConstraintF>::alloc(
+                cs.ns(|| "alloc b"),
+                || Ok(a + &ConstraintF::one())
+            ).unwrap();
+
+            a_gadget.enforce_not_equal(cs.ns(|| "a != b"), &b_gadget).unwrap();
+            assert!(cs.is_satisfied());
+            assert_eq!(cs.num_constraints(), <FpGadget<ConstraintF> as NEqGadget<ConstraintF>>::cost());
+
+            //If the prover maliciously sets b equal to a, no inverse can satisfy the constraint
+            cs.set("alloc b/alloc", a);
+            assert!(!cs.is_satisfied());
+            assert_eq!("a != b/(A - B) * inverse = 1", cs.which_is_unsatisfied().unwrap());
+        }
+
+        //Case a == b: the witness can't be generated
+        {
+            let mut cs = TestConstraintSystem::<ConstraintF>::new();
+
+            let a_gadget = FpGadget::<ConstraintF>::alloc(cs.ns(|| "alloc a"), || Ok(a)).unwrap();
+            let b_gadget = FpGadget::<ConstraintF>::alloc(cs.ns(|| "alloc b"), || Ok(a)).unwrap();
+
+            match a_gadget.enforce_not_equal(cs.ns(|| "a != b"), &b_gadget) {
+                Err(SynthesisError::Unsatisfiable) => (),
+                _ => panic!("enforce_not_equal should fail for equal values"),
+            }
+        }
+    }
+
+    fn mul_by_inverse_fp_gadget_test<ConstraintF: PrimeField>()
+    {
+        let mut rng = thread_rng();
+        let a = ConstraintF::rand(&mut rng);
+        let b = ConstraintF::rand(&mut rng);
+
+        let mut cs = TestConstraintSystem::<ConstraintF>::new();
+        let a_gadget = FpGadget::<ConstraintF>::alloc(cs.ns(|| "alloc a"), || Ok(a)).unwrap();
+        let b_gadget = FpGadget::<ConstraintF>::alloc(cs.ns(|| "alloc b"), || Ok(b)).unwrap();
+
+        let num_constraints = cs.num_constraints();
+        let result = a_gadget.mul_by_inverse(cs.ns(|| "a div b"), &b_gadget).unwrap();
+        assert_eq!(cs.num_constraints() - num_constraints, 1);
+        assert_eq!(result.get_value().unwrap(), a / &b);
+        assert!(cs.is_satisfied());
+
+        //The prover can't witness anything else than a / b
+        cs.set("a div b/alloc result/alloc", a * &b);
+        assert!(!cs.is_satisfied());
+        assert_eq!("a div b/result * other = self/mul_constraint", cs.which_is_unsatisfied().unwrap());
+
+        //Division by zero
+        let zero = FpGadget::<ConstraintF>::zero(cs.ns(|| "zero")).unwrap();
+        match a_gadget.mul_by_inverse(cs.ns(|| "a div constant zero"), &zero) {
+            Err(SynthesisError::Unsatisfiable) => (),
+            _ => panic!("mul_by_inverse should fail for a constant zero divisor"),
+        }
+        let zero = FpGadget::<ConstraintF>::alloc(cs.ns(|| "alloc zero"), || Ok(ConstraintF::zero())).unwrap();
+        match a_gadget.mul_by_inverse(cs.ns(|| "a div zero"), &zero) {
+            Err(SynthesisError::Unsatisfiable) => (),
+            _ => panic!("mul_by_inverse should fail for a zero divisor"),
+        }
+    }
+
+    fn alloc_input_and_witness_equal_fp_gadget_test<ConstraintF: PrimeField>()
+    {
+        let mut rng = thread_rng();
+        let a = ConstraintF::rand(&mut rng);
+
+        let mut cs = TestConstraintSystem::<ConstraintF>::new();
+        let (input, witness) = FpGadget::<ConstraintF>::alloc_input_and_witness_equal(
+            cs.ns(|| "alloc a"),
+            || Ok(a)
+        ).unwrap();
+        assert_eq!(input.get_value().unwrap(), a);
+        assert_eq!(witness.get_value().unwrap(), a);
+        assert_eq!(cs.num_constraints(), 1);
+        assert!(cs.is_satisfied());
+
+        //The prover can't witness anything else than the public input
+        cs.set("alloc a/alloc witness/alloc", ConstraintF::rand(&mut rng));
+        assert!(!cs.is_satisfied());
+        assert_eq!("alloc a/input = witness/conditional_equals", cs.which_is_unsatisfied().unwrap());
+    }
+
     /*
     Test for the inverse gadget, should fail on old, insecure gadget
     which does not implement sufficiently many restristrictions to enforce the inverse relation.
@@ -726,8 +947,12 @@ mod test {
         }
 
         equ_verdict_fp_gadget_test::<Fq>();
+        not_equal_fp_gadget_test::<Fq>();
+        mul_by_inverse_fp_gadget_test::<Fq>();
+        alloc_input_and_witness_equal_fp_gadget_test::<Fq>();
         from_bits_fp_gadget_test::<Fq>();
         bit_fp_gadgets_test::<Fq>();
+        endianness_bits_fp_gadget_test::<Fq>();
 
         let c = Fq2Gadget::alloc(&mut cs.ns(|| "generate_c"), || Ok(Fq2::rand(&mut rng))).unwrap();
         let d = Fq2Gadget::alloc(&mut cs.ns(|| "generate_d"), || Ok(Fq2::rand(&mut rng))).unwrap();
@@ -759,6 +984,81 @@ mod test {
 
     }
 
+    #[test]
+    fn bls12_377_fq12_gadget_alloc_test() {
+        use crate::fields::bls12_377::Fq12Gadget;
+        use algebra::{fields::bls12_377::{Fq, Fq12}, to_bytes, ToBytes};
+
+        let mut cs = TestConstraintSystem::<Fq>::new();
+
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+        let a_native = Fq12::rand(&mut rng);
+        let b_native = Fq12::rand(&mut rng);
+
+        // Allocation round-trips
+        let a = Fq12Gadget::alloc(&mut cs.ns(|| "alloc a"), || Ok(a_native)).unwrap();
+        let a_input = Fq12Gadget::alloc_input(&mut cs.ns(|| "alloc_input a"), || Ok(a_native)).unwrap();
+        let a_const = Fq12Gadget::from_value(&mut cs.ns(|| "constant a"), &a_native);
+        let b = Fq12Gadget::alloc(&mut cs.ns(|| "alloc b"), || Ok(b_native)).unwrap();
+        assert_eq!(a.get_value().unwrap(), a_native);
+        assert_eq!(a_input.get_value().unwrap(), a_native);
+        assert_eq!(a_const.get_constant(), a_native);
+
+        // Arithmetic must match native
+        let ab = a.mul(cs.ns(|| "a * b"), &b).unwrap();
+        assert_eq!(ab.get_value().unwrap(), a_native * &b_native);
+        let a_squared = a_input.square(cs.ns(|| "a^2")).unwrap();
+        assert_eq!(a_squared.get_value().unwrap(), a_native.square());
+
+        // Serialization must match native
+        let a_bytes = a.to_bytes(cs.ns(|| "a to bytes")).unwrap();
+        let a_bytes = a_bytes.iter().map(|byte| byte.get_value().unwrap()).collect::<Vec<_>>();
+        assert_eq!(a_bytes, to_bytes!(a_native).unwrap());
+
+        // Equality enforcement
+        a.enforce_equal(cs.ns(|| "a == a_input"), &a_input).unwrap();
+        a_input.enforce_equal(cs.ns(|| "a_input == a_const"), &a_const).unwrap();
+        assert!(cs.is_satisfied());
+
+        a.enforce_equal(cs.ns(|| "a == b"), &b).unwrap();
+        assert!(!cs.is_satisfied());
+    }
+
+    #[test]
+    fn bls12_377_fq12_conjugate_test() {
+        use crate::fields::bls12_377::Fq12Gadget;
+        use algebra::fields::bls12_377::{Fq, Fq12};
+
+        let mut cs = TestConstraintSystem::<Fq>::new();
+
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+        let a_native = Fq12::rand(&mut rng);
+        let a = Fq12Gadget::alloc(&mut cs.ns(|| "alloc a"), || Ok(a_native)).unwrap();
+
+        // Conjugation is free and an involution
+        let num_constraints = cs.num_constraints();
+        let a_conj = a.conjugate(cs.ns(|| "conjugate a")).unwrap();
+        let a_conj_conj = a_conj.conjugate(cs.ns(|| "conjugate a_conj")).unwrap();
+        assert_eq!(cs.num_constraints(), num_constraints);
+
+        let mut a_conj_native = a_native;
+        a_conj_native.conjugate();
+        assert_eq!(a_conj.get_value().unwrap(), a_conj_native);
+        assert_eq!(a_conj_conj.get_value().unwrap(), a_native);
+        a_conj_conj.enforce_equal(cs.ns(|| "conj(conj(a)) == a"), &a).unwrap();
+
+        // u = conj(a)/a has norm 1, hence its unitary inverse is its inverse
+        let u_native = a_conj_native * &a_native.inverse().unwrap();
+        let u = Fq12Gadget::alloc(&mut cs.ns(|| "alloc u"), || Ok(u_native)).unwrap();
+        let num_constraints = cs.num_constraints();
+        let u_unitary_inv = u.unitary_inverse(cs.ns(|| "unitary inverse u")).unwrap();
+        assert_eq!(cs.num_constraints(), num_constraints);
+        let u_inv = u.inverse(cs.ns(|| "inverse u")).unwrap();
+        assert_eq!(u_unitary_inv.get_value().unwrap(), u_native.inverse().unwrap());
+        u_unitary_inv.enforce_equal(cs.ns(|| "unitary_inverse(u) == inverse(u)"), &u_inv).unwrap();
+        assert!(cs.is_satisfied());
+    }
+
     #[test]
     fn jubjub_field_gadgets_test() {
         use crate::fields::jubjub::FqGadget;
@@ -776,8 +1076,12 @@ mod test {
         }
         assert!(cs.is_satisfied());
         equ_verdict_fp_gadget_test::<Fq>();
+        not_equal_fp_gadget_test::<Fq>();
+        mul_by_inverse_fp_gadget_test::<Fq>();
+        alloc_input_and_witness_equal_fp_gadget_test::<Fq>();
         from_bits_fp_gadget_test::<Fq>();
         bit_fp_gadgets_test::<Fq>();
+        endianness_bits_fp_gadget_test::<Fq>();
     }
 
     #[test]
@@ -797,8 +1101,12 @@ mod test {
         }
         assert!(cs.is_satisfied());
         equ_verdict_fp_gadget_test::<Fq>();
+        not_equal_fp_gadget_test::<Fq>();
+        mul_by_inverse_fp_gadget_test::<Fq>();
+        alloc_input_and_witness_equal_fp_gadget_test::<Fq>();
         from_bits_fp_gadget_test::<Fq>();
         bit_fp_gadgets_test::<Fq>();
+        endianness_bits_fp_gadget_test::<Fq>();
     }
 
     #[test]
@@ -820,8 +1128,12 @@ mod test {
         }
 
         equ_verdict_fp_gadget_test::<Fq>();
+        not_equal_fp_gadget_test::<Fq>();
+        mul_by_inverse_fp_gadget_test::<Fq>();
+        alloc_input_and_witness_equal_fp_gadget_test::<Fq>();
         from_bits_fp_gadget_test::<Fq>();
         bit_fp_gadgets_test::<Fq>();
+        endianness_bits_fp_gadget_test::<Fq>();
 
         let c = Fq2Gadget::alloc(&mut cs.ns(|| "generate_c"), || Ok(Fq2::rand(&mut rng))).unwrap();
         let d = Fq2Gadget::alloc(&mut cs.ns(|| "generate_d"), || Ok(Fq2::rand(&mut rng))).unwrap();
@@ -861,8 +1173,12 @@ mod test {
         }
 
         equ_verdict_fp_gadget_test::<Fq>();
+        not_equal_fp_gadget_test::<Fq>();
+        mul_by_inverse_fp_gadget_test::<Fq>();
+        alloc_input_and_witness_equal_fp_gadget_test::<Fq>();
         from_bits_fp_gadget_test::<Fq>();
         bit_fp_gadgets_test::<Fq>();
+        endianness_bits_fp_gadget_test::<Fq>();
 
         let c = Fq3Gadget::alloc(&mut cs.ns(|| "generate_c"), || Ok(Fq3::rand(&mut rng))).unwrap();
         let d = Fq3Gadget::alloc(&mut cs.ns(|| "generate_d"), || Ok(Fq3::rand(&mut rng))).unwrap();
@@ -881,4 +1197,235 @@ mod test {
         }
         assert!(cs.is_satisfied());
     }
+
+    #[test]
+    fn mnt6_fq3_gadget_test() {
+        use crate::fields::{fp3::Fp3Gadget, mnt6753::{Fq3Gadget, FqGadget}};
+        use algebra::{
+            fields::mnt6753::{Fq, Fq3, Fq3Parameters},
+            fields::Fp3Parameters, to_bytes, ToBytes,
+        };
+
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        for i in 0..10 {
+            let mut cs = TestConstraintSystem::<Fq>::new();
+            let a_native = Fq3::rand(&mut rng);
+            let b_native = Fq3::rand(&mut rng);
+            let c_native = Fq::rand(&mut rng);
+
+            let a = Fq3Gadget::alloc(cs.ns(|| "alloc a"), || Ok(a_native)).unwrap();
+            let b = Fq3Gadget::alloc(cs.ns(|| "alloc b"), || Ok(b_native)).unwrap();
+            let c = FqGadget::alloc(cs.ns(|| "alloc c"), || Ok(c_native)).unwrap();
+
+            // Multiplication, with constraint count sanity check
+            let num_constraints = cs.num_constraints();
+            let ab = a.mul(cs.ns(|| "a * b"), &b).unwrap();
+            assert_eq!(cs.num_constraints() - num_constraints, Fq3Gadget::cost_of_mul());
+            assert_eq!(ab.get_value().unwrap(), a_native * &b_native);
+
+            let ab_expected = Fq3Gadget::alloc(cs.ns(|| "alloc a * b"), || Ok(a_native * &b_native)).unwrap();
+            let num_constraints = cs.num_constraints();
+            a.mul_equals(cs.ns(|| "a * b == ab"), &b, &ab_expected).unwrap();
+            assert_eq!(cs.num_constraints() - num_constraints, Fq3Gadget::cost_of_mul_equals());
+
+            // Squaring and inversion
+            let a_squared = a.square(cs.ns(|| "a^2")).unwrap();
+            assert_eq!(a_squared.get_value().unwrap(), a_native.square());
+            let a_inv = a.inverse(cs.ns(|| "a^-1")).unwrap();
+            assert_eq!(a_inv.get_value().unwrap(), a_native.inverse().unwrap());
+
+            // Frobenius map
+            let a_frob = a.frobenius_map(cs.ns(|| "frob a"), i).unwrap();
+            let mut a_frob_native = a_native;
+            a_frob_native.frobenius_map(i);
+            assert_eq!(a_frob.get_value().unwrap(), a_frob_native);
+
+            // Multiplication by the nonresidue, by constants and by base field elements
+            let c_nr = Fp3Gadget::<Fq3Parameters, Fq>::mul_fp_gadget_by_nonresidue(cs.ns(|| "c * nr"), &c).unwrap();
+            assert_eq!(c_nr.get_value().unwrap(), Fq3Parameters::mul_fp_by_nonresidue(&c_native));
+            let a_b_const = a.mul_by_constant(cs.ns(|| "a * b const"), &b_native).unwrap();
+            assert_eq!(a_b_const.get_value().unwrap(), a_native * &b_native);
+            let a_c_const = a.mul_by_fp_constant(cs.ns(|| "a * c const"), &c_native).unwrap();
+            let mut a_c_native = a_native;
+            a_c_native.mul_assign_by_fp(&c_native);
+            assert_eq!(a_c_const.get_value().unwrap(), a_c_native);
+            let mut a_c = a.clone();
+            a_c.mul_assign_by_fp_gadget(cs.ns(|| "a * c"), &c).unwrap();
+            assert_eq!(a_c.get_value().unwrap(), a_c_native);
+
+            // Serialization must match native
+            let a_bytes = a.to_bytes(cs.ns(|| "a to bytes")).unwrap();
+            let a_bytes = a_bytes.iter().map(|byte| byte.get_value().unwrap()).collect::<Vec<_>>();
+            assert_eq!(a_bytes, to_bytes!(a_native).unwrap());
+
+            // Conditional selection
+            for &cond in [true, false].iter() {
+                let cond_gadget = Boolean::alloc(cs.ns(|| format!("alloc cond {}", cond)), || Ok(cond)).unwrap();
+                let selected = Fq3Gadget::conditionally_select(
+                    cs.ns(|| format!("select {}", cond)),
+                    &cond_gadget,
+                    &a,
+                    &b,
+                ).unwrap();
+                assert_eq!(selected.get_value().unwrap(), if cond { a_native } else { b_native });
+            }
+
+            if !cs.is_satisfied() {
+                println!("{:?}", cs.which_is_unsatisfied().unwrap());
+            }
+            assert!(cs.is_satisfied());
+        }
+    }
+
+    #[test]
+    fn fp_gadget_slice_conditional_select_test() {
+        use crate::fields::mnt4753::FqGadget;
+        use algebra::fields::mnt4753::Fq;
+
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+        let mut cs = TestConstraintSystem::<Fq>::new();
+
+        let first_native = (0..4).map(|_| Fq::rand(&mut rng)).collect::<Vec<_>>();
+        let second_native = (0..4).map(|_| Fq::rand(&mut rng)).collect::<Vec<_>>();
+        let first = Vec::<FqGadget>::alloc(cs.ns(|| "alloc first"), || Ok(first_native.as_slice())).unwrap();
+        let second = Vec::<FqGadget>::alloc(cs.ns(|| "alloc second"), || Ok(second_native.as_slice())).unwrap();
+
+        for &cond in [true, false].iter() {
+            let cond_gadget = Boolean::alloc(cs.ns(|| format!("alloc cond {}", cond)), || Ok(cond)).unwrap();
+            let num_constraints = cs.num_constraints();
+            let selected = conditionally_select_slice(
+                cs.ns(|| format!("select {}", cond)),
+                &cond_gadget,
+                &first,
+                &second,
+            ).unwrap();
+            assert_eq!(
+                cs.num_constraints() - num_constraints,
+                first.len() * <FqGadget as CondSelectGadget<Fq>>::cost()
+            );
+            let selected = selected.iter().map(|f| f.get_value().unwrap()).collect::<Vec<_>>();
+            assert_eq!(selected, if cond { first_native.clone() } else { second_native.clone() });
+        }
+        assert!(cs.is_satisfied());
+
+        // Lengths must match
+        assert!(conditionally_select_slice(
+            cs.ns(|| "select mismatched"),
+            &Boolean::constant(true),
+            &first,
+            &second[..3],
+        ).is_err());
+    }
+
+    #[test]
+    fn fp_gadget_enforce_cmp_test() {
+        use crate::fields::mnt4753::FqGadget;
+        use algebra::{fields::mnt4753::Fq, FpParameters};
+        use std::cmp::Ordering;
+
+        let num_bits = <Fq as PrimeField>::Params::MODULUS_BITS as u64 - 2;
+        let bound = Fq::from(2u64).pow(&[num_bits]);
+        let max = bound - &Fq::one();
+        let values = [Fq::zero(), Fq::one(), max - &Fq::one(), max];
+
+        for a in values.iter() {
+            for b in values.iter() {
+                for &ordering in [Ordering::Less, Ordering::Greater, Ordering::Equal].iter() {
+                    for &should_also_check_equal in [false, true].iter() {
+                        let mut cs = TestConstraintSystem::<Fq>::new();
+                        let a_gadget = FqGadget::alloc(cs.ns(|| "alloc a"), || Ok(*a)).unwrap();
+                        let b_gadget = FqGadget::alloc(cs.ns(|| "alloc b"), || Ok(*b)).unwrap();
+                        a_gadget.enforce_cmp(
+                            cs.ns(|| "cmp"),
+                            &b_gadget,
+                            ordering,
+                            should_also_check_equal,
+                        ).unwrap();
+
+                        let native = a.into_repr().cmp(&b.into_repr());
+                        let expected = native == ordering
+                            || (should_also_check_equal && native == Ordering::Equal);
+                        assert_eq!(cs.is_satisfied(), expected);
+                    }
+                }
+            }
+        }
+
+        // Values out of the comparison range
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let a = FqGadget::alloc(cs.ns(|| "alloc a"), || Ok(bound)).unwrap();
+        let b = FqGadget::alloc(cs.ns(|| "alloc b"), || Ok(Fq::zero())).unwrap();
+        assert!(a.enforce_cmp(cs.ns(|| "a > b"), &b, Ordering::Greater, false).is_err());
+        assert!(b.enforce_cmp(cs.ns(|| "b < a"), &a, Ordering::Less, true).is_err());
+
+        // Bit length
+        a.enforce_bit_length(cs.ns(|| "a fits num_bits + 1"), num_bits as usize + 1).unwrap();
+        assert!(cs.is_satisfied());
+        a.enforce_bit_length(cs.ns(|| "a fits num_bits"), num_bits as usize).unwrap();
+        assert!(!cs.is_satisfied());
+        assert!(a.enforce_bit_length(
+            cs.ns(|| "too many bits"),
+            <Fq as PrimeField>::Params::MODULUS_BITS as usize
+        ).is_err());
+    }
+
+    fn nonnative_field_gadget_test<TargetField: PrimeField, ConstraintF: PrimeField>() {
+        use crate::fields::nonnative::NonNativeFieldGadget;
+
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        for _ in 0..3 {
+            let mut cs = TestConstraintSystem::<ConstraintF>::new();
+            let a_native = TargetField::rand(&mut rng);
+            let b_native = TargetField::rand(&mut rng);
+            let a = NonNativeFieldGadget::<TargetField, ConstraintF>::alloc(cs.ns(|| "alloc a"), || Ok(a_native)).unwrap();
+            let b = NonNativeFieldGadget::<TargetField, ConstraintF>::alloc(cs.ns(|| "alloc b"), || Ok(b_native)).unwrap();
+            assert_eq!(a.get_value().unwrap(), a_native);
+
+            let sum = a.add(cs.ns(|| "a + b"), &b).unwrap();
+            assert_eq!(sum.get_value().unwrap(), a_native + &b_native);
+
+            let prod = a.mul(cs.ns(|| "a * b"), &b).unwrap();
+            assert_eq!(prod.get_value().unwrap(), a_native * &b_native);
+
+            // Multiplication of an unreduced operand
+            let sum_prod = sum.mul(cs.ns(|| "(a + b) * b"), &b).unwrap();
+            assert_eq!(sum_prod.get_value().unwrap(), (a_native + &b_native) * &b_native);
+
+            // Repeated additions
+            let mut acc = a.clone();
+            let mut acc_native = a_native;
+            for i in 0..20 {
+                acc = acc.add(cs.ns(|| format!("acc + b {}", i)), &b).unwrap();
+                acc_native += &b_native;
+            }
+            assert_eq!(acc.get_value().unwrap(), acc_native);
+            assert_eq!(acc.reduce(cs.ns(|| "reduce acc")).unwrap().get_value().unwrap(), acc_native);
+
+            let expected = NonNativeFieldGadget::<TargetField, ConstraintF>::alloc(
+                cs.ns(|| "alloc expected"),
+                || Ok(a_native * &b_native),
+            ).unwrap();
+            prod.enforce_equal(cs.ns(|| "a * b == expected"), &expected).unwrap();
+            assert!(cs.is_satisfied());
+
+            let wrong = NonNativeFieldGadget::<TargetField, ConstraintF>::alloc(
+                cs.ns(|| "alloc wrong"),
+                || Ok(a_native * &b_native + &TargetField::one()),
+            ).unwrap();
+            prod.enforce_equal(cs.ns(|| "a * b == wrong"), &wrong).unwrap();
+            assert!(!cs.is_satisfied());
+        }
+    }
+
+    #[test]
+    fn nonnative_field_gadget_mnt_test() {
+        use algebra::fields::{bls12_381::Fr as BlsFr, mnt4753::{Fq, Fr}};
+
+        // The scalar field of MNT4 over its base field, as in recursion
+        nonnative_field_gadget_test::<Fr, Fq>();
+        // A target field bigger than the constraint field
+        nonnative_field_gadget_test::<Fq, BlsFr>();
+    }
 }
\ No newline at end of file
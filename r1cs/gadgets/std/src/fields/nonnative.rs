@@ -0,0 +1,419 @@
+//! Emulation of a prime field different from the constraint field, for instance to
+//! verify proofs or signatures over the other field of a curve cycle.
+
+use algebra::{BigInteger, Field, FpParameters, PrimeField};
+use r1cs_core::{ConstraintSystem, SynthesisError};
+
+use std::{
+    borrow::Borrow,
+    cmp::{max, min},
+    marker::PhantomData,
+};
+
+use crate::{fields::fp::FpGadget, prelude::*, Assignment};
+
+/// An element of `TargetField` in a circuit over `ConstraintF`, represented by the
+/// integer `sum_k limbs[k] * 2^(k * limb_bits)`, where `(limb_bits, num_limbs)` is
+/// given by `params()`. This integer is congruent to the element modulo the target
+/// modulus, but is not necessarily reduced. Every limb is smaller than
+/// `2^limb_bound_bits`: on allocation and after a reduction the limbs have at most
+/// `limb_bits` bits, each addition may add one more bit.
+#[derive(Clone, Debug)]
+pub struct NonNativeFieldGadget<TargetField: PrimeField, ConstraintF: PrimeField> {
+    pub limbs:           Vec<FpGadget<ConstraintF>>,
+    pub limb_bound_bits: usize,
+    _target:             PhantomData<TargetField>,
+}
+
+impl<TargetField: PrimeField, ConstraintF: PrimeField> NonNativeFieldGadget<TargetField, ConstraintF> {
+    /// Returns `(limb_bits, num_limbs)`. The limbs are chosen as wide as possible, such
+    /// that the sums of limb products arising in a multiplication, together with the
+    /// carries between them, don't overflow `ConstraintF`.
+    pub fn params() -> (usize, usize) {
+        let target_bits = TargetField::Params::MODULUS_BITS as usize;
+        let constraint_bits = ConstraintF::Params::MODULUS_BITS as usize;
+
+        let mut limb_bits = (constraint_bits - 5) / 2;
+        loop {
+            let num_limbs = (target_bits + limb_bits - 1) / limb_bits;
+            if 2 * limb_bits + bit_len(num_limbs + 2) + 5 <= constraint_bits {
+                return (limb_bits, num_limbs);
+            }
+            limb_bits -= 1;
+        }
+    }
+
+    /// The bound on `limb_bound_bits` up to which a gadget can still be reduced.
+    fn max_limb_bound_bits() -> usize {
+        ConstraintF::Params::MODULUS_BITS as usize - 5
+    }
+
+    pub fn get_value(&self) -> Option<TargetField> {
+        let (limb_bits, _) = Self::params();
+        let integer = integer_from_limbs(&self.limbs, limb_bits)?;
+        let (_, remainder) = div_rem(&integer, TargetField::Params::MODULUS.as_ref());
+
+        let mut repr = <TargetField as PrimeField>::BigInt::default();
+        repr.as_mut().copy_from_slice(&remainder);
+        Some(TargetField::from_repr(repr))
+    }
+
+    /// Outputs `self + other` by adding the limbs, which costs no constraints. If the
+    /// limbs would grow too large, the operands are reduced first.
+    pub fn add<CS: ConstraintSystem<ConstraintF>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+    ) -> Result<Self, SynthesisError> {
+        let (a, b) = if max(self.limb_bound_bits, other.limb_bound_bits) + 1 > Self::max_limb_bound_bits() {
+            (self.reduce(cs.ns(|| "reduce self"))?, other.reduce(cs.ns(|| "reduce other"))?)
+        } else {
+            (self.clone(), other.clone())
+        };
+
+        let mut limbs = Vec::with_capacity(a.limbs.len());
+        for (i, (x, y)) in a.limbs.iter().zip(b.limbs.iter()).enumerate() {
+            limbs.push(x.add(cs.ns(|| format!("add limb {}", i)), y)?);
+        }
+        Ok(Self::new(limbs, max(a.limb_bound_bits, b.limb_bound_bits) + 1))
+    }
+
+    /// Outputs `self * other`. The `num_limbs^2` products of limbs cost a constraint
+    /// each, and the product is reduced by a witnessed quotient and remainder, see
+    /// `reduce`. Operands with limbs of more than `limb_bits` bits are reduced first.
+    pub fn mul<CS: ConstraintSystem<ConstraintF>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+    ) -> Result<Self, SynthesisError> {
+        let (limb_bits, num_limbs) = Self::params();
+        let a = if self.limb_bound_bits > limb_bits {
+            self.reduce(cs.ns(|| "reduce self"))?
+        } else {
+            self.clone()
+        };
+        let b = if other.limb_bound_bits > limb_bits {
+            other.reduce(cs.ns(|| "reduce other"))?
+        } else {
+            other.clone()
+        };
+
+        let mut products = Vec::with_capacity(2 * num_limbs - 1);
+        for k in 0..2 * num_limbs - 1 {
+            products.push(FpGadget::zero(cs.ns(|| format!("zero product {}", k)))?);
+        }
+        for (i, x) in a.limbs.iter().enumerate() {
+            for (j, y) in b.limbs.iter().enumerate() {
+                let xy = x.mul(cs.ns(|| format!("a_{} * b_{}", i, j)), y)?;
+                products[i + j] = products[i + j].add(cs.ns(|| format!("add a_{} * b_{}", i, j)), &xy)?;
+            }
+        }
+
+        Self::reduce_limbs(
+            cs.ns(|| "reduce product"),
+            &products,
+            2 * limb_bits + bit_len(num_limbs),
+        )
+    }
+
+    /// Outputs the remainder of `self` modulo the target modulus, as limbs of
+    /// `limb_bits` bits. The remainder is witnessed together with the quotient,
+    /// enforcing `self = quotient * modulus + remainder` over the integers. Note that
+    /// the remainder is not enforced to be smaller than the modulus.
+    pub fn reduce<CS: ConstraintSystem<ConstraintF>>(
+        &self,
+        cs: CS,
+    ) -> Result<Self, SynthesisError> {
+        Self::reduce_limbs(cs, &self.limbs, self.limb_bound_bits)
+    }
+
+    /// Enforces `self == other` in `TargetField`, by enforcing the limbs of their
+    /// remainders to be equal. Equal remainders imply congruent integers, and an
+    /// honest prover witnesses the canonical remainders, which are equal for equal
+    /// elements.
+    pub fn enforce_equal<CS: ConstraintSystem<ConstraintF>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+    ) -> Result<(), SynthesisError> {
+        let a = self.reduce(cs.ns(|| "reduce self"))?;
+        let b = other.reduce(cs.ns(|| "reduce other"))?;
+        for (i, (x, y)) in a.limbs.iter().zip(b.limbs.iter()).enumerate() {
+            x.enforce_equal(cs.ns(|| format!("limb {}", i)), y)?;
+        }
+        Ok(())
+    }
+
+    fn new(limbs: Vec<FpGadget<ConstraintF>>, limb_bound_bits: usize) -> Self {
+        Self {
+            limbs,
+            limb_bound_bits,
+            _target: PhantomData,
+        }
+    }
+
+    /// Reduces the integer `sum_k limbs[k] * 2^(k * limb_bits)`, where each limb is
+    /// smaller than `2^limb_bound_bits`.
+    fn reduce_limbs<CS: ConstraintSystem<ConstraintF>>(
+        mut cs: CS,
+        limbs: &[FpGadget<ConstraintF>],
+        limb_bound_bits: usize,
+    ) -> Result<Self, SynthesisError> {
+        let (limb_bits, num_limbs) = Self::params();
+        let target_bits = TargetField::Params::MODULUS_BITS as usize;
+        let modulus = TargetField::Params::MODULUS;
+        let modulus_limbs = (0..num_limbs)
+            .map(|k| limb_of::<ConstraintF>(modulus.as_ref(), k * limb_bits, limb_bits))
+            .collect::<Vec<_>>();
+
+        // The integer is smaller than 2^integer_bits and the modulus is at least
+        // 2^(target_bits - 1), which bounds the quotient.
+        let integer_bits = limb_bound_bits + limb_bits * (limbs.len() - 1) + 1;
+        let quotient_bits = max(integer_bits.saturating_sub(target_bits - 1), 1);
+        let num_quotient_limbs = (quotient_bits + limb_bits - 1) / limb_bits;
+
+        let (quotient, remainder) = match integer_from_limbs(limbs, limb_bits) {
+            Some(integer) => {
+                let (q, r) = div_rem(&integer, modulus.as_ref());
+                (Some(q), Some(r))
+            },
+            None => (None, None),
+        };
+        let quotient = alloc_limbs(
+            cs.ns(|| "alloc quotient"),
+            quotient.as_ref(),
+            num_quotient_limbs,
+            limb_bits,
+        )?;
+        let remainder = alloc_limbs(
+            cs.ns(|| "alloc remainder"),
+            remainder.as_ref(),
+            num_limbs,
+            limb_bits,
+        )?;
+
+        // diffs[k] = limbs[k] - sum_{i + j = k} quotient[i] * modulus[j] - remainder[k]
+        let num_diffs = max(limbs.len(), num_quotient_limbs + num_limbs - 1);
+        let mut diffs = Vec::with_capacity(num_diffs);
+        for k in 0..num_diffs {
+            let mut diff = match limbs.get(k) {
+                Some(limb) => limb.clone(),
+                None => FpGadget::zero(cs.ns(|| format!("zero diff {}", k)))?,
+            };
+            for (i, q) in quotient.iter().enumerate().filter(|(i, _)| *i <= k && k - *i < num_limbs) {
+                let qp = q.mul_by_constant(cs.ns(|| format!("q_{} * p_{}", i, k - i)), &modulus_limbs[k - i])?;
+                diff = diff.sub(cs.ns(|| format!("diff {} - q_{} * p_{}", k, i, k - i)), &qp)?;
+            }
+            if let Some(r) = remainder.get(k) {
+                diff = diff.sub(cs.ns(|| format!("diff {} - r_{}", k, k)), r)?;
+            }
+            diffs.push(diff);
+        }
+
+        let product_bits = 2 * limb_bits + bit_len(min(num_quotient_limbs, num_limbs));
+        let diff_bits = max(limb_bound_bits, product_bits) + 1;
+        enforce_zero_integer(
+            cs.ns(|| "integer = quotient * modulus + remainder"),
+            &diffs,
+            diff_bits,
+            limb_bits,
+        )?;
+
+        Ok(Self::new(remainder, limb_bits))
+    }
+}
+
+impl<TargetField: PrimeField, ConstraintF: PrimeField> AllocGadget<TargetField, ConstraintF>
+    for NonNativeFieldGadget<TargetField, ConstraintF>
+{
+    fn alloc<FN, T, CS: ConstraintSystem<ConstraintF>>(
+        cs: CS,
+        value_gen: FN,
+    ) -> Result<Self, SynthesisError>
+    where
+        FN: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<TargetField>,
+    {
+        let (limb_bits, num_limbs) = Self::params();
+        let value = value_gen().ok().map(|v| v.borrow().into_repr().as_ref().to_vec());
+        let limbs = alloc_limbs(cs, value.as_ref(), num_limbs, limb_bits)?;
+        Ok(Self::new(limbs, limb_bits))
+    }
+
+    fn alloc_input<FN, T, CS: ConstraintSystem<ConstraintF>>(
+        mut cs: CS,
+        value_gen: FN,
+    ) -> Result<Self, SynthesisError>
+    where
+        FN: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<TargetField>,
+    {
+        let (limb_bits, num_limbs) = Self::params();
+        let value = value_gen().ok().map(|v| v.borrow().into_repr().as_ref().to_vec());
+        let mut limbs = Vec::with_capacity(num_limbs);
+        for k in 0..num_limbs {
+            let limb = FpGadget::alloc_input(cs.ns(|| format!("alloc limb {}", k)), || {
+                Ok(limb_of::<ConstraintF>(value.as_ref().get()?, k * limb_bits, limb_bits))
+            })?;
+            limb.enforce_bit_length(cs.ns(|| format!("limb {} bit length", k)), limb_bits)?;
+            limbs.push(limb);
+        }
+        Ok(Self::new(limbs, limb_bits))
+    }
+}
+
+/// Allocates `num_limbs` limbs of `limb_bits` bits of the integer `value`, given by
+/// its little-endian `u64` words, enforcing their bit length.
+fn alloc_limbs<ConstraintF: PrimeField, CS: ConstraintSystem<ConstraintF>>(
+    mut cs: CS,
+    value: Option<&Vec<u64>>,
+    num_limbs: usize,
+    limb_bits: usize,
+) -> Result<Vec<FpGadget<ConstraintF>>, SynthesisError> {
+    let mut limbs = Vec::with_capacity(num_limbs);
+    for k in 0..num_limbs {
+        let limb = FpGadget::alloc(cs.ns(|| format!("alloc limb {}", k)), || {
+            Ok(limb_of::<ConstraintF>(value.get()?, k * limb_bits, limb_bits))
+        })?;
+        limb.enforce_bit_length(cs.ns(|| format!("limb {} bit length", k)), limb_bits)?;
+        limbs.push(limb);
+    }
+    Ok(limbs)
+}
+
+/// Enforces that the integer `sum_k diffs[k] * 2^(k * limb_bits)` is zero, where the
+/// `diffs` are signed integers of absolute value smaller than `2^diff_bits`. The
+/// carries from each limb to the next are witnessed and range checked, such that
+/// none of the constraints wraps around the modulus of `ConstraintF`.
+fn enforce_zero_integer<ConstraintF: PrimeField, CS: ConstraintSystem<ConstraintF>>(
+    mut cs: CS,
+    diffs: &[FpGadget<ConstraintF>],
+    diff_bits: usize,
+    limb_bits: usize,
+) -> Result<(), SynthesisError> {
+    if diff_bits + 4 > ConstraintF::Params::MODULUS_BITS as usize {
+        return Err(SynthesisError::Unsatisfiable);
+    }
+
+    let shift = ConstraintF::from(2u64).pow(&[limb_bits as u64]);
+    let shift_inv = shift.inverse().unwrap();
+    // The carries are of absolute value at most 2^carry_bits
+    let carry_bits = (diff_bits + 1).saturating_sub(limb_bits);
+    let carry_offset = ConstraintF::from(2u64).pow(&[carry_bits as u64]);
+
+    let zero = FpGadget::zero(cs.ns(|| "zero"))?;
+    let mut carry = zero.clone();
+    for (k, diff) in diffs.iter().enumerate() {
+        let sum = diff.add(cs.ns(|| format!("diff {} + carry", k)), &carry)?;
+        if k == diffs.len() - 1 {
+            sum.enforce_equal(cs.ns(|| "last carry is zero"), &zero)?;
+        } else {
+            // sum = next_carry * 2^limb_bits
+            let next_carry = FpGadget::alloc(cs.ns(|| format!("alloc carry {}", k)), || {
+                Ok(sum.get_value().get()? * &shift_inv)
+            })?;
+            let shifted = next_carry.mul_by_constant(cs.ns(|| format!("shift carry {}", k)), &shift)?;
+            sum.enforce_equal(cs.ns(|| format!("diff {} + carry = shifted carry", k)), &shifted)?;
+            next_carry
+                .add_constant(cs.ns(|| format!("offset carry {}", k)), &carry_offset)?
+                .enforce_bit_length(cs.ns(|| format!("carry {} bit length", k)), carry_bits + 1)?;
+            carry = next_carry;
+        }
+    }
+    Ok(())
+}
+
+/// The number of bits needed to represent `n`.
+fn bit_len(n: usize) -> usize {
+    (0usize.count_zeros() - n.leading_zeros()) as usize
+}
+
+/// Returns the bits `[lo, lo + limb_bits)` of the integer given by its little-endian
+/// `u64` words, as an element of `F`.
+fn limb_of<F: PrimeField>(words: &[u64], lo: usize, limb_bits: usize) -> F {
+    let bits = (lo..lo + limb_bits)
+        .rev()
+        .map(|i| words.get(i / 64).map_or(false, |word| (word >> (i % 64)) & 1 == 1))
+        .collect::<Vec<_>>();
+    F::from_repr(F::BigInt::from_bits(&bits))
+}
+
+/// Returns the integer `sum_k limbs[k] * 2^(k * limb_bits)` as little-endian `u64`
+/// words, or `None` if a limb is not assigned.
+fn integer_from_limbs<F: PrimeField>(limbs: &[FpGadget<F>], limb_bits: usize) -> Option<Vec<u64>> {
+    let mut integer = vec![];
+    for (k, limb) in limbs.iter().enumerate() {
+        add_shifted(&mut integer, limb.get_value()?.into_repr().as_ref(), k * limb_bits);
+    }
+    Some(integer)
+}
+
+/// Adds `x * 2^shift` to `acc`, both given by their little-endian `u64` words.
+fn add_shifted(acc: &mut Vec<u64>, x: &[u64], shift: usize) {
+    let (word_shift, bit_shift) = (shift / 64, shift % 64);
+    if acc.len() < word_shift + x.len() + 2 {
+        acc.resize(word_shift + x.len() + 2, 0);
+    }
+
+    let mut carry = 0u128;
+    for (j, word) in acc.iter_mut().enumerate().skip(word_shift) {
+        let j = j - word_shift;
+        let lo = x.get(j).map_or(0, |w| w << bit_shift);
+        let hi = match (bit_shift, j.checked_sub(1).and_then(|j| x.get(j))) {
+            (0, _) | (_, None) => 0,
+            (_, Some(w)) => w >> (64 - bit_shift),
+        };
+        let sum = u128::from(*word) + u128::from(lo | hi) + carry;
+        *word = sum as u64;
+        carry = sum >> 64;
+    }
+}
+
+/// Returns the quotient and remainder of `n` divided by `d`, all given by their
+/// little-endian `u64` words. The remainder has as many words as `d`.
+fn div_rem(n: &[u64], d: &[u64]) -> (Vec<u64>, Vec<u64>) {
+    let mut quotient = vec![0u64; n.len()];
+    let mut remainder = vec![0u64; d.len() + 1];
+    for i in (0..64 * n.len()).rev() {
+        // remainder = 2 * remainder + (i-th bit of n)
+        let mut carry = (n[i / 64] >> (i % 64)) & 1;
+        for word in remainder.iter_mut() {
+            let next_carry = *word >> 63;
+            *word = (*word << 1) | carry;
+            carry = next_carry;
+        }
+        if !less_than(&remainder, d) {
+            sub_assign(&mut remainder, d);
+            quotient[i / 64] |= 1 << (i % 64);
+        }
+    }
+    remainder.truncate(d.len());
+    (quotient, remainder)
+}
+
+fn less_than(a: &[u64], b: &[u64]) -> bool {
+    for i in (0..max(a.len(), b.len())).rev() {
+        let x = a.get(i).cloned().unwrap_or(0);
+        let y = b.get(i).cloned().unwrap_or(0);
+        if x != y {
+            return x < y;
+        }
+    }
+    false
+}
+
+/// Subtracts `b` from `a`, assuming `a >= b`.
+fn sub_assign(a: &mut [u64], b: &[u64]) {
+    let mut borrow = 0u128;
+    for (i, word) in a.iter_mut().enumerate() {
+        let y = u128::from(b.get(i).cloned().unwrap_or(0)) + borrow;
+        let x = u128::from(*word);
+        if x >= y {
+            *word = (x - y) as u64;
+            borrow = 0;
+        } else {
+            *word = ((1u128 << 64) + x - y) as u64;
+            borrow = 1;
+        }
+    }
+}
@@ -23,6 +23,43 @@ mod test {
     };
     use r1cs_core::ConstraintSystem;
 
+    #[test]
+    fn bls12_g1_double_two_torsion_witness() {
+        use algebra::Field;
+
+        let a: G1 = rand::random();
+
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let mut gadget_a = G1Gadget::alloc_without_check(&mut cs.ns(|| "a"), || Ok(a)).unwrap();
+        let num_constraints = cs.num_constraints();
+        gadget_a.double_in_place(&mut cs.ns(|| "2a")).unwrap();
+        assert_eq!(cs.num_constraints() - num_constraints, G1Gadget::cost_of_double());
+        assert!(cs.is_satisfied());
+
+        // A witness with y = 0 is rejected whatever the slope, as 2y is not invertible.
+        cs.set("a/y/alloc", Fq::zero());
+        for inv in &[Fq::zero(), Fq::one(), rand::random()] {
+            cs.set("2a/2y inverse/alloc", *inv);
+            assert!(!cs.is_satisfied());
+            assert_eq!(cs.which_is_unsatisfied().unwrap(), "2a/check 2y inverse/mul_constraint");
+        }
+
+        // Since COEFF_A = 0, the point (0, 0) satisfies both y = 0 and 3x^2 + a = 0,
+        // hence the slope relation alone leaves lambda unbound: the witness is rejected
+        // even if its curve equation is not enforced.
+        cs.set("a/x/alloc", Fq::zero());
+        cs.set("2a/x^2/mul/alloc", Fq::zero());
+        let lambda: Fq = rand::random();
+        cs.set("2a/lambda/alloc", lambda);
+        cs.set("2a/lambda^2/mul/alloc", lambda.square());
+        cs.set("2a/times lambda/mul/alloc", -(lambda.square() * &lambda));
+        for inv in &[Fq::zero(), Fq::one(), rand::random()] {
+            cs.set("2a/2y inverse/alloc", *inv);
+            assert!(!cs.is_satisfied());
+            assert_eq!(cs.which_is_unsatisfied().unwrap(), "2a/check 2y inverse/mul_constraint");
+        }
+    }
+
     #[test]
     fn bls12_g1_constraint_costs() {
         use crate::boolean::AllocatedBit;
@@ -160,6 +197,36 @@ mod test {
         assert!(cs.is_satisfied());
     }
 
+    #[test]
+    fn bls12_g1_prime_subgroup_generator_test() {
+        use algebra::{FpParameters, UniformRand};
+        use rand::SeedableRng;
+        use rand_xorshift::XorShiftRng;
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        let mut cs = TestConstraintSystem::<Fq>::new();
+
+        // The generator is a constant equal to the native one
+        let g = G1Gadget::prime_subgroup_generator(cs.ns(|| "generator"));
+        assert_eq!(cs.num_aux(), 0);
+        assert_eq!(cs.num_constraints(), 0);
+        assert_eq!(g.get_value().unwrap(), G1::prime_subgroup_generator());
+        g.enforce_on_curve(cs.ns(|| "generator on curve")).unwrap();
+
+        // T + [r]G = T, i.e. [r]G is the identity
+        let t = G1::rand(&mut rng);
+        let gadget_t = G1Gadget::alloc(&mut cs.ns(|| "t"), || Ok(t)).unwrap();
+        let mut order: Vec<bool> = BitIterator::new(<Fr as PrimeField>::Params::MODULUS).collect();
+        order.reverse();
+        let order = order.into_iter().map(Boolean::constant).collect::<Vec<_>>();
+        let result = g
+            .mul_bits(cs.ns(|| "t + [r]g"), &gadget_t, order.iter())
+            .unwrap();
+        result.enforce_equal(cs.ns(|| "t + [r]g == t"), &gadget_t).unwrap();
+        assert_eq!(result.get_value().unwrap(), t);
+        assert!(cs.is_satisfied());
+    }
+
     #[test]
     fn bls12_g2_gadget_test() {
         let mut cs = TestConstraintSystem::<Fq>::new();
@@ -80,6 +80,37 @@ mod test {
         assert_eq!(add_cost, G2Gadget::cost_of_add());
     }
 
+    #[test]
+    fn mnt4753_g1_enforce_equal_affine_nonzero_test() {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+
+        let a: G1 = rand::random();
+        let b: G1 = rand::random();
+        let gadget_a = G1Gadget::alloc(&mut cs.ns(|| "a"), || Ok(a)).unwrap();
+        let gadget_a_copy = G1Gadget::alloc(&mut cs.ns(|| "a copy"), || Ok(a)).unwrap();
+        let gadget_b = G1Gadget::alloc(&mut cs.ns(|| "b"), || Ok(b)).unwrap();
+
+        let num_constraints = cs.num_constraints();
+        gadget_a
+            .enforce_equal(&mut cs.ns(|| "a == a copy"), &gadget_a_copy)
+            .unwrap();
+        let full_cost = cs.num_constraints() - num_constraints;
+
+        let num_constraints = cs.num_constraints();
+        gadget_a
+            .enforce_equal_affine_nonzero(&mut cs.ns(|| "a == a copy nonzero"), &gadget_a_copy)
+            .unwrap();
+        let nonzero_cost = cs.num_constraints() - num_constraints;
+
+        assert!(cs.is_satisfied());
+        assert!(nonzero_cost < full_cost);
+
+        gadget_a
+            .enforce_equal_affine_nonzero(&mut cs.ns(|| "a == b nonzero"), &gadget_b)
+            .unwrap();
+        assert!(!cs.is_satisfied());
+    }
+
     #[test]
     fn mnt4753_g1_gadget_test() {
         use algebra::UniformRand;
@@ -164,6 +195,304 @@ mod test {
         assert!(cs.is_satisfied());
     }
 
+    #[test]
+    fn mnt4753_g1_window_table_test() {
+        use algebra::{UniformRand, ToBits};
+        use rand::SeedableRng;
+        use rand_xorshift::XorShiftRng;
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        let mut cs = TestConstraintSystem::<Fq>::new();
+
+        let base = G1::rand(&mut rng);
+        let b = G1::rand(&mut rng);
+        let gadget_base = G1Gadget::alloc(&mut cs.ns(|| "base"), || Ok(base)).unwrap();
+        let gadget_b = G1Gadget::alloc(&mut cs.ns(|| "b"), || Ok(b)).unwrap();
+
+        // The table must not allocate anything
+        let num_constraints = cs.num_constraints();
+        let table = G1Gadget::precompute_gadget_window_table(cs.ns(|| "table"), &base, 3).unwrap();
+        assert_eq!(cs.num_constraints(), num_constraints);
+
+        // Windows of a single bit can't avoid collisions
+        assert!(G1Gadget::precompute_gadget_window_table(cs.ns(|| "1-bit table"), &base, 1).is_err());
+
+        // Reuse the same table for two different scalars
+        for i in 0..2 {
+            let scalar = Fr::rand(&mut rng);
+            let native_result = (base.into_affine().mul(scalar) + &b).into_affine();
+
+            let mut scalar = scalar.write_bits();
+            // Get the scalar bits into little-endian form.
+            scalar.reverse();
+            let input = Vec::<Boolean>::alloc(cs.ns(|| format!("Input {}", i)), || Ok(scalar)).unwrap();
+
+            let result = G1Gadget::mul_bits_with_window_table(
+                cs.ns(|| format!("mul_bits_with_window_table {}", i)),
+                &table,
+                &gadget_b,
+                input.as_slice()
+            ).unwrap();
+            assert_eq!(result.get_value().unwrap().into_affine(), native_result);
+
+            let expected = gadget_base
+                .mul_bits(cs.ns(|| format!("mul_bits {}", i)), &gadget_b, input.iter())
+                .unwrap();
+            result.enforce_equal(cs.ns(|| format!("result == expected {}", i)), &expected).unwrap();
+        }
+
+        // A result equal to the sum of the entries looked up collides with it:
+        // for 5 the single entry looked up is (5 + 2) * base
+        let five = Vec::<Boolean>::alloc(cs.ns(|| "five"), || Ok(vec![true, false, true])).unwrap();
+        let colliding_b = G1Gadget::alloc(cs.ns(|| "colliding b"), || {
+            Ok(base.into_affine().mul(Fr::from(7u64)))
+        }).unwrap();
+        assert!(G1Gadget::mul_bits_with_window_table(
+            cs.ns(|| "colliding result"),
+            &table,
+            &colliding_b,
+            five.as_slice()
+        ).is_err());
+
+        if !cs.is_satisfied() {
+            println!("{:?}", cs.which_is_unsatisfied().unwrap());
+        }
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn mnt4753_straus_msm_test() {
+        use algebra::{UniformRand, ToBits};
+        use rand::SeedableRng;
+        use rand_xorshift::XorShiftRng;
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        // Returns the number of constraints of the Straus MSM and of the naive
+        // sequence of fixed base scalar multiplications.
+        macro_rules! straus_msm_test {
+            ($group: ty, $gadget: ty) => {{
+                let mut cs = TestConstraintSystem::<Fq>::new();
+
+                let bases = (0..3).map(|_| <$group>::rand(&mut rng)).collect::<Vec<_>>();
+                let scalars = (0..3).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+                let b = <$group>::rand(&mut rng);
+                let gadget_b = <$gadget>::alloc(&mut cs.ns(|| "b"), || Ok(b)).unwrap();
+
+                let mut native_result = b;
+                for (base, scalar) in bases.iter().zip(scalars.iter()) {
+                    native_result += &base.into_affine().mul(*scalar);
+                }
+
+                let inputs = scalars.iter().enumerate().map(|(i, scalar)| {
+                    let mut bits = scalar.write_bits();
+                    // Get the scalar bits into little-endian form.
+                    bits.reverse();
+                    Vec::<Boolean>::alloc(cs.ns(|| format!("Input {}", i)), || Ok(bits)).unwrap()
+                }).collect::<Vec<_>>();
+
+                let num_constraints = cs.num_constraints();
+                let result = <$gadget>::straus_msm(
+                    cs.ns(|| "straus msm"),
+                    &gadget_b,
+                    &bases,
+                    &inputs,
+                ).unwrap();
+                let straus_cost = cs.num_constraints() - num_constraints;
+                assert_eq!(result.get_value().unwrap().into_affine(), native_result.into_affine());
+
+                let num_constraints = cs.num_constraints();
+                let mut expected = gadget_b.clone();
+                for (i, (base, input)) in bases.iter().zip(inputs.iter()).enumerate() {
+                    expected = <$gadget>::mul_bits_fixed_base(
+                        base,
+                        cs.ns(|| format!("mul_bits_fixed_base {}", i)),
+                        &expected,
+                        input.as_slice(),
+                    ).unwrap();
+                }
+                let naive_cost = cs.num_constraints() - num_constraints;
+                result.enforce_equal(cs.ns(|| "result == expected"), &expected).unwrap();
+
+                // Scalars of different lengths are padded
+                let short_inputs = vec![inputs[0][..10].to_vec(), inputs[1].clone(), vec![]];
+                let short_result = <$gadget>::straus_msm(
+                    cs.ns(|| "straus msm short scalars"),
+                    &gadget_b,
+                    &bases,
+                    &short_inputs,
+                ).unwrap();
+                let mut short_scalar = scalars[0].into_repr();
+                short_scalar.as_mut()[0] &= (1 << 10) - 1;
+                for limb in short_scalar.as_mut()[1..].iter_mut() {
+                    *limb = 0;
+                }
+                let native_short_result = b
+                    + &bases[0].into_affine().mul(short_scalar)
+                    + &bases[1].into_affine().mul(scalars[1]);
+                assert_eq!(short_result.get_value().unwrap().into_affine(), native_short_result.into_affine());
+
+                // Bases and scalars must match
+                assert!(<$gadget>::straus_msm(cs.ns(|| "mismatch"), &gadget_b, &bases, &inputs[1..]).is_err());
+                assert!(<$gadget>::straus_msm(cs.ns(|| "empty"), &gadget_b, &[], &[]).is_err());
+
+                // Opposite bases give the neutral element as a table entry
+                let opposite_bases = vec![bases[0], -bases[0]];
+                assert!(<$gadget>::straus_msm(cs.ns(|| "opposite bases"), &gadget_b, &opposite_bases, &inputs[..2]).is_err());
+
+                if !cs.is_satisfied() {
+                    println!("{:?}", cs.which_is_unsatisfied().unwrap());
+                }
+                assert!(cs.is_satisfied());
+                (straus_cost, naive_cost)
+            }};
+        }
+
+        // Per bit position: 2^3 - 3 - 1 products and a single addition.
+        let (straus_cost, _) = straus_msm_test!(G1, G1Gadget);
+        assert_eq!(straus_cost, 753 * (4 + G1Gadget::cost_of_add()) + G1Gadget::cost_of_add());
+
+        // Additions over Fq2 are expensive enough for Straus to beat the naive approach.
+        let (straus_cost, naive_cost) = straus_msm_test!(G2, G2Gadget);
+        assert!(straus_cost < naive_cost);
+    }
+
+    #[test]
+    fn mnt4753_g1_mul_scalar_field_element_test() {
+        use algebra::{UniformRand, ToBits, Field};
+        use rand::SeedableRng;
+        use rand_xorshift::XorShiftRng;
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        let mut cs = TestConstraintSystem::<Fq>::new();
+
+        let a = G1::rand(&mut rng);
+        let b = G1::rand(&mut rng);
+        let gadget_a = G1Gadget::alloc(&mut cs.ns(|| "a"), || Ok(a)).unwrap();
+        let gadget_b = G1Gadget::alloc(&mut cs.ns(|| "b"), || Ok(b)).unwrap();
+
+        // r - 1 has its most significant bit set, i.e. uses the full width.
+        let scalars = vec![-Fr::one(), Fr::rand(&mut rng)];
+        for (i, scalar) in scalars.into_iter().enumerate() {
+            let native_result = (a.into_affine().mul(scalar) + &b).into_affine();
+
+            let mut bits = scalar.write_bits();
+            assert_eq!(bits.len(), 753);
+            // Get the scalar bits into little-endian form.
+            bits.reverse();
+            let input = Vec::<Boolean>::alloc(cs.ns(|| format!("Input {}", i)), || Ok(bits)).unwrap();
+
+            let result = gadget_a
+                .mul_scalar_field_element(cs.ns(|| format!("mul {}", i)), &gadget_b, input.as_slice())
+                .unwrap();
+            assert_eq!(result.get_value().unwrap().into_affine(), native_result);
+
+            // Any other number of bits is rejected.
+            assert!(gadget_a
+                .mul_scalar_field_element(cs.ns(|| format!("mul short {}", i)), &gadget_b, &input[1..])
+                .is_err());
+        }
+        assert!(cs.is_satisfied());
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "scalar does not fit")]
+    fn mnt4753_g1_mul_bits_overlong_scalar_test() {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+
+        let a: G1 = rand::random();
+        let b: G1 = rand::random();
+        let gadget_a = G1Gadget::alloc(&mut cs.ns(|| "a"), || Ok(a)).unwrap();
+        let gadget_b = G1Gadget::alloc(&mut cs.ns(|| "b"), || Ok(b)).unwrap();
+
+        let mut bits = vec![false; 754];
+        bits[753] = true;
+        let input = Vec::<Boolean>::alloc(cs.ns(|| "Input"), || Ok(bits)).unwrap();
+        let _ = gadget_a.mul_bits(cs.ns(|| "mul_bits"), &gadget_b, input.iter());
+    }
+
+    #[test]
+    fn mnt4753_prime_subgroup_generator_test() {
+        use algebra::{FpParameters, UniformRand};
+        use rand::SeedableRng;
+        use rand_xorshift::XorShiftRng;
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        let mut cs = TestConstraintSystem::<Fq>::new();
+
+        // The generator is a constant equal to the native one
+        let g = G1Gadget::prime_subgroup_generator(cs.ns(|| "generator"));
+        assert_eq!(cs.num_aux(), 0);
+        assert_eq!(cs.num_constraints(), 0);
+        assert_eq!(g.get_value().unwrap(), G1::prime_subgroup_generator());
+        g.enforce_on_curve(cs.ns(|| "generator on curve")).unwrap();
+
+        // T + [r]G = T, i.e. [r]G is the identity
+        let t = G1::rand(&mut rng);
+        let gadget_t = G1Gadget::alloc(&mut cs.ns(|| "t"), || Ok(t)).unwrap();
+        let mut bits = BitIterator::new(<Fr as PrimeField>::Params::MODULUS)
+            .skip_while(|b| !b)
+            .collect::<Vec<_>>();
+        assert_eq!(bits.len(), 753);
+        bits.reverse();
+        let order = bits.into_iter().map(Boolean::constant).collect::<Vec<_>>();
+        let result = g
+            .mul_scalar_field_element(cs.ns(|| "t + [r]g"), &gadget_t, order.as_slice())
+            .unwrap();
+        result.enforce_equal(cs.ns(|| "t + [r]g == t"), &gadget_t).unwrap();
+        assert_eq!(result.get_value().unwrap(), t);
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn mnt4753_from_coordinates_test() {
+        use crate::fields::{fp::FpGadget, fp2::Fp2Gadget};
+        use algebra::{fields::mnt4753::Fq2Parameters, Field};
+
+        let mut cs = TestConstraintSystem::<Fq>::new();
+
+        // G1
+        let a: G1 = rand::random();
+        let a_affine = a.into_affine();
+        let x = FpGadget::<Fq>::alloc(cs.ns(|| "a.x"), || Ok(a_affine.x)).unwrap();
+        let y = FpGadget::<Fq>::alloc(cs.ns(|| "a.y"), || Ok(a_affine.y)).unwrap();
+
+        let (num_aux, num_constraints) = (cs.num_aux(), cs.num_constraints());
+        let gadget_a = G1Gadget::from_coordinates(x, y);
+        assert_eq!(cs.num_aux(), num_aux);
+        assert_eq!(cs.num_constraints(), num_constraints);
+        gadget_a.enforce_on_curve(cs.ns(|| "a on curve")).unwrap();
+        let from_coordinates_aux = cs.num_aux() - num_aux;
+
+        let num_aux = cs.num_aux();
+        let alloc_a = G1Gadget::alloc(cs.ns(|| "alloc a"), || Ok(a)).unwrap();
+        assert!(from_coordinates_aux < cs.num_aux() - num_aux);
+        assert_eq!(gadget_a.get_value().unwrap(), alloc_a.get_value().unwrap());
+        assert!(cs.is_satisfied());
+
+        // G2
+        let b: G2 = rand::random();
+        let b_affine = b.into_affine();
+        let x = Fp2Gadget::<Fq2Parameters, Fq>::alloc(cs.ns(|| "b.x"), || Ok(b_affine.x)).unwrap();
+        let y = Fp2Gadget::<Fq2Parameters, Fq>::alloc(cs.ns(|| "b.y"), || Ok(b_affine.y)).unwrap();
+
+        let num_aux = cs.num_aux();
+        let gadget_b = G2Gadget::from_coordinates(x, y);
+        assert_eq!(cs.num_aux(), num_aux);
+        gadget_b.enforce_on_curve(cs.ns(|| "b on curve")).unwrap();
+        assert_eq!(gadget_b.get_value().unwrap(), b);
+        assert!(cs.is_satisfied());
+
+        // A point off the curve is rejected
+        let x = FpGadget::<Fq>::alloc(cs.ns(|| "c.x"), || Ok(a_affine.x)).unwrap();
+        let y = FpGadget::<Fq>::alloc(cs.ns(|| "c.y"), || Ok(a_affine.y + &Fq::one())).unwrap();
+        G1Gadget::from_coordinates(x, y)
+            .enforce_on_curve(cs.ns(|| "c on curve"))
+            .unwrap();
+        assert!(!cs.is_satisfied());
+        assert_eq!(cs.which_is_unsatisfied().unwrap(), "c on curve/on curve check/mul_constraint");
+    }
+
     #[test]
     fn mnt4753_g2_gadget_test() {
         let mut cs = TestConstraintSystem::<Fq>::new();
@@ -203,4 +532,540 @@ mod test {
 
         assert!(cs.is_satisfied());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn mnt4753_g1_mul_bits_canonical_test() {
+        use algebra::{BigInteger, FpParameters, UniformRand};
+        use rand::SeedableRng;
+        use rand_xorshift::XorShiftRng;
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        let base = G1::rand(&mut rng);
+        let b = G1::rand(&mut rng);
+
+        // A scalar small enough such that also `scalar + modulus` fits into
+        // MODULUS_BITS bits.
+        let mut scalar_repr = Fr::rand(&mut rng).into_repr();
+        scalar_repr.0[11] = 0;
+        let scalar = Fr::from_repr(scalar_repr);
+        let mut non_canonical_repr = scalar_repr;
+        non_canonical_repr.add_nocarry(&<Fr as PrimeField>::Params::MODULUS);
+        let native_result = (base.into_affine().mul(scalar) + &b).into_affine();
+
+        let modulus_bits = <Fr as PrimeField>::Params::MODULUS_BITS as usize;
+        let to_le_bits = |repr: <Fr as PrimeField>::BigInt| {
+            let mut bits: Vec<bool> = BitIterator::new(repr).collect();
+            bits.reverse();
+            bits.truncate(modulus_bits);
+            bits
+        };
+        let canonical_bits = to_le_bits(scalar_repr);
+        let non_canonical_bits = to_le_bits(non_canonical_repr);
+        assert_ne!(canonical_bits, non_canonical_bits);
+
+        // `mul_bits` multiplies by the integer the bits represent, which gives the
+        // same result for the non-canonical representative of the scalar.
+        {
+            let mut cs = TestConstraintSystem::<Fq>::new();
+            let gadget_base = G1Gadget::alloc(&mut cs.ns(|| "base"), || Ok(base)).unwrap();
+            let gadget_b = G1Gadget::alloc(&mut cs.ns(|| "b"), || Ok(b)).unwrap();
+            for (i, bits) in [&canonical_bits, &non_canonical_bits].iter().enumerate() {
+                let input = Vec::<Boolean>::alloc(cs.ns(|| format!("Input {}", i)), || Ok(bits.to_vec()))
+                    .unwrap();
+                let result = gadget_base
+                    .mul_bits(cs.ns(|| format!("mul_bits {}", i)), &gadget_b, input.iter())
+                    .unwrap();
+                assert_eq!(result.get_value().unwrap().into_affine(), native_result);
+            }
+            assert!(cs.is_satisfied());
+        }
+
+        // `mul_bits_canonical` accepts the canonical bits only
+        for (bits, canonical) in vec![(canonical_bits, true), (non_canonical_bits, false)] {
+            let mut cs = TestConstraintSystem::<Fq>::new();
+            let gadget_base = G1Gadget::alloc(&mut cs.ns(|| "base"), || Ok(base)).unwrap();
+            let gadget_b = G1Gadget::alloc(&mut cs.ns(|| "b"), || Ok(b)).unwrap();
+            let input = Vec::<Boolean>::alloc(cs.ns(|| "Input"), || Ok(bits)).unwrap();
+            let result = gadget_base
+                .mul_bits_canonical(cs.ns(|| "mul_bits_canonical"), &gadget_b, &input)
+                .unwrap();
+            assert_eq!(result.get_value().unwrap().into_affine(), native_result);
+            assert_eq!(cs.is_satisfied(), canonical);
+            if !canonical {
+                assert!(cs
+                    .which_is_unsatisfied()
+                    .unwrap()
+                    .starts_with("mul_bits_canonical/enforce scalar in field"));
+            }
+        }
+    }
+
+    #[test]
+    fn mnt4753_g1_constant_costs() {
+        use algebra::UniformRand;
+        use rand::SeedableRng;
+        use rand_xorshift::XorShiftRng;
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        let a = G1::rand(&mut rng);
+        let b = G1::rand(&mut rng);
+        let scalar = Fr::rand(&mut rng);
+        let mut bits: Vec<bool> = BitIterator::new(scalar.into_repr()).collect();
+        bits.reverse();
+
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let gadget_a = G1Gadget::alloc(&mut cs.ns(|| "a"), || Ok(a)).unwrap();
+        let input = Vec::<Boolean>::alloc(cs.ns(|| "scalar"), || Ok(bits)).unwrap();
+
+        // Hardcoding a point allocates neither variables nor constraints,
+        // whereas allocating it costs the on-curve check.
+        let num_constraints = cs.num_constraints();
+        let num_aux = cs.num_aux();
+        let constant_b = G1Gadget::constant(&mut cs.ns(|| "constant b"), &b.into_affine());
+        assert_eq!(cs.num_constraints(), num_constraints);
+        assert_eq!(cs.num_aux(), num_aux);
+        assert_eq!(constant_b.get_constant(), b);
+
+        let gadget_b = G1Gadget::alloc(&mut cs.ns(|| "b"), || Ok(b)).unwrap();
+        assert!(cs.num_constraints() > num_constraints);
+
+        // Adding a constant operand costs the same as `add_constant`, and no
+        // more than adding a witness.
+        let num_constraints = cs.num_constraints();
+        let ab_witness = gadget_a.add(cs.ns(|| "a + b"), &gadget_b).unwrap();
+        let witness_add_cost = cs.num_constraints() - num_constraints;
+
+        let num_constraints = cs.num_constraints();
+        let ab_constant = gadget_a.add(cs.ns(|| "a + constant b"), &constant_b).unwrap();
+        let constant_add_cost = cs.num_constraints() - num_constraints;
+
+        let num_constraints = cs.num_constraints();
+        let _ = gadget_a.add_constant(cs.ns(|| "a + hardcoded b"), &b).unwrap();
+        let add_constant_cost = cs.num_constraints() - num_constraints;
+
+        assert_eq!(
+            ab_witness.get_value().unwrap().into_affine(),
+            ab_constant.get_value().unwrap().into_affine()
+        );
+        assert_eq!(constant_add_cost, add_constant_cost);
+        assert!(constant_add_cost <= witness_add_cost);
+
+        // Multiplying a constant base by a scalar via the fixed-base gadget is
+        // cheaper than the variable-base multiplication of the allocated point.
+        let num_constraints = cs.num_constraints();
+        let var_base = gadget_b
+            .mul_bits(cs.ns(|| "variable base mul"), &gadget_a, input.iter())
+            .unwrap();
+        let variable_base_cost = cs.num_constraints() - num_constraints;
+
+        let num_constraints = cs.num_constraints();
+        let fixed_base = G1Gadget::mul_bits_fixed_base(
+            &constant_b.get_constant(),
+            cs.ns(|| "fixed base mul"),
+            &gadget_a,
+            &input,
+        ).unwrap();
+        let fixed_base_cost = cs.num_constraints() - num_constraints;
+
+        assert_eq!(
+            var_base.get_value().unwrap().into_affine(),
+            fixed_base.get_value().unwrap().into_affine()
+        );
+        assert_eq!(
+            fixed_base.get_value().unwrap().into_affine(),
+            (b.into_affine().mul(scalar) + &a).into_affine()
+        );
+        assert!(fixed_base_cost < variable_base_cost);
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn mnt4753_g1_fixed_base_scalar_mul_test() {
+        use algebra::UniformRand;
+        use rand::SeedableRng;
+        use rand_xorshift::XorShiftRng;
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        for (i, base) in vec![G1::prime_subgroup_generator(), G1::rand(&mut rng)].into_iter().enumerate() {
+            let base = base.into_affine();
+            let scalar = Fr::rand(&mut rng);
+            let mut bits: Vec<bool> = BitIterator::new(scalar.into_repr()).collect();
+            bits.reverse();
+
+            let mut cs = TestConstraintSystem::<Fq>::new();
+            let input = Vec::<Boolean>::alloc(cs.ns(|| "scalar"), || Ok(bits)).unwrap();
+
+            let num_constraints = cs.num_constraints();
+            let result = G1Gadget::fixed_base_scalar_mul(
+                cs.ns(|| "fixed base mul"),
+                &base,
+                &input,
+            ).unwrap();
+            let fixed_base_cost = cs.num_constraints() - num_constraints;
+            assert_eq!(result.get_value().unwrap().into_affine(), base.mul(scalar).into_affine(), "base {}", i);
+            assert!(cs.is_satisfied());
+
+            // Variable base multiplication of the same point, allocated as witness
+            let offset = G1::rand(&mut rng);
+            let gadget_base = G1Gadget::alloc(cs.ns(|| "base"), || Ok(base.into_projective())).unwrap();
+            let gadget_offset = G1Gadget::alloc(cs.ns(|| "offset"), || Ok(offset)).unwrap();
+            let num_constraints = cs.num_constraints();
+            let _ = gadget_base
+                .mul_bits(cs.ns(|| "variable base mul"), &gadget_offset, input.iter())
+                .unwrap();
+            let variable_base_cost = cs.num_constraints() - num_constraints;
+            assert!(cs.is_satisfied());
+
+            assert!(
+                2 * fixed_base_cost < variable_base_cost,
+                "fixed base: {}, variable base: {}", fixed_base_cost, variable_base_cost
+            );
+        }
+    }
+
+    #[test]
+    fn mnt4753_g1_mul_bits_of_difference_test() {
+        use algebra::UniformRand;
+        use rand::SeedableRng;
+        use rand_xorshift::XorShiftRng;
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        let g = G1::rand(&mut rng).into_affine();
+        let h = G1::rand(&mut rng).into_affine();
+        let scalar = Fr::rand(&mut rng);
+        let mut bits: Vec<bool> = BitIterator::new(scalar.into_repr()).collect();
+        bits.reverse();
+
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let input = Vec::<Boolean>::alloc(cs.ns(|| "scalar"), || Ok(bits)).unwrap();
+
+        let num_constraints = cs.num_constraints();
+        let fused = G1Gadget::mul_bits_of_difference(cs.ns(|| "s * (g - h)"), &g, &h, &input).unwrap();
+        let fused_cost = cs.num_constraints() - num_constraints;
+        assert_eq!(
+            fused.get_value().unwrap().into_affine(),
+            (g.into_projective() - &h.into_projective()).into_affine().mul(scalar).into_affine()
+        );
+
+        // Naive approach: two scalar multiplications and a subtraction
+        let num_constraints = cs.num_constraints();
+        let s_g = G1Gadget::fixed_base_scalar_mul(cs.ns(|| "s * g"), &g, &input).unwrap();
+        let s_h = G1Gadget::fixed_base_scalar_mul(cs.ns(|| "s * h"), &h, &input).unwrap();
+        let naive = s_g.sub(cs.ns(|| "s * g - s * h"), &s_h).unwrap();
+        let naive_cost = cs.num_constraints() - num_constraints;
+        assert_eq!(
+            fused.get_value().unwrap().into_affine(),
+            naive.get_value().unwrap().into_affine()
+        );
+        assert!(2 * fused_cost <= naive_cost, "fused: {}, naive: {}", fused_cost, naive_cost);
+
+        // g == h gives the neutral element
+        let zero = G1Gadget::mul_bits_of_difference(cs.ns(|| "s * (g - g)"), &g, &g, &input).unwrap();
+        assert!(zero.get_value().unwrap().is_zero());
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn mnt4753_g1_mul_bits_trace_test() {
+        use algebra::UniformRand;
+        use rand::SeedableRng;
+        use rand_xorshift::XorShiftRng;
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        let base = G1::rand(&mut rng);
+        let offset = G1::rand(&mut rng);
+        let scalar = Fr::rand(&mut rng);
+        let mut bits: Vec<bool> = BitIterator::new(scalar.into_repr()).collect();
+        bits.reverse();
+
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let base_g = G1Gadget::alloc(cs.ns(|| "alloc base"), || Ok(base)).unwrap();
+        let offset_g = G1Gadget::alloc(cs.ns(|| "alloc offset"), || Ok(offset)).unwrap();
+        let input = Vec::<Boolean>::alloc(cs.ns(|| "scalar"), || Ok(bits.clone())).unwrap();
+
+        let num_constraints = cs.num_constraints();
+        let (result, trace) = base_g.mul_bits_trace(cs.ns(|| "trace"), &offset_g, input.iter()).unwrap();
+        let trace_cost = cs.num_constraints() - num_constraints;
+
+        let num_constraints = cs.num_constraints();
+        let expected = base_g.mul_bits(cs.ns(|| "mul_bits"), &offset_g, input.iter()).unwrap();
+        assert_eq!(trace_cost, cs.num_constraints() - num_constraints);
+        assert!(cs.is_satisfied());
+
+        assert_eq!(result.get_value().unwrap().into_affine(), expected.get_value().unwrap().into_affine());
+        assert_eq!(result.get_value().unwrap().into_affine(), (offset + &base.into_affine().mul(scalar)).into_affine());
+        assert_eq!(trace.len(), bits.len());
+        assert_eq!(trace.last().unwrap().get_value(), result.get_value());
+
+        // Each step adds the current power of `base` if the bit is set
+        let mut prev = offset;
+        let mut power = base;
+        for (step, bit) in trace.iter().zip(bits.iter()) {
+            if *bit {
+                prev += &power;
+            }
+            assert_eq!(step.get_value().unwrap().into_affine(), prev.into_affine());
+            power.double_in_place();
+        }
+    }
+
+    #[test]
+    fn mnt4753_g1_shared_base_table_test() {
+        use algebra::UniformRand;
+        use rand::SeedableRng;
+        use rand_xorshift::XorShiftRng;
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+        const NUM_SCALARS: usize = 3;
+
+        let base = G1::rand(&mut rng);
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let scalars = (0..NUM_SCALARS).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let inputs = scalars.iter().enumerate().map(|(i, scalar)| {
+            // The table covers the bit length of the scalar field only
+            let mut bits: Vec<bool> = BitIterator::new(scalar.into_repr()).collect();
+            bits.reverse();
+            bits.truncate(Fr::size_in_bits());
+            Vec::<Boolean>::alloc(cs.ns(|| format!("scalar {}", i)), || Ok(bits)).unwrap()
+        }).collect::<Vec<_>>();
+
+        // The table consists of constants only
+        let num_constraints = cs.num_constraints();
+        let table = G1Gadget::precompute_base_table(cs.ns(|| "table"), &base, 3).unwrap();
+        assert_eq!(cs.num_constraints(), num_constraints);
+
+        // Shared table for all the scalars
+        for (i, (scalar, input)) in scalars.iter().zip(inputs.iter()).enumerate() {
+            let result = G1Gadget::scalar_mul_with_precomputed_table(
+                cs.ns(|| format!("shared table mul {}", i)),
+                &table,
+                input,
+            ).unwrap();
+            assert_eq!(result.get_value().unwrap().into_affine(), base.into_affine().mul(*scalar).into_affine());
+        }
+        let shared_table_cost = cs.num_constraints() - num_constraints;
+        assert!(cs.is_satisfied());
+
+        // Independent windowed multiplications, each of them computing the multiples of
+        // the base, allocated as witness, in the circuit
+        let offset = G1::rand(&mut rng);
+        let gadget_offset = G1Gadget::alloc(cs.ns(|| "offset"), || Ok(offset)).unwrap();
+        let num_constraints = cs.num_constraints();
+        for (i, input) in inputs.iter().enumerate() {
+            let gadget_base = G1Gadget::alloc(cs.ns(|| format!("base {}", i)), || Ok(base)).unwrap();
+            let _ = gadget_base
+                .mul_bits(cs.ns(|| format!("variable base mul {}", i)), &gadget_offset, input.iter())
+                .unwrap();
+        }
+        let independent_cost = cs.num_constraints() - num_constraints;
+        assert!(cs.is_satisfied());
+
+        assert!(
+            shared_table_cost < independent_cost,
+            "shared table: {}, independent: {}", shared_table_cost, independent_cost
+        );
+
+        // Short scalars use only the first rows of the table
+        let short = Vec::<Boolean>::alloc(cs.ns(|| "short scalar"), || Ok(vec![true, false, true, true])).unwrap();
+        let result = G1Gadget::scalar_mul_with_precomputed_table(cs.ns(|| "short mul"), &table, &short).unwrap();
+        assert_eq!(result.get_value().unwrap().into_affine(), base.into_affine().mul(Fr::from(13u64)).into_affine());
+        assert!(cs.is_satisfied());
+
+        // Scalars for which the lookups of different windows coincide: the low windows
+        // of small multiples of 2^(2w) are zero, and 2^(2w+1) - 1 = 7 + 7 * 2^3 + 2^6
+        // carries through the first windows.
+        for (i, scalar) in [1u64 << 6, 3 << 6, 1 << 9, 5 << 12, (1 << 7) - 1].iter().enumerate() {
+            let bits = (0..64).map(|k| (scalar >> k) & 1 == 1).collect::<Vec<_>>();
+            let input = Vec::<Boolean>::alloc(cs.ns(|| format!("scalar {}", scalar)), || Ok(bits)).unwrap();
+            let result = G1Gadget::scalar_mul_with_precomputed_table(
+                cs.ns(|| format!("colliding windows mul {}", i)),
+                &table,
+                &input,
+            ).unwrap();
+            assert_eq!(result.get_value().unwrap().into_affine(), base.into_affine().mul(Fr::from(*scalar)).into_affine());
+        }
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn mnt4753_g1_shared_base_table_zero_scalar_test() {
+        use algebra::UniformRand;
+        use rand::SeedableRng;
+        use rand_xorshift::XorShiftRng;
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        // The result is the neutral element, which incomplete additions cannot reach
+        let base = G1::rand(&mut rng);
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let table = G1Gadget::precompute_base_table(cs.ns(|| "table"), &base, 3).unwrap();
+        let zero = Vec::<Boolean>::alloc(cs.ns(|| "zero scalar"), || Ok(vec![false; 12])).unwrap();
+        assert!(G1Gadget::scalar_mul_with_precomputed_table(cs.ns(|| "zero mul"), &table, &zero).is_err());
+    }
+
+    #[test]
+    fn mnt4753_alloc_checked_test() {
+        use algebra::{
+            curves::mnt4753::g2::MNT4G2Parameters, fields::mnt4753::Fq2,
+            Field, SquareRootField, SWModelParameters, UniformRand,
+        };
+        use rand::SeedableRng;
+        use rand_xorshift::XorShiftRng;
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        // Valid points are accepted
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let _ = G1Gadget::alloc_checked(cs.ns(|| "alloc g1"), || Ok(G1::rand(&mut rng))).unwrap();
+        let _ = G2Gadget::alloc_checked(cs.ns(|| "alloc g2"), || Ok(G2::rand(&mut rng))).unwrap();
+        assert!(cs.is_satisfied());
+
+        // A point off the curve
+        let p = G1::rand(&mut rng).into_affine();
+        let off_curve = G1::new(p.x, p.y.double(), Fq::one());
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let _ = G1Gadget::alloc_checked(cs.ns(|| "alloc off curve"), || Ok(off_curve)).unwrap();
+        assert!(!cs.is_satisfied());
+        assert_eq!(
+            cs.which_is_unsatisfied().unwrap(),
+            "alloc off curve/alloc and prime order check/Alloc checked/on curve check/mul_constraint"
+        );
+
+        let q = G2::rand(&mut rng).into_affine();
+        let off_curve = G2::new(q.x, q.y.double(), Fq2::one());
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let _ = G2Gadget::alloc_checked(cs.ns(|| "alloc off curve"), || Ok(off_curve)).unwrap();
+        assert!(!cs.is_satisfied());
+        assert!(cs.which_is_unsatisfied().unwrap()
+            .starts_with("alloc off curve/alloc and prime order check/Alloc checked/on curve check/"));
+
+        // A point on the twist which is (with overwhelming probability) outside the
+        // prime order subgroup: it passes the curve equation, but not the subgroup check.
+        let not_in_subgroup = loop {
+            let x = Fq2::rand(&mut rng);
+            let rhs = x.square() * &x + &(MNT4G2Parameters::COEFF_A * &x) + &MNT4G2Parameters::COEFF_B;
+            if let Some(y) = rhs.sqrt() {
+                break G2::new(x, y, Fq2::one());
+            }
+        };
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let _ = G2Gadget::alloc(cs.ns(|| "alloc"), || Ok(not_in_subgroup)).unwrap();
+        assert!(cs.is_satisfied());
+        let checked = G2Gadget::alloc_checked(cs.ns(|| "alloc checked"), || Ok(not_in_subgroup)).unwrap();
+        // The cofactor of G2 has a larger Hamming weight than r - 1, hence the subgroup
+        // check computes [r - 1] * P and enforces it to be -P, which fails on the
+        // x-coordinate, whereas the curve equation holds.
+        assert_eq!(checked.get_value().unwrap(), not_in_subgroup);
+        assert!(!cs.is_satisfied());
+        assert!(cs.which_is_unsatisfied().unwrap()
+            .starts_with("alloc checked/alloc and prime order check/Check equals/X Coordinate Conditional Equality"));
+    }
+
+    #[test]
+    fn mnt4753_conditional_negate_test() {
+        use crate::fields::mnt4753::FqGadget;
+        use algebra::UniformRand;
+        use rand::SeedableRng;
+        use rand_xorshift::XorShiftRng;
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        let mut cs = TestConstraintSystem::<Fq>::new();
+
+        let p = G1::rand(&mut rng);
+        let q = G2::rand(&mut rng);
+        let p_g = G1Gadget::alloc(cs.ns(|| "alloc p"), || Ok(p)).unwrap();
+        let q_g = G2Gadget::alloc(cs.ns(|| "alloc q"), || Ok(q)).unwrap();
+
+        // negate matches the native negation
+        let neg_p = p_g.negate(cs.ns(|| "-p")).unwrap();
+        let neg_q = q_g.negate(cs.ns(|| "-q")).unwrap();
+        assert_eq!(neg_p.get_value().unwrap(), -p);
+        assert_eq!(neg_q.get_value().unwrap(), -q);
+
+        for &negate in [false, true].iter() {
+            let cond = Boolean::alloc(cs.ns(|| format!("cond {}", negate)), || Ok(negate)).unwrap();
+
+            let num_constraints = cs.num_constraints();
+            let p_cond = p_g.conditional_negate(cs.ns(|| format!("cond negate p {}", negate)), &cond).unwrap();
+            assert_eq!(cs.num_constraints() - num_constraints, <FqGadget as CondSelectGadget<Fq>>::cost());
+            let q_cond = q_g.conditional_negate(cs.ns(|| format!("cond negate q {}", negate)), &cond).unwrap();
+
+            let (expected_p, expected_q) = if negate { (neg_p.clone(), neg_q.clone()) } else { (p_g.clone(), q_g.clone()) };
+            assert_eq!(p_cond.get_value().unwrap(), expected_p.get_value().unwrap());
+            assert_eq!(q_cond.get_value().unwrap(), expected_q.get_value().unwrap());
+            p_cond.enforce_equal(cs.ns(|| format!("check p {}", negate)), &expected_p).unwrap();
+            q_cond.enforce_equal(cs.ns(|| format!("check q {}", negate)), &expected_q).unwrap();
+        }
+
+        // A constant condition costs nothing
+        let num_constraints = cs.num_constraints();
+        let p_same = p_g.conditional_negate(cs.ns(|| "constant false"), &Boolean::constant(false)).unwrap();
+        assert_eq!(cs.num_constraints(), num_constraints);
+        assert_eq!(p_same.get_value().unwrap(), p);
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn mnt4753_double_test() {
+        use algebra::{curves::mnt4753::g2::MNT4G2Parameters, Field, SWModelParameters, UniformRand};
+        use rand::SeedableRng;
+        use rand_xorshift::XorShiftRng;
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        for i in 0..5 {
+            let p = G1::rand(&mut rng);
+            let q = G2::rand(&mut rng);
+            let p_g = G1Gadget::alloc(cs.ns(|| format!("alloc p {}", i)), || Ok(p)).unwrap();
+            let q_g = G2Gadget::alloc(cs.ns(|| format!("alloc q {}", i)), || Ok(q)).unwrap();
+
+            let num_constraints = cs.num_constraints();
+            let p_double = p_g.double(cs.ns(|| format!("double p {}", i))).unwrap();
+            let double_cost = cs.num_constraints() - num_constraints;
+            let (q_double, slope) = q_g.double_with_slope(cs.ns(|| format!("double q {}", i))).unwrap();
+
+            assert_eq!(p_double.get_value().unwrap().into_affine(), p.double().into_affine());
+            assert_eq!(q_double.get_value().unwrap().into_affine(), q.double().into_affine());
+
+            // The slope is the one of the tangent at q
+            let q = q.into_affine();
+            let expected_slope = (q.x.square().double() + &q.x.square() + &MNT4G2Parameters::COEFF_A) * &q.y.double().inverse().unwrap();
+            assert_eq!(slope.get_value().unwrap(), expected_slope);
+
+            // Same result as double_in_place, which costs the same as double_with_slope,
+            // while double pays on top for handling the points of order two
+            let mut p_double_in_place = p_g.clone();
+            let num_constraints = cs.num_constraints();
+            p_double_in_place.double_in_place(cs.ns(|| format!("double in place p {}", i))).unwrap();
+            assert_eq!(cs.num_constraints() - num_constraints, G1Gadget::cost_of_double());
+            assert!(double_cost > G1Gadget::cost_of_double());
+            p_double.enforce_equal(cs.ns(|| format!("check p {}", i)), &p_double_in_place).unwrap();
+        }
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn mnt4753_double_y_zero_test() {
+        use algebra::{curves::mnt4753::G1Affine, Field};
+
+        // G1 has prime order, hence no point of order two: the flag for y = 0 is
+        // exercised on a witness (x, 0) off the curve.
+        let a = G1Affine::new(rand::random(), Fq::zero(), false);
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let gadget_a = G1Gadget::alloc_without_check(&mut cs.ns(|| "a"), || Ok(a.into_projective())).unwrap();
+        let two_a = gadget_a.double(&mut cs.ns(|| "2a")).unwrap();
+        assert!(cs.is_satisfied());
+        assert!(two_a.infinity.get_value().unwrap());
+        assert!(two_a.get_value().unwrap().is_zero());
+
+        // A point on the curve is doubled as usual, and cannot be flagged as of order two
+        let b: G1 = rand::random();
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let gadget_b = G1Gadget::alloc(&mut cs.ns(|| "b"), || Ok(b)).unwrap();
+        let two_b = gadget_b.double(&mut cs.ns(|| "2b")).unwrap();
+        assert!(cs.is_satisfied());
+        assert!(!two_b.infinity.get_value().unwrap());
+        assert_eq!(two_b.get_value().unwrap(), b.double());
+
+        cs.set("2b/y is zero/boolean", Fq::one());
+        assert!(!cs.is_satisfied());
+        assert_eq!(cs.which_is_unsatisfied().unwrap(), "2b/y = 0 if y is zero/conditional_equals");
+    }
+}
@@ -3,11 +3,12 @@ use algebra::Field;
 use crate::{fields::{
     FieldGadget, fp::FpGadget, fp3::Fp3Gadget,
 }, groups::curves::short_weierstrass::short_weierstrass_projective::AffineGadget,
-    bits::ToBytesGadget, alloc::AllocGadget,
+    bits::ToBytesGadget, alloc::{AllocGadget, ConstantGadget},
             bits::uint8::UInt8, Assignment};
 
 use r1cs_core::{ConstraintSystem, SynthesisError};
-use algebra::curves::models::mnt6::MNT6Parameters;
+use algebra::curves::models::mnt6::{MNT6Parameters, G1Prepared, G2Prepared, g2::G2PreparedCoefficients};
+use algebra::{AffineCurve, ProjectiveCurve};
 
 use std::fmt::Debug;
 use std::ops::Mul;
@@ -61,6 +62,25 @@ impl<P: MNT6Parameters> G1PreparedGadget<P> {
     }
 }
 
+impl<P: MNT6Parameters> ConstantGadget<G1Prepared<P>, P::Fp> for G1PreparedGadget<P> {
+    fn from_value<CS: ConstraintSystem<P::Fp>>(
+        mut cs: CS,
+        value: &G1Prepared<P>,
+    ) -> Self
+    {
+        let p = G1Gadget::<P>::from_value(cs.ns(|| "hardcode p"), &value.p.into_projective());
+        let p_y_twist_squared = Fp3G::<P>::from_value(cs.ns(|| "hardcode p_y_twist_squared"), &value.py_twist_squared);
+        G1PreparedGadget{p, p_y_twist_squared}
+    }
+
+    fn get_constant(&self) -> G1Prepared<P> {
+        G1Prepared{
+            p: self.p.get_constant().into_affine(),
+            py_twist_squared: self.p_y_twist_squared.get_constant(),
+        }
+    }
+}
+
 impl<P: MNT6Parameters> ToBytesGadget<P::Fp> for G1PreparedGadget<P> {
     #[inline]
     fn to_bytes<CS: ConstraintSystem<P::Fp>>(&self, mut cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
@@ -148,38 +168,13 @@ impl<P: MNT6Parameters>G2PreparedGadget<P> {
         s: &G2Gadget<P>,
     ) -> Result<(G2Gadget<P>, G2CoefficientsGadget<P>), SynthesisError>
     {
-        //Compute gamma
-        let s_x_squared = s.x.square(cs.ns(||"s_x^2"))?;
-        let three_sx_squared_plus_a = s_x_squared
-            .double(cs.ns(|| "2s_x^2"))?
-            .add(cs.ns(|| "3s_x^2"), &s_x_squared)?
-            .add_constant(cs.ns(|| "3s_x^2 + a"), &P::TWIST_COEFF_A)?;
-
-        let two_sy = s.y.double(cs.ns(||"2s_y"))?;
-
-        let gamma = Fp3G::<P>::alloc(cs.ns(|| "gamma"), || {
-            Ok(three_sx_squared_plus_a.get_value().get()?.mul(&two_sy.get_value().get()?.inverse().get()?))
-        })?;
-
-        //Check gamma (gamma*2s_y = 3sx^2 + a)
-        gamma.mul_equals(cs.ns(|| "Check gamma"), &two_sy, &three_sx_squared_plus_a)?;
+        //Compute the tangent line doubling, with slope gamma
+        let (s2, gamma) = s.double_with_slope(cs.ns(|| "double s"))?;
 
         //Compute and check gamma_x
         let gamma_x = gamma.mul(cs.ns(|| "Compute gamma_x"), &s.x)?;
 
-        //Compute new_sx
-        let two_sx = s.x.double(cs.ns(|| "2s_x"))?;
-        let new_sx = gamma.square(cs.ns(|| "gamma^2"))?
-            .sub(cs.ns(|| "gamma^2 - 2s_x"), &two_sx)?;
-
-        //Compute and check new_sy
-        let new_sy = s.x
-            .sub(cs.ns(|| "s_x - new_s_x"), &new_sx)?
-            .mul(cs.ns(|| "gamma * (s_x - new_s_x)"), &gamma)?
-            .sub(cs.ns(|| "gamma * (s_x - new_s_x) - s_y"), &s.y)?;
-
         let c = G2CoefficientsGadget{r_y: s.y.clone(), gamma, gamma_x};
-        let s2 = G2Gadget::<P>::new(new_sx, new_sy, Boolean::constant(false));
 
         Ok((s2, c))
     }
@@ -198,6 +193,9 @@ impl<P: MNT6Parameters>G2PreparedGadget<P> {
 
         let sy_plus_y = s.y.add(cs.ns(||"(s_y + y)"), &y)?;
         let sy_minus_y = s.y.sub(cs.ns(|| "(s_y - y)"), &y)?;
+        // For naf_i < 0 the added point is -Q = (x, -y): only the sign of y changes,
+        // hence the numerator of the slope, whereas gamma_x = gamma * x stays the same.
+        // The line through -Q is evaluated with -y in the Miller loop.
         let numerator = if naf_i > 0 {sy_minus_y} else {sy_plus_y};
 
         let gamma = Fp3G::<P>::alloc(cs.ns(|| "Compute gamma"), || {
@@ -229,6 +227,40 @@ impl<P: MNT6Parameters>G2PreparedGadget<P> {
     }
 }
 
+impl<P: MNT6Parameters> ConstantGadget<G2Prepared<P>, P::Fp> for G2PreparedGadget<P> {
+    /// Hardcodes a natively prepared point. As `from_affine` walks through `P::WNAF`
+    /// exactly like `G2Prepared::from_affine`, the result can be used in place of the
+    /// in-circuit preparation of the same point.
+    fn from_value<CS: ConstraintSystem<P::Fp>>(
+        mut cs: CS,
+        value: &G2Prepared<P>,
+    ) -> Self
+    {
+        let q = G2Gadget::<P>::from_value(cs.ns(|| "hardcode q"), &value.q.into_projective());
+        let coeffs = value.coeffs.iter().enumerate().map(|(i, c)| {
+            G2CoefficientsGadget{
+                r_y: Fp3G::<P>::from_value(cs.ns(|| format!("hardcode r_y {}", i)), &c.r_y),
+                gamma: Fp3G::<P>::from_value(cs.ns(|| format!("hardcode gamma {}", i)), &c.gamma),
+                gamma_x: Fp3G::<P>::from_value(cs.ns(|| format!("hardcode gamma_x {}", i)), &c.gamma_x),
+            }
+        }).collect();
+        G2PreparedGadget{q, coeffs}
+    }
+
+    fn get_constant(&self) -> G2Prepared<P> {
+        G2Prepared{
+            q: self.q.get_constant().into_affine(),
+            coeffs: self.coeffs.iter().map(|c| {
+                G2PreparedCoefficients{
+                    r_y: c.r_y.get_constant(),
+                    gamma: c.gamma.get_constant(),
+                    gamma_x: c.gamma_x.get_constant(),
+                }
+            }).collect(),
+        }
+    }
+}
+
 impl<P: MNT6Parameters> ToBytesGadget<P::Fp> for G2PreparedGadget<P>
 {
     #[inline]
@@ -38,6 +38,39 @@ impl<P, ConstraintF, F> AffineGadget<P, ConstraintF, F>
             _engine: PhantomData,
         }
     }
+
+    /// Wraps already existing coordinate gadgets into a non-zero affine point,
+    /// without allocating any new variable. The point is not checked to be on
+    /// the curve: use `enforce_on_curve` if needed.
+    pub fn from_coordinates(x: F, y: F) -> Self {
+        Self::new(x, y, Boolean::constant(false))
+    }
+
+    /// Enforces that `(self.x, self.y)` satisfies the curve equation
+    /// y^2 = x^3 + a*x + b.
+    pub fn enforce_on_curve<CS: ConstraintSystem<ConstraintF>>(
+        &self,
+        mut cs: CS,
+    ) -> Result<(), SynthesisError> {
+        // We do this by checking that y^2 - b = x * (x^2 +a)
+        let x2 = self.x.square(&mut cs.ns(|| "x^2"))?;
+        let y2 = self.y.square(&mut cs.ns(|| "y^2"))?;
+
+        let x2_plus_a = x2.add_constant(cs.ns(|| "x^2 + a"), &P::COEFF_A)?;
+        let y2_minus_b = y2.add_constant(cs.ns(|| "y^2 - b"), &P::COEFF_B.neg())?;
+
+        x2_plus_a.mul_equals(cs.ns(|| "on curve check"), &self.x, &y2_minus_b)?;
+        Ok(())
+    }
+
+    /// Returns the generator of the prime order subgroup, as given by
+    /// `P::AFFINE_GENERATOR_COEFFS`, hardcoded as a constant.
+    pub fn prime_subgroup_generator<CS: ConstraintSystem<ConstraintF>>(mut cs: CS) -> Self {
+        let (x, y) = P::AFFINE_GENERATOR_COEFFS;
+        let x = F::from_value(cs.ns(|| "hardcode x"), &x);
+        let y = F::from_value(cs.ns(|| "hardcode y"), &y);
+        Self::from_coordinates(x, y)
+    }
 }
 
 impl<P, ConstraintF, F> PartialEq for AffineGadget<P, ConstraintF, F>
@@ -256,12 +289,20 @@ for AffineGadget<P, ConstraintF, F>
 
         let two_y = self.y.double(cs.ns(|| "2y"))?;
 
-        let lambda = F::alloc(cs.ns(|| "lambda"), || {
-            let y_doubled_inv = two_y.get_value().get()?.inverse().get()?;
-            Ok(three_x_squared_plus_a.get_value().get()? * &y_doubled_inv)
+        // If y = 0 the relation lambda * 2y = 3x^2 + a is satisfied by any lambda
+        // as soon as 3x^2 + a = 0 (which cannot happen for a point on the curve,
+        // but may happen for an unchecked witness), so that the result would be
+        // arbitrary. Enforcing 2y to be invertible rules out such a case, as well
+        // as the points of order two, whose double is the neutral element.
+        let two_y_inv = F::alloc(cs.ns(|| "2y inverse"), || {
+            two_y.get_value().get()?.inverse().get()
         })?;
+        let one = F::one(cs.ns(|| "one"))?;
+        two_y_inv.mul_equals(cs.ns(|| "check 2y inverse"), &two_y, &one)?;
 
-        // Check lambda
+        let lambda = F::alloc(cs.ns(|| "lambda"), || {
+            Ok(three_x_squared_plus_a.get_value().get()? * &two_y_inv.get_value().get()?)
+        })?;
         lambda.mul_equals(cs.ns(|| "check lambda"), &two_y, &three_x_squared_plus_a)?;
 
         let x = lambda
@@ -295,7 +336,7 @@ for AffineGadget<P, ConstraintF, F>
     }
 
     fn cost_of_double() -> usize {
-        3 * F::cost_of_mul() + F::cost_of_mul_equals()
+        3 * F::cost_of_mul() + 2 * F::cost_of_mul_equals()
     }
 }
 
@@ -39,6 +39,51 @@ impl<P, ConstraintF, F> AffineGadget<P, ConstraintF, F>
         }
     }
 
+    /// Wraps already existing coordinate gadgets into a non-zero affine point,
+    /// without allocating any new variable. The point is not checked to be on
+    /// the curve: use `enforce_on_curve` if needed.
+    pub fn from_coordinates(x: F, y: F) -> Self {
+        Self::new(x, y, Boolean::constant(false))
+    }
+
+    /// Enforces that `(self.x, self.y)` satisfies the curve equation
+    /// y^2 = x^3 + a*x + b.
+    pub fn enforce_on_curve<CS: ConstraintSystem<ConstraintF>>(
+        &self,
+        mut cs: CS,
+    ) -> Result<(), SynthesisError> {
+        // We do this by checking that y^2 - b = x * (x^2 +a)
+        let x2 = self.x.square(&mut cs.ns(|| "x^2"))?;
+        let y2 = self.y.square(&mut cs.ns(|| "y^2"))?;
+
+        let x2_plus_a = x2.add_constant(cs.ns(|| "x^2 + a"), &P::COEFF_A)?;
+        let y2_minus_b = y2.add_constant(cs.ns(|| "y^2 - b"), &P::COEFF_B.neg())?;
+
+        x2_plus_a.mul_equals(cs.ns(|| "on curve check"), &self.x, &y2_minus_b)?;
+        Ok(())
+    }
+
+    /// Returns the generator of the prime order subgroup, as given by
+    /// `P::AFFINE_GENERATOR_COEFFS`, hardcoded as a constant.
+    pub fn prime_subgroup_generator<CS: ConstraintSystem<ConstraintF>>(mut cs: CS) -> Self {
+        let (x, y) = P::AFFINE_GENERATOR_COEFFS;
+        let x = F::from_value(cs.ns(|| "hardcode x"), &x);
+        let y = F::from_value(cs.ns(|| "hardcode y"), &y);
+        Self::from_coordinates(x, y)
+    }
+
+    /// Hardcodes `value` into the circuit: the coordinates are constant linear
+    /// combinations, hence no variables are allocated and no on-curve check is
+    /// needed. Meant for fixed generators and verifying key elements. Note that
+    /// additions with a constant operand cost the same as `add_constant`, while
+    /// scalar multiplications of a constant point should go through
+    /// `mul_bits_fixed_base`.
+    pub fn constant<CS: ConstraintSystem<ConstraintF>>(mut cs: CS, value: &SWAffine<P>) -> Self {
+        let x = F::from_value(cs.ns(|| "hardcode x"), &value.x);
+        let y = F::from_value(cs.ns(|| "hardcode y"), &value.y);
+        Self::new(x, y, Boolean::constant(value.infinity))
+    }
+
     #[inline]
     /// Incomplete addition: neither `self` nor `other` can be the neutral
     /// element.
@@ -99,6 +144,432 @@ impl<P, ConstraintF, F> AffineGadget<P, ConstraintF, F>
 
         Ok(Self::new(x_3, y_3, Boolean::Constant(false)))
     }
+
+    #[inline]
+    /// Enforce equality of the x and y coordinates only, skipping the check
+    /// on the infinity flag. Sound only if neither `self` nor `other` can be
+    /// the neutral element.
+    pub fn enforce_equal_affine_nonzero<CS: ConstraintSystem<ConstraintF>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+    ) -> Result<(), SynthesisError> {
+        self.x.enforce_equal(&mut cs.ns(|| "X Coordinate Equality"), &other.x)?;
+        self.y.enforce_equal(&mut cs.ns(|| "Y Coordinate Equality"), &other.y)?;
+        Ok(())
+    }
+
+    /// Precompute, as constant gadgets, the window table of the fixed point `base`
+    /// for windows of `w >= 2` bits: the i-th row contains `(j + 2) * 2^(w*i) * base`
+    /// for `j` in `0..2^w`. No variable or constraint is allocated, hence the table can
+    /// be computed once and reused for several scalars in the same circuit.
+    /// The entries are consumed by incomplete additions, which are unsatisfiable as
+    /// soon as the two summands have the same x-coordinate. The offset `2 * 2^(w*i)`
+    /// of each row is such that the sum of the entries looked up in the previous rows,
+    /// which is at most `(2^w + 1) * (2^(w*i) - 1)/(2^w - 1) < 2 * 2^(w*i)` times `base`,
+    /// is smaller than any entry of the i-th row. Hence the partial sums can't collide
+    /// (up to sign) with the entry looked up next, unless they wrap around the order of
+    /// `base`, which can only happen in the last rows of full length scalars: in such
+    /// (negligibly many) cases `Unsatisfiable` is returned when summing up the entries.
+    pub fn precompute_gadget_window_table<CS: ConstraintSystem<ConstraintF>>(
+        cs: CS,
+        base: &SWProjective<P>,
+        w: usize,
+    ) -> Result<Vec<Vec<Self>>, SynthesisError> {
+        <Self as GroupGadget<SWProjective<P>, ConstraintF>>::precompute_base_table(cs, base, w)
+    }
+
+    /// Select `table[i]`, where `i` is the integer represented by `bits`
+    /// in *little-endian* form. `table` must have exactly `2^bits.len()`
+    /// entries, none of which is the neutral element.
+    pub fn select_constant<CS: ConstraintSystem<ConstraintF>>(
+        mut cs: CS,
+        table: &[Self],
+        bits: &[Boolean],
+    ) -> Result<Self, SynthesisError> {
+        if bits.len() >= 32 || table.len() != 1 << bits.len() {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        // Binary tree of selections, starting from the most significant bit
+        let mut candidates = table.to_vec();
+        for (i, bit) in bits.iter().enumerate().rev() {
+            let half = candidates.len()/2;
+            let (low, high) = candidates.split_at(half);
+            candidates = low.iter()
+                .zip(high.iter())
+                .enumerate()
+                .map(|(j, (l, h))| {
+                    let mut cs = cs.ns(|| format!("select bit {} entry {}", i, j));
+                    let x = F::conditionally_select(cs.ns(|| "x"), bit, &h.x, &l.x)?;
+                    let y = F::conditionally_select(cs.ns(|| "y"), bit, &h.y, &l.y)?;
+                    Ok(Self::new(x, y, Boolean::constant(false)))
+                })
+                .collect::<Result<Vec<_>, SynthesisError>>()?;
+        }
+        Ok(candidates.pop().unwrap())
+    }
+
+    /// Incomplete additions are unsatisfiable if the summands have the same x-coordinate:
+    /// if the witnesses are known, detect it here instead of failing while computing the
+    /// inverse of their difference.
+    fn check_distinct_x(
+        x1: Option<P::BaseField>,
+        x2: Option<P::BaseField>,
+    ) -> Result<(), SynthesisError> {
+        match (x1, x2) {
+            (Some(x1), Some(x2)) if x1 == x2 => Err(SynthesisError::Unsatisfiable),
+            _ => Ok(()),
+        }
+    }
+
+    /// Sums up the entries of the window table `table` (see `precompute_gadget_window_table`)
+    /// looked up by the windows of `bits`, starting from the entry of the first row.
+    /// Returns the sum, i.e. `scalar * base` plus the offsets of the rows used, together
+    /// with the sum of these offsets.
+    fn window_table_sum<CS: ConstraintSystem<ConstraintF>>(
+        mut cs: CS,
+        table: &[Vec<Self>],
+        bits: &[Boolean],
+    ) -> Result<(Self, SWProjective<P>), SynthesisError> {
+        if table.is_empty() || !table[0].len().is_power_of_two() || table[0].len() < 4 {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+        let w = table[0].len().trailing_zeros() as usize;
+        if bits.is_empty() || bits.len() > table.len() * w {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        let mut sum: Option<Self> = None;
+        let mut offsets = SWProjective::<P>::zero();
+
+        for (i, (window_bits, row)) in bits.chunks(w).zip(table.iter()).enumerate() {
+            //Pad the last window if needed
+            let mut window_bits = window_bits.to_vec();
+            window_bits.resize(w, Boolean::constant(false));
+
+            let adder = Self::select_constant(cs.ns(|| format!("Lookup_{}", i)), row, &window_bits)?;
+            sum = Some(match sum {
+                None => adder,
+                Some(sum) => {
+                    Self::check_distinct_x(sum.x.get_value(), adder.x.get_value())?;
+                    sum.add(cs.ns(|| format!("Add_{}", i)), &adder)?
+                },
+            });
+            offsets += &row[0].get_constant();
+        }
+        Ok((sum.unwrap(), offsets))
+    }
+
+    /// Fixed base scalar multiplication using a window table computed by
+    /// `precompute_gadget_window_table`: returns `result + scalar * base`.
+    /// Inputs must be specified in *little-endian* form. The entries looked up are
+    /// summed up first, then `result` is added and the offsets of the rows are
+    /// subtracted. As for `mul_bits_fixed_base`, `result` must not be the neutral
+    /// element; moreover `Unsatisfiable` is returned if one of these two incomplete
+    /// additions collides, i.e. if `result` is (up to sign) the sum of the entries, or
+    /// if `result + scalar * base` is the neutral element or twice the opposite of the
+    /// offsets.
+    pub fn mul_bits_with_window_table<CS: ConstraintSystem<ConstraintF>>(
+        mut cs: CS,
+        table: &[Vec<Self>],
+        result: &Self,
+        bits: &[Boolean],
+    ) -> Result<Self, SynthesisError> {
+        if bits.is_empty() {
+            return Ok(result.clone());
+        }
+        let (sum, offsets) = Self::window_table_sum(cs.ns(|| "table sum"), table, bits)?;
+
+        Self::check_distinct_x(sum.x.get_value(), result.x.get_value())?;
+        let result = sum.add(cs.ns(|| "sum + result"), result)?;
+
+        Self::check_distinct_x(result.x.get_value(), Some(offsets.into_affine().x))?;
+        result.sub_constant(cs.ns(|| "result - offsets"), &offsets)
+    }
+
+    /// Fixed base scalar multiplication returning `scalar * base`, with `scalar`
+    /// given in *little-endian* form. The multiples of `base` needed by each window
+    /// of 2 bits are computed natively and selected by a two bit lookup against
+    /// constants (see `mul_bits_fixed_base`), hence no table entry is a witness and
+    /// no curve addition is spent on building the table.
+    /// Since the additions are incomplete, the sum starts from the constant offset
+    /// `base/2`, which is subtracted at the end. Therefore the circuit is unsatisfiable
+    /// if `scalar * base` is the neutral element, and (as for `mul_bits_fixed_base`)
+    /// for the negligibly many scalars for which an intermediate sum collides with
+    /// the looked up entry.
+    pub fn fixed_base_scalar_mul<CS: ConstraintSystem<ConstraintF>>(
+        mut cs: CS,
+        base: &SWAffine<P>,
+        scalar_bits: &[Boolean],
+    ) -> Result<Self, SynthesisError> {
+        if base.is_zero() || scalar_bits.is_empty() {
+            return Self::zero(cs.ns(|| "zero"));
+        }
+        let two_inv = P::ScalarField::one().double().inverse().get()?;
+        let offset = base.mul(two_inv);
+        let offset_g = Self::from_value(cs.ns(|| "hardcode offset"), &offset);
+
+        let result = Self::mul_bits_fixed_base(
+            &base.into_projective(),
+            cs.ns(|| "offset + scalar * base"),
+            &offset_g,
+            scalar_bits,
+        )?;
+        result.sub_constant(cs.ns(|| "result - offset"), &offset)
+    }
+
+    /// Returns `scalar * (g - h)`, with `scalar` given in *little-endian* form.
+    /// As `g` and `h` are fixed, their difference is computed natively and a single
+    /// `fixed_base_scalar_mul` is performed, instead of two scalar multiplications
+    /// followed by a subtraction. If `g == h` the result is the (constant) neutral
+    /// element; otherwise the same restrictions as for `fixed_base_scalar_mul` apply.
+    pub fn mul_bits_of_difference<CS: ConstraintSystem<ConstraintF>>(
+        cs: CS,
+        g: &SWAffine<P>,
+        h: &SWAffine<P>,
+        scalar_bits: &[Boolean],
+    ) -> Result<Self, SynthesisError> {
+        let difference = (g.into_projective() - &h.into_projective()).into_affine();
+        Self::fixed_base_scalar_mul(cs, &difference, scalar_bits)
+    }
+
+    /// Fused multi-scalar multiplication by a few fixed bases using Straus' method:
+    /// returns `result + sum_k scalars[k] * bases[k]`. Scalars must be specified in
+    /// *little-endian* form and may have different lengths.
+    /// For each bit position i, the `2^n` subset sums of the bases (shifted by `2^i`
+    /// and offset by `sigma = bases[0]`) are hardcoded as constants, and the entry
+    /// selected by the i-th bits of the `n` scalars is expressed as a linear combination
+    /// of the products of these bits.
+    /// This costs `2^n - n - 1` constraints for the products plus a single addition per
+    /// bit position, hence it pays off w.r.t. `mul_bits_fixed_base` for small `n` and
+    /// expensive additions (e.g. over extension fields).
+    /// The entries can't be the neutral element, which has no affine coordinates: this
+    /// happens if `bases[0]` is the opposite of `2^i` times a subset sum of the bases
+    /// (e.g. for bases `[P, -P]`), in which case `Unsatisfiable` is returned.
+    /// As for `mul_bits_fixed_base`, `result` must not be the neutral element.
+    pub fn straus_msm<CS: ConstraintSystem<ConstraintF>>(
+        mut cs: CS,
+        result: &Self,
+        bases: &[SWProjective<P>],
+        scalars: &[Vec<Boolean>],
+    ) -> Result<Self, SynthesisError> {
+        let n = bases.len();
+        if n == 0 || n >= 32 || scalars.len() != n || bases[0].is_zero() {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+        let num_bits = scalars.iter().map(|s| s.len()).max().unwrap();
+        if num_bits == 0 {
+            return Ok(result.clone());
+        }
+
+        let sigma = bases[0];
+        let mut shifted_bases = bases.to_vec();
+        let mut to_sub = SWProjective::<P>::zero();
+        let mut result = result.clone();
+
+        for i in 0..num_bits {
+            let mut cs = cs.ns(|| format!("bit position {}", i));
+
+            // The i-th bits of the scalars, padded if needed
+            let bits = scalars
+                .iter()
+                .map(|s| s.get(i).cloned().unwrap_or(Boolean::constant(false)))
+                .collect::<Vec<_>>();
+
+            // monomials[j] is the product of the bits in the subset j of the bases
+            let mut monomials = Vec::with_capacity(1 << n);
+            monomials.push(Boolean::constant(true));
+            for (k, bit) in bits.iter().enumerate() {
+                for j in 0..(1 << k) {
+                    let monomial = if j == 0 {
+                        *bit
+                    } else {
+                        Boolean::and(cs.ns(|| format!("monomial {}", j | (1 << k))), &monomials[j], bit)?
+                    };
+                    monomials.push(monomial);
+                }
+            }
+
+            // table[j] = sigma + 2^i * (sum of the bases in the subset j)
+            let mut table = Vec::with_capacity(1 << n);
+            table.push(sigma);
+            for (k, base) in shifted_bases.iter().enumerate() {
+                for j in 0..(1 << k) {
+                    let entry = table[j] + base;
+                    table.push(entry);
+                }
+            }
+            if table.iter().any(|entry| entry.is_zero()) {
+                return Err(SynthesisError::Unsatisfiable);
+            }
+            SWProjective::batch_normalization(&mut table);
+
+            // Coefficients of the coordinates w.r.t. the monomials (Moebius transform)
+            let mut x_coeffs = table.iter().map(|p| p.x).collect::<Vec<_>>();
+            let mut y_coeffs = table.iter().map(|p| p.y).collect::<Vec<_>>();
+            for k in 0..n {
+                for j in 0..(1 << n) {
+                    if j & (1 << k) != 0 {
+                        let (x_sub, y_sub) = (x_coeffs[j ^ (1 << k)], y_coeffs[j ^ (1 << k)]);
+                        x_coeffs[j] -= &x_sub;
+                        y_coeffs[j] -= &y_sub;
+                    }
+                }
+            }
+
+            let mut x = F::from_value(cs.ns(|| "x constant term"), &x_coeffs[0]);
+            let mut y = F::from_value(cs.ns(|| "y constant term"), &y_coeffs[0]);
+            for j in 1..(1 << n) {
+                x = x.conditionally_add_constant(cs.ns(|| format!("x term {}", j)), &monomials[j], x_coeffs[j])?;
+                y = y.conditionally_add_constant(cs.ns(|| format!("y term {}", j)), &monomials[j], y_coeffs[j])?;
+            }
+
+            let adder = Self::new(x, y, Boolean::constant(false));
+            result = result.add(cs.ns(|| "add"), &adder)?;
+            to_sub += &sigma;
+
+            for base in shifted_bases.iter_mut() {
+                base.double_in_place();
+            }
+        }
+        result = result.sub_constant(cs.ns(|| "result - sigma*num_bits"), &to_sub)?;
+        Ok(result)
+    }
+
+    /// Variable base scalar multiplication by an element of the scalar field of
+    /// the curve: returns `result + scalar * self`. `scalar` must be given as exactly
+    /// `P::ScalarField::size_in_bits()` Booleans in *little-endian* form, otherwise
+    /// `Unsatisfiable` is returned. As for `mul_bits`, `result` must not be the
+    /// neutral element.
+    pub fn mul_scalar_field_element<CS: ConstraintSystem<ConstraintF>>(
+        &self,
+        cs: CS,
+        result: &Self,
+        scalar: &[Boolean],
+    ) -> Result<Self, SynthesisError> {
+        if scalar.len() != P::ScalarField::size_in_bits() {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+        self.mul_bits(cs, result, scalar.iter())
+    }
+
+    /// Same as `mul_bits`, but additionally returns the accumulators after each step,
+    /// i.e. the i-th element of the trace is `result + sum_{j <= i} bits[j] * 2^j * self`
+    /// (bits are in *little-endian* form, as for `mul_bits`). The trace has one element
+    /// per bit, and the last one (if any) is the returned result. No constraint is
+    /// added w.r.t. `mul_bits`.
+    pub fn mul_bits_trace<'a, CS: ConstraintSystem<ConstraintF>>(
+        &self,
+        mut cs: CS,
+        result: &Self,
+        bits: impl Iterator<Item = &'a Boolean>,
+    ) -> Result<(Self, Vec<Self>), SynthesisError> {
+        let scalar_bits = P::ScalarField::size_in_bits();
+        let mut power = self.clone();
+        let mut result = result.clone();
+        let mut trace = Vec::new();
+        for (i, bit) in bits.enumerate() {
+            debug_assert!(
+                i < scalar_bits || bit.get_value() != Some(true),
+                "mul_bits: scalar does not fit into {} bits",
+                scalar_bits
+            );
+            let new_encoded = result.add(&mut cs.ns(|| format!("Add {}-th power", i)), &power)?;
+            result = Self::conditionally_select(
+                &mut cs.ns(|| format!("Select {}", i)),
+                bit,
+                &new_encoded,
+                &result,
+            )?;
+            trace.push(result.clone());
+            power.double_in_place(&mut cs.ns(|| format!("{}-th Doubling", i)))?;
+        }
+        Ok((result, trace))
+    }
+
+    /// Returns `-self` if `cond` is set, `self` otherwise. Only the y-coordinate
+    /// is selected, hence this costs a single conditional selection of `F`; the
+    /// infinity flag is left untouched, as the neutral element is its own negative.
+    pub fn conditional_negate<CS: ConstraintSystem<ConstraintF>>(
+        &self,
+        mut cs: CS,
+        cond: &Boolean,
+    ) -> Result<Self, SynthesisError> {
+        let neg_y = self.y.negate(cs.ns(|| "negate y"))?;
+        let y = F::conditionally_select(cs.ns(|| "select y"), cond, &neg_y, &self.y)?;
+        Ok(Self::new(self.x.clone(), y, self.infinity))
+    }
+
+    /// Tangent-line doubling, returning `2 * self` together with the slope
+    /// `lambda = (3x^2 + a)/2y` of the tangent at `self` (as needed by the doubling
+    /// steps of the Miller loop). This is the doubling of `double_in_place`, hence
+    /// costs `cost_of_double()` constraints. `self` must be different from the
+    /// neutral element: as 2y is enforced to be invertible, the circuit is
+    /// unsatisfiable for the points of order two, whose double (the neutral element)
+    /// is not supported by the incomplete formulas. Use `double` if such points have
+    /// to be handled.
+    pub fn double_with_slope<CS: ConstraintSystem<ConstraintF>>(
+        &self,
+        mut cs: CS,
+    ) -> Result<(Self, F), SynthesisError> {
+        let x_squared = self.x.square(cs.ns(|| "x^2"))?;
+        let three_x_squared_plus_a = x_squared
+            .double(cs.ns(|| "2x^2"))?
+            .add(cs.ns(|| "3x^2"), &x_squared)?
+            .add_constant(cs.ns(|| "3x^2 + a"), &P::COEFF_A)?;
+        let two_y = self.y.double(cs.ns(|| "2y"))?;
+
+        // Enforce 2y to be invertible, see `double_in_place`
+        let two_y_inv = F::alloc(cs.ns(|| "2y inverse"), || {
+            two_y.get_value().get()?.inverse().get()
+        })?;
+        let one = F::one(cs.ns(|| "one"))?;
+        two_y_inv.mul_equals(cs.ns(|| "check 2y inverse"), &two_y, &one)?;
+
+        let lambda = F::alloc(cs.ns(|| "lambda"), || {
+            Ok(three_x_squared_plus_a.get_value().get()? * &two_y_inv.get_value().get()?)
+        })?;
+        lambda.mul_equals(cs.ns(|| "check lambda"), &two_y, &three_x_squared_plus_a)?;
+
+        let two_x = self.x.double(cs.ns(|| "2x"))?;
+        let x = lambda
+            .square(cs.ns(|| "lambda^2"))?
+            .sub(cs.ns(|| "lambda^2 - 2x"), &two_x)?;
+
+        let y = self
+            .x
+            .sub(cs.ns(|| "self.x - x"), &x)?
+            .mul(cs.ns(|| "times lambda"), &lambda)?
+            .sub(cs.ns(|| "minus self.y"), &self.y)?;
+
+        Ok((Self::new(x, y, Boolean::constant(false)), lambda))
+    }
+
+    /// Returns `2 * self`, setting the infinity flag of the result if `self` is the
+    /// neutral element or a point of order two (i.e. y = 0). The latter is detected
+    /// by a witnessed Boolean `b`, which is checked by enforcing y = 0 if `b` is set
+    /// and by doubling (x, 1) instead of `self` in that case: if `b` is not set, the
+    /// slope relation of `double_with_slope` rules out y = 0. As there, `self` must
+    /// be on the curve; on top of `cost_of_double()`, this costs the allocation of
+    /// `b`, one conditional equality, one OR, the conditional selection of the
+    /// y-coordinate and the one of the result.
+    pub fn double<CS: ConstraintSystem<ConstraintF>>(&self, mut cs: CS) -> Result<Self, SynthesisError> {
+        let y_is_zero = Boolean::alloc(cs.ns(|| "y is zero"), || {
+            Ok(self.y.get_value().get()?.is_zero())
+        })?;
+        let zero_fe = F::zero(cs.ns(|| "zero fe"))?;
+        self.y.conditional_enforce_equal(cs.ns(|| "y = 0 if y is zero"), &zero_fe, &y_is_zero)?;
+
+        let one_fe = F::one(cs.ns(|| "one fe"))?;
+        let y = F::conditionally_select(cs.ns(|| "select y"), &y_is_zero, &one_fe, &self.y)?;
+        let (doubled, _) = Self::new(self.x.clone(), y, self.infinity)
+            .double_with_slope(cs.ns(|| "double"))?;
+
+        let is_infinity = Boolean::or(cs.ns(|| "is infinity"), &self.infinity, &y_is_zero)?;
+        let zero = Self::zero(cs.ns(|| "zero"))?;
+        Self::conditionally_select(cs.ns(|| "select result"), &is_infinity, &zero, &doubled)
+    }
 }
 
 impl<P, ConstraintF, F> PartialEq for AffineGadget<P, ConstraintF, F>
@@ -302,40 +773,14 @@ for AffineGadget<P, ConstraintF, F>
     #[inline]
     fn double_in_place<CS: ConstraintSystem<ConstraintF>>(
         &mut self,
-        mut cs: CS,
+        cs: CS,
     ) -> Result<(), SynthesisError> {
-        let a = P::COEFF_A;
-        let x_squared = self.x.square(cs.ns(|| "x^2"))?;
-
-        let one = P::BaseField::one();
-        let two = one.double();
-        let three = two + &one;
-
-        let three_x_squared = x_squared.mul_by_constant(cs.ns(|| "3 * x^2"), &three)?;
-        let three_x_squared_plus_a = three_x_squared.add_constant(cs.ns(|| "3 * x^2 + a"), &a)?;
-
-        let two_y = self.y.double(cs.ns(|| "2y"))?;
-
-        let lambda = F::alloc(cs.ns(|| "lambda"), || {
-            let y_doubled_inv = two_y.get_value().get()?.inverse().get()?;
-            Ok(three_x_squared_plus_a.get_value().get()? * &y_doubled_inv)
-        })?;
-
-        // Check lambda
-        lambda.mul_equals(cs.ns(|| "check lambda"), &two_y, &three_x_squared_plus_a)?;
-
-        let x = lambda
-            .square(cs.ns(|| "lambda^2"))?
-            .sub(cs.ns(|| "lambda^2 - x"), &self.x)?
-            .sub(cs.ns(|| "lambda^2 - 2x"), &self.x)?;
-
-        let y = self
-            .x
-            .sub(cs.ns(|| "x - self.x"), &x)?
-            .mul(cs.ns(|| "times lambda"), &lambda)?
-            .sub(cs.ns(|| "plus self.y"), &self.y)?;
-
-        *self = Self::new(x, y, Boolean::constant(false));
+        // If y = 0 the relation lambda * 2y = 3x^2 + a is satisfied by any lambda
+        // as soon as 3x^2 + a = 0 (which cannot happen for a point on the curve,
+        // but may happen for an unchecked witness), so that the result would be
+        // arbitrary. Enforcing 2y to be invertible rules out such a case, as well
+        // as the points of order two, whose double is the neutral element.
+        *self = self.double_with_slope(cs)?.0;
         Ok(())
     }
 
@@ -350,6 +795,19 @@ for AffineGadget<P, ConstraintF, F>
         ))
     }
 
+    /// Inputs must be specified in *little-endian* form. Bits beyond
+    /// `P::ScalarField::size_in_bits()` are allowed, but (in debug builds)
+    /// their witnesses must be false, i.e. the scalar must fit into the bit
+    /// length of the scalar field. `result` must not be the neutral element.
+    fn mul_bits<'a, CS: ConstraintSystem<ConstraintF>>(
+        &self,
+        cs: CS,
+        result: &Self,
+        bits: impl Iterator<Item = &'a Boolean>,
+    ) -> Result<Self, SynthesisError> {
+        Ok(self.mul_bits_trace(cs, result, bits)?.0)
+    }
+
     ///This will take [(4 + 1) * ceil(len(bits)/2)] constraints to put the x lookup constraint
     ///into the addition formula. See coda/src/lib/snarky_curves/snarky_curves.ml "scale_known"
     ///Note: `self` must be different from `result` due to SW incomplete addition.
@@ -405,6 +863,58 @@ for AffineGadget<P, ConstraintF, F>
         Ok(result)
     }
 
+    /// The i-th row contains `(j + 2) * 2^(window*i) * base` for `j` in `0..2^window`,
+    /// with `window >= 2`: see `precompute_gadget_window_table` for the choice of the
+    /// offsets, which avoids collisions in the incomplete additions.
+    fn precompute_base_table<CS: ConstraintSystem<ConstraintF>>(
+        mut cs: CS,
+        base: &SWProjective<P>,
+        window: usize,
+    ) -> Result<Vec<Vec<Self>>, SynthesisError> {
+        if window < 2 || window >= 32 {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+        let scalar_size = P::ScalarField::size_in_bits();
+        let num_windows = (scalar_size + window - 1) / window;
+
+        let mut t = *base;
+        let mut table = Vec::with_capacity(num_windows);
+        for i in 0..num_windows {
+            let mut row = Vec::with_capacity(1 << window);
+            let mut entry = t.double();
+            for j in 0..(1 << window) {
+                row.push(Self::from_value(cs.ns(|| format!("hardcode table entry {}_{}", i, j)), &entry));
+                entry += &t;
+            }
+            table.push(row);
+            for _ in 0..window {
+                t.double_in_place();
+            }
+        }
+        Ok(table)
+    }
+
+    /// As the table entries are constants, the lookups are performed by `select_constant`
+    /// on the coordinates only, see `mul_bits_with_window_table`. The entries looked up
+    /// are summed up starting from the first one, and the offsets of the rows are
+    /// subtracted at the end: the circuit is unsatisfiable (and `Unsatisfiable` is
+    /// returned) if `scalar * base` is the neutral element (e.g. for a zero scalar) or
+    /// twice the opposite of the offsets, and for the negligibly many full length scalars
+    /// for which a partial sum collides with the entry looked up next.
+    fn scalar_mul_with_precomputed_table<CS: ConstraintSystem<ConstraintF>>(
+        mut cs: CS,
+        table: &[Vec<Self>],
+        scalar_bits: &[Boolean],
+    ) -> Result<Self, SynthesisError> {
+        if scalar_bits.is_empty() {
+            return Self::zero(cs.ns(|| "zero"));
+        }
+        let (sum, offsets) = Self::window_table_sum(cs.ns(|| "table sum"), table, scalar_bits)?;
+
+        Self::check_distinct_x(sum.x.get_value(), Some(offsets.into_affine().x))?;
+        sum.sub_constant(cs.ns(|| "sum - offsets"), &offsets)
+    }
+
     /// Useful in context when you have some signed representation of the scalar's digits, like
     /// in BH hash. I decided here to keep the same logic as TE implementation  for future extensibility:
     /// in fact there is no actual difference between "outer" and "inner" sums since they all
@@ -532,7 +1042,7 @@ for AffineGadget<P, ConstraintF, F>
     }
 
     fn cost_of_double() -> usize {
-        3 * F::cost_of_mul() + F::cost_of_mul_equals()
+        3 * F::cost_of_mul() + 2 * F::cost_of_mul_equals()
     }
 }
 
@@ -637,6 +1147,9 @@ for AffineGadget<P, ConstraintF, F>
         ConstraintF: Field,
         F: FieldGadget<P::BaseField, ConstraintF>,
 {
+    /// Allocates the coordinates of the point and enforces the curve equation
+    /// y^2 = x^3 + a*x + b, but not membership to the prime order subgroup:
+    /// use `alloc_checked` for points coming from untrusted sources.
     #[inline]
     fn alloc<FN, T, CS: ConstraintSystem<ConstraintF>>(
         mut cs: CS,
@@ -679,6 +1192,8 @@ for AffineGadget<P, ConstraintF, F>
         Ok(Self::new(x, y, infinity))
     }
 
+    /// Allocates the coordinates of the point without enforcing anything: only for
+    /// points whose validity is enforced elsewhere in the circuit.
     #[inline]
     fn alloc_without_check<FN, T, CS: ConstraintSystem<ConstraintF>>(
         mut cs: CS,
@@ -707,6 +1222,10 @@ for AffineGadget<P, ConstraintF, F>
         Ok(Self::new(x, y, infinity))
     }
 
+    /// Allocates the point enforcing both the curve equation and membership to the
+    /// prime order subgroup. This is the allocator to use for untrusted points, e.g.
+    /// the inputs of the pairing gadget: a prover could otherwise inject points off
+    /// the curve or of small order.
     #[inline]
     fn alloc_checked<FN, T, CS: ConstraintSystem<ConstraintF>>(
         mut cs: CS,
@@ -858,16 +1377,11 @@ impl<P, ConstraintF, F> ConstantGadget<SWProjective<P>, ConstraintF> for AffineG
         F: FieldGadget<P::BaseField, ConstraintF>,
 {
     fn from_value<CS: ConstraintSystem<ConstraintF>>(
-        mut cs: CS,
+        cs: CS,
         value: &SWProjective<P>,
     ) -> Self
     {
-        let value = value.into_affine();
-        let x = F::from_value(cs.ns(|| "hardcode x"), &value.x);
-        let y = F::from_value(cs.ns(|| "hardcode y"), &value.y);
-        let infinity = Boolean::constant(value.infinity);
-
-        Self::new(x, y, infinity)
+        Self::constant(cs, &value.into_affine())
     }
 
     fn get_constant(&self) ->SWProjective<P> {
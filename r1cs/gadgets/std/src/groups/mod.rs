@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use algebra::{Field, Group};
+use algebra::{Field, FpParameters, Group, PrimeField};
 use r1cs_core::{ConstraintSystem, SynthesisError};
 
 use std::{borrow::Borrow, fmt::Debug};
@@ -69,8 +69,11 @@ pub trait GroupGadget<G: Group, ConstraintF: Field>:
 
     fn negate<CS: ConstraintSystem<ConstraintF>>(&self, cs: CS) -> Result<Self, SynthesisError>;
 
-    /// Variable base exponentiation.
-    /// Inputs must be specified in *little-endian* form.
+    /// Variable base exponentiation by the integer represented by `bits`.
+    /// Inputs must be specified in *little-endian* form. There is no canonicity
+    /// requirement on `bits`: for a point of order `r`, bits representing `s + r`
+    /// give the same result as those representing `s`. Use `mul_bits_canonical`
+    /// if the bits must uniquely encode the scalar.
     /// If the addition law is incomplete for the identity element,
     /// `result` must not be the identity element.
     fn mul_bits<'a, CS: ConstraintSystem<ConstraintF>>(
@@ -94,6 +97,33 @@ pub trait GroupGadget<G: Group, ConstraintF: Field>:
         Ok(result)
     }
 
+    /// Variable base exponentiation by a canonical scalar, i.e. as `mul_bits`,
+    /// but additionally enforcing that the integer represented by `bits` is
+    /// smaller than the modulus of `G::ScalarField`. Without this check, the
+    /// prover may replace a scalar `s` by any `s + k * modulus` fitting into
+    /// `bits.len()` bits: the result is the same, but the bits are not a unique
+    /// encoding of the scalar anymore, which matters as soon as they are used
+    /// elsewhere (e.g. packed into a field element, or hashed).
+    /// Inputs must be specified in *little-endian* form. Bit vectors shorter
+    /// than `MODULUS_BITS` are always canonical and cost nothing extra.
+    fn mul_bits_canonical<CS: ConstraintSystem<ConstraintF>>(
+        &self,
+        mut cs: CS,
+        result: &Self,
+        bits: &[Boolean],
+    ) -> Result<Self, SynthesisError> {
+        let modulus_bits = <G::ScalarField as PrimeField>::Params::MODULUS_BITS as usize;
+        if bits.len() >= modulus_bits {
+            // `enforce_in_field` expects the bits in big-endian form.
+            let be_bits = bits.iter().rev().cloned().collect::<Vec<_>>();
+            Boolean::enforce_in_field::<_, _, G::ScalarField>(
+                cs.ns(|| "enforce scalar in field"),
+                &be_bits,
+            )?;
+        }
+        self.mul_bits(cs.ns(|| "mul bits"), result, bits.iter())
+    }
+
     fn precomputed_base_scalar_mul<'a, CS, I, B>(
         &mut self,
         mut cs: CS,
@@ -134,6 +164,80 @@ pub trait GroupGadget<G: Group, ConstraintF: Field>:
         base_g.mul_bits(cs, result, bits.into_iter())
     }
 
+    /// Precomputes, as constant gadgets, the multiples of the fixed point `base`
+    /// needed by `scalar_mul_with_precomputed_table` for windows of `window` bits:
+    /// the i-th row contains `sigma + j * 2^(window*i) * base` for `j` in
+    /// `0..2^window`, with `sigma = base`, so that no entry is the neutral element.
+    /// Implementations may choose a different offset for each row, as long as the
+    /// consecutive entries of the i-th row differ by `2^(window*i) * base`.
+    /// No variable or constraint is allocated: the table can be computed once and
+    /// reused for all the scalar multiplications of `base` in the circuit.
+    fn precompute_base_table<CS: ConstraintSystem<ConstraintF>>(
+        mut cs: CS,
+        base: &G,
+        window: usize,
+    ) -> Result<Vec<Vec<Self>>, SynthesisError> {
+        if window == 0 || window >= 32 {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+        let scalar_size = <G::ScalarField as PrimeField>::size_in_bits();
+        let num_windows = (scalar_size + window - 1) / window;
+
+        let sigma = *base;
+        let mut t = *base;
+        let mut table = Vec::with_capacity(num_windows);
+        for i in 0..num_windows {
+            let mut row = Vec::with_capacity(1 << window);
+            let mut entry = sigma;
+            for j in 0..(1 << window) {
+                row.push(Self::from_value(cs.ns(|| format!("hardcode table entry {}_{}", i, j)), &entry));
+                entry += &t;
+            }
+            table.push(row);
+            for _ in 0..window {
+                t.double_in_place();
+            }
+        }
+        Ok(table)
+    }
+
+    /// Fixed base scalar multiplication returning `scalar * base`, where `table` has
+    /// been computed by `precompute_base_table` and `scalar_bits` are given in
+    /// *little-endian* form. The default implementation recovers the powers of two of
+    /// `base` from the table and adds them by `precomputed_base_scalar_mul`, starting
+    /// from the neutral element. Hence it requires a complete addition law: gadgets with
+    /// incomplete addition must override it, performing one lookup per window instead.
+    fn scalar_mul_with_precomputed_table<CS: ConstraintSystem<ConstraintF>>(
+        mut cs: CS,
+        table: &[Vec<Self>],
+        scalar_bits: &[Boolean],
+    ) -> Result<Self, SynthesisError> {
+        if table.is_empty() || table[0].len() < 2 || !table[0].len().is_power_of_two() {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+        let window = table[0].len().trailing_zeros() as usize;
+        if scalar_bits.len() > table.len() * window {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        // The second entry of the i-th row exceeds the first one by 2^(window*i) * base
+        let mut powers = Vec::with_capacity(table.len() * window);
+        for row in table.iter() {
+            let mut power = row[1].get_constant() - &row[0].get_constant();
+            for _ in 0..window {
+                powers.push(power);
+                power.double_in_place();
+            }
+        }
+
+        let mut result = Self::zero(cs.ns(|| "zero"))?;
+        result.precomputed_base_scalar_mul(
+            cs.ns(|| "scalar mul"),
+            scalar_bits.iter().zip(powers.iter()),
+        )?;
+        Ok(result)
+    }
+
     fn precomputed_base_3_bit_signed_digit_scalar_mul<'a, CS, I, J, B>(
         _: CS,
         _: &[B],
@@ -307,6 +411,39 @@ mod test {
         group_test::<_, JubJubProjective, _, _>(&mut cs.ns(|| "GroupTest(a, b)"), a, b);
     }
 
+    #[test]
+    fn jubjub_precomputed_base_table_test() {
+        use crate::groups::jubjub::JubJubGadget;
+        use algebra::{curves::jubjub::JubJubProjective, fields::jubjub::{fq::Fq, fr::Fr}, BitIterator, PrimeField};
+
+        let mut cs = TestConstraintSystem::<Fq>::new();
+
+        let base: JubJubProjective = rand::random();
+        let table: Vec<Vec<JubJubGadget>> = GroupGadget::<JubJubProjective, Fq>::precompute_base_table(
+            cs.ns(|| "table"),
+            &base,
+            2
+        ).unwrap();
+        assert_eq!(cs.num_constraints(), 0);
+
+        for i in 0..2 {
+            let scalar: Fr = rand::random();
+            // The table covers the bit length of the scalar field only
+            let mut bits: Vec<bool> = BitIterator::new(scalar.into_repr()).collect();
+            bits.reverse();
+            bits.truncate(Fr::size_in_bits());
+            let input = Vec::<Boolean>::alloc(cs.ns(|| format!("scalar {}", i)), || Ok(bits)).unwrap();
+
+            let result: JubJubGadget = GroupGadget::<JubJubProjective, Fq>::scalar_mul_with_precomputed_table(
+                cs.ns(|| format!("mul {}", i)),
+                &table,
+                &input,
+            ).unwrap();
+            assert_eq!(GroupGadget::<JubJubProjective, Fq>::get_value(&result).unwrap(), base * &scalar);
+        }
+        assert!(cs.is_satisfied());
+    }
+
     #[test]
     fn mnt4_group_gadgets_test() {
         use crate::groups::curves::short_weierstrass::mnt::mnt4::mnt4753::MNT4G1Gadget;
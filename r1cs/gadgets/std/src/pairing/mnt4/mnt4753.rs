@@ -1,4 +1,22 @@
 use crate::pairing::mnt4::MNT4PairingGadget;
 use algebra::curves::mnt4753::MNT4_753Parameters;
 
-pub type MNT4753PairingGadget = MNT4PairingGadget<MNT4_753Parameters>;
\ No newline at end of file
+pub type MNT4753PairingGadget = MNT4PairingGadget<MNT4_753Parameters>;
+
+#[cfg(test)]
+mod test {
+    use super::MNT4_753Parameters;
+    use crate::pairing::mnt4::test::{
+        native_prepared_interchangeable_test, prepared_gadgets_differential_test,
+    };
+
+    #[test]
+    fn mnt4753_prepared_gadgets_test() {
+        prepared_gadgets_differential_test::<MNT4_753Parameters>();
+    }
+
+    #[test]
+    fn mnt4753_native_prepared_interchangeable_test() {
+        native_prepared_interchangeable_test();
+    }
+}
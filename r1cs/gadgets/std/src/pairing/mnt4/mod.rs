@@ -1,6 +1,6 @@
 use r1cs_core::{ConstraintSystem, SynthesisError};
 
-use crate::{fields::{fp4::Fp4Gadget, FieldGadget}, groups::curves::short_weierstrass::mnt::mnt4::{G1Gadget, G2Gadget, G1PreparedGadget, G2PreparedGadget},
+use crate::{fields::{fp2::Fp2Gadget, fp4::Fp4Gadget, FieldGadget}, groups::curves::short_weierstrass::mnt::mnt4::{G1Gadget, G2Gadget, G1PreparedGadget, G2PreparedGadget, G2CoefficientsGadget},
 };
 
 use algebra::{ModelParameters, Fp4, PairingCurve};
@@ -15,6 +15,28 @@ pub mod mnt4753;
 
 pub struct MNT4PairingGadget<P: MNT4Parameters>(PhantomData<P>);
 
+impl<P: MNT4Parameters> MNT4PairingGadget<P> {
+    /// Evaluates at `p` the line of a doubling or addition step of the Miller loop with
+    /// pre-computed coefficients `c`, passing through the twist point with y-coordinate `y`
+    /// (i.e. `c.r_y` for the doubling step and +/- `q.y` for the addition step).
+    /// Mirrors the native `MNT4p::line_evaluation()`.
+    pub fn evaluate_line<CS: ConstraintSystem<P::Fp>>(
+        mut cs: CS,
+        p: &G1PreparedGadget<P>,
+        c: &G2CoefficientsGadget<P>,
+        y: &Fp2Gadget<P::Fp2Params, P::Fp>,
+    ) -> Result<Fp4Gadget<P::Fp4Params, P::Fp>, SynthesisError>
+    {
+        let mut t = c.gamma.mul_by_constant(cs.ns(|| "compute gamma_twist"), &P::TWIST)?;
+        t.mul_assign_by_fp_gadget(cs.ns(|| "gamma_twist * p.x"), &p.p.x)?;
+        let c1 = c.gamma_x
+            .sub(cs.ns(|| "gamma_x - y"), y)?
+            .sub(cs.ns(|| "gamma_x - y - t"), &t)?;
+
+        Ok(Fp4Gadget::<P::Fp4Params, P::Fp>::new(p.p_y_twist_squared.clone(), c1))
+    }
+}
+
 impl<P: MNT4Parameters> PairingGadget<MNT4p<P>, P::Fp> for MNT4PairingGadget<P>
     where
         G1Affine<P>: PairingCurve<
@@ -65,17 +87,8 @@ impl<P: MNT4Parameters> PairingGadget<MNT4p<P>, P::Fp> for MNT4PairingGadget<P>
                 idx += 1;
 
                 //Double step
-                //Compute g_rr_at_p_c0
-                let g_rr_at_p_c0 = ps.clone().p_y_twist_squared;
-
-                let mut t = c.gamma.mul_by_constant(cs.ns(|| "double compute gamma_twist"), &P::TWIST)?;
-                t.mul_assign_by_fp_gadget(cs.ns(|| "double gamma_twist * ps.p.x"), &ps.p.x)?;
-                let g_rr_at_p_c1 = c.gamma_x
-                    .sub(cs.ns(|| "gamma_x - r_y"), &c.r_y)?
-                    .sub(cs.ns(|| "gamma_x - r_y - t"), &t)?;
-
                 //Compute g_rr_at_p
-                let g_rr_at_p = Self::GTGadget::new(g_rr_at_p_c0.clone(), g_rr_at_p_c1);
+                let g_rr_at_p = Self::evaluate_line(cs.ns(|| "double evaluate line"), ps, c, &c.r_y)?;
 
                 //Compute new_f
                 f = f.square(cs.ns(|| "f^2"))?.mul_by_023(cs.ns(||"double compute f"), &g_rr_at_p)?;
@@ -85,20 +98,10 @@ impl<P: MNT4Parameters> PairingGadget<MNT4p<P>, P::Fp> for MNT4PairingGadget<P>
                     let c = &qs.coeffs[idx];
                     idx += 1;
 
-                    let g_rq_at_p_c0 = ps.clone().p_y_twist_squared;
-
-                    //Compute g_rq_at_p_c1
+                    //Compute g_rq_at_p
                     let neg_q_y = qs.q.y.negate(cs.ns(|| "- q.y"))?;
                     let q_y = if n > 0 {qs.clone().q.y} else {neg_q_y};
-
-                    let mut t = c.gamma.mul_by_constant(cs.ns(|| "add compute gamma_twist"), &P::TWIST)?;
-                    t.mul_assign_by_fp_gadget(cs.ns(|| "add gamma_twist * ps.p.x"), &ps.p.x)?;
-                    let g_rq_at_p_c1 = c.gamma_x
-                        .sub(cs.ns(|| "gamma_x - q_y"), &q_y)?
-                        .sub(cs.ns(|| "gamma_x - q_y - t"), &t)?;
-
-                    //Compute g_rq_at_p
-                    let g_rq_at_p = Self::GTGadget::new(g_rq_at_p_c0, g_rq_at_p_c1);
+                    let g_rq_at_p = Self::evaluate_line(cs.ns(|| "add evaluate line"), ps, c, &q_y)?;
 
                     //Compute and check f
                     f = f.mul_by_023(cs.ns(||"add compute f"), &g_rq_at_p)?;
@@ -162,4 +165,95 @@ impl<P: MNT4Parameters> PairingGadget<MNT4p<P>, P::Fp> for MNT4PairingGadget<P>
     {
         Self::G2PreparedGadget::from_affine(cs, q)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+    use crate::{prelude::*, test_constraint_system::TestConstraintSystem};
+    use algebra::{ProjectiveCurve, UniformRand};
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    /// Prepares random points both natively and in-circuit, and checks that the
+    /// gadgets agree with `G1Prepared` and `G2Prepared`, the latter coefficient
+    /// by coefficient, i.e. that both iterate over `P::WNAF` in the same order.
+    pub(crate) fn prepared_gadgets_differential_test<P: MNT4Parameters>() {
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        // Make sure both addition and subtraction steps are covered
+        assert!(P::WNAF.iter().any(|&n| n > 0));
+        assert!(P::WNAF.iter().any(|&n| n < 0));
+
+        for i in 0..2 {
+            let mut cs = TestConstraintSystem::<P::Fp>::new();
+
+            let p = G1Projective::<P>::rand(&mut rng);
+            let q = G2Projective::<P>::rand(&mut rng);
+            let p_native = G1Prepared::<P>::from_affine(&p.into_affine());
+            let q_native = G2Prepared::<P>::from_affine(&q.into_affine());
+
+            let p_g = G1Gadget::<P>::alloc(cs.ns(|| format!("alloc p {}", i)), || Ok(p)).unwrap();
+            let q_g = G2Gadget::<P>::alloc(cs.ns(|| format!("alloc q {}", i)), || Ok(q)).unwrap();
+            let p_prep = G1PreparedGadget::<P>::from_affine(cs.ns(|| format!("prepare p {}", i)), &p_g).unwrap();
+            let q_prep = G2PreparedGadget::<P>::from_affine(cs.ns(|| format!("prepare q {}", i)), &q_g).unwrap();
+            assert!(cs.is_satisfied());
+
+            assert_eq!(p_prep.p.get_value().unwrap().into_affine(), p_native.p);
+            assert_eq!(p_prep.p_y_twist_squared.get_value().unwrap(), p_native.py_twist_squared);
+
+            assert_eq!(q_prep.q.get_value().unwrap().into_affine(), q_native.q);
+            assert_eq!(
+                q_prep.coeffs.len(),
+                q_native.coeffs.len(),
+                "number of coefficients differs"
+            );
+            for (j, (c_g, c)) in q_prep.coeffs.iter().zip(q_native.coeffs.iter()).enumerate() {
+                assert_eq!(c_g.r_y.get_value().unwrap(), c.r_y, "r_y differs at step {}", j);
+                assert_eq!(c_g.gamma.get_value().unwrap(), c.gamma, "gamma differs at step {}", j);
+                assert_eq!(c_g.gamma_x.get_value().unwrap(), c.gamma_x, "gamma_x differs at step {}", j);
+            }
+        }
+    }
+
+    /// Runs the Miller loop gadget on the same G1 point once against an in-circuit
+    /// prepared G2 point and once against its natively prepared, hardcoded counterpart,
+    /// and checks that both agree with the native Miller loop.
+    ///
+    /// Concrete over MNT4-753, as the Miller loop gadget requires the `PairingCurve`
+    /// bounds of the `PairingGadget` impl.
+    pub(crate) fn native_prepared_interchangeable_test() {
+        type P = algebra::curves::mnt4753::MNT4_753Parameters;
+
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+        let mut cs = TestConstraintSystem::<<P as MNT4Parameters>::Fp>::new();
+
+        let p = G1Projective::<P>::rand(&mut rng);
+        let q = G2Projective::<P>::rand(&mut rng);
+        let p_native = G1Prepared::<P>::from_affine(&p.into_affine());
+        let q_native = G2Prepared::<P>::from_affine(&q.into_affine());
+
+        let p_g = G1Gadget::<P>::alloc(cs.ns(|| "alloc p"), || Ok(p)).unwrap();
+        let q_g = G2Gadget::<P>::alloc(cs.ns(|| "alloc q"), || Ok(q)).unwrap();
+        let p_prep = G1PreparedGadget::<P>::from_affine(cs.ns(|| "prepare p"), &p_g).unwrap();
+        let q_prep = G2PreparedGadget::<P>::from_affine(cs.ns(|| "prepare q"), &q_g).unwrap();
+        let q_hardcoded = G2PreparedGadget::<P>::from_value(cs.ns(|| "hardcode prepared q"), &q_native);
+        assert_eq!(q_hardcoded.get_constant(), q_native);
+
+        let f_prep = MNT4PairingGadget::<P>::miller_loop(
+            cs.ns(|| "miller loop with prepared q"),
+            &[p_prep.clone()],
+            &[q_prep],
+        ).unwrap();
+        let f_hardcoded = MNT4PairingGadget::<P>::miller_loop(
+            cs.ns(|| "miller loop with hardcoded q"),
+            &[p_prep],
+            &[q_hardcoded],
+        ).unwrap();
+        assert!(cs.is_satisfied());
+
+        let f_native = MNT4p::<P>::ate_miller_loop(&p_native, &q_native);
+        assert_eq!(f_prep.get_value().unwrap(), f_native);
+        assert_eq!(f_hardcoded.get_value().unwrap(), f_native);
+    }
+}
@@ -1,4 +1,22 @@
 use crate::pairing::mnt6::MNT6PairingGadget;
 use algebra::curves::mnt6753::MNT6_753Parameters;
 
-pub type MNT6753PairingGadget = MNT6PairingGadget<MNT6_753Parameters>;
\ No newline at end of file
+pub type MNT6753PairingGadget = MNT6PairingGadget<MNT6_753Parameters>;
+
+#[cfg(test)]
+mod test {
+    use super::MNT6_753Parameters;
+    use crate::pairing::mnt6::test::{
+        native_prepared_interchangeable_test, prepared_gadgets_differential_test,
+    };
+
+    #[test]
+    fn mnt6753_prepared_gadgets_test() {
+        prepared_gadgets_differential_test::<MNT6_753Parameters>();
+    }
+
+    #[test]
+    fn mnt6753_native_prepared_interchangeable_test() {
+        native_prepared_interchangeable_test();
+    }
+}
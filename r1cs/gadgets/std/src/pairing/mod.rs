@@ -1,7 +1,7 @@
 use crate::prelude::*;
 use algebra::{Field, PairingEngine};
 use r1cs_core::{ConstraintSystem, SynthesisError};
-use std::fmt::Debug;
+use std::{fmt::Debug, marker::PhantomData};
 
 pub mod mnt4;
 pub mod mnt6;
@@ -65,6 +65,83 @@ pub trait PairingGadget<PairingE: PairingEngine, ConstraintF: Field> {
     ) -> Result<Self::G2PreparedGadget, SynthesisError>;
 }
 
+type G1Variable<PairingE, ConstraintF, P> =
+    <<P as PairingGadget<PairingE, ConstraintF>>::G1Gadget as GroupGadget<<PairingE as PairingEngine>::G1Projective, ConstraintF>>::Variable;
+type G2Variable<PairingE, ConstraintF, P> =
+    <<P as PairingGadget<PairingE, ConstraintF>>::G2Gadget as GroupGadget<<PairingE as PairingEngine>::G2Projective, ConstraintF>>::Variable;
+
+/// Opt-in memoization of `prepare_g1` and `prepare_g2` within a single synthesis.
+/// Preparing a point whose coordinates are exactly the same variables (or linear
+/// combinations) as a previously prepared one returns a clone of the earlier result
+/// instead of enforcing the preparation constraints again. Points which merely
+/// share the same witness values are never treated as equal.
+pub struct PreparedGadgetCache<PairingE, ConstraintF, P>
+where
+    PairingE: PairingEngine,
+    ConstraintF: Field,
+    P: PairingGadget<PairingE, ConstraintF>,
+{
+    g1: Vec<(G1Variable<PairingE, ConstraintF, P>, P::G1PreparedGadget)>,
+    g2: Vec<(G2Variable<PairingE, ConstraintF, P>, P::G2PreparedGadget)>,
+    _engine: PhantomData<(PairingE, ConstraintF)>,
+}
+
+impl<PairingE, ConstraintF, P> Default for PreparedGadgetCache<PairingE, ConstraintF, P>
+where
+    PairingE: PairingEngine,
+    ConstraintF: Field,
+    P: PairingGadget<PairingE, ConstraintF>,
+{
+    fn default() -> Self {
+        Self { g1: Vec::new(), g2: Vec::new(), _engine: PhantomData }
+    }
+}
+
+impl<PairingE, ConstraintF, P> PreparedGadgetCache<PairingE, ConstraintF, P>
+where
+    PairingE: PairingEngine,
+    ConstraintF: Field,
+    P: PairingGadget<PairingE, ConstraintF>,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn prepare_g1<CS: ConstraintSystem<ConstraintF>>(
+        &mut self,
+        cs: CS,
+        q: &P::G1Gadget,
+    ) -> Result<P::G1PreparedGadget, SynthesisError>
+    where
+        G1Variable<PairingE, ConstraintF, P>: PartialEq,
+    {
+        let key = q.get_variable();
+        if let Some((_, prepared)) = self.g1.iter().find(|(k, _)| *k == key) {
+            return Ok(prepared.clone());
+        }
+        let prepared = P::prepare_g1(cs, q)?;
+        self.g1.push((key, prepared.clone()));
+        Ok(prepared)
+    }
+
+    pub fn prepare_g2<CS: ConstraintSystem<ConstraintF>>(
+        &mut self,
+        cs: CS,
+        q: &P::G2Gadget,
+    ) -> Result<P::G2PreparedGadget, SynthesisError>
+    where
+        G2Variable<PairingE, ConstraintF, P>: PartialEq,
+    {
+        let key = q.get_variable();
+        if let Some((_, prepared)) = self.g2.iter().find(|(k, _)| *k == key) {
+            return Ok(prepared.clone());
+        }
+        let prepared = P::prepare_g2(cs, q)?;
+        self.g2.push((key, prepared.clone()));
+        Ok(prepared)
+    }
+}
+
 #[cfg(test)]
 mod test {
     // use rand;
@@ -425,4 +502,191 @@ mod test {
         }
         assert!(cs.is_satisfied(), "cs is not satisfied");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn mnt4_753_line_evaluation_test() {
+        use algebra::{
+            fields::mnt4753::Fq,
+            PairingCurve, ProjectiveCurve, UniformRand,
+        };
+
+        use super::mnt4753::MNT4753PairingGadget as PairingGadget;
+        use crate::{
+            groups::curves::short_weierstrass::mnt::mnt4::mnt4753::{
+                MNT4G1Gadget as G1Gadget, MNT4G1PreparedGadget as G1PreparedGadget,
+                MNT4G2Gadget as G2Gadget, MNT4G2PreparedGadget as G2PreparedGadget,
+            },
+            prelude::*,
+        };
+        use algebra::curves::mnt4753::{MNT4, MNT4_753Parameters, G1Projective, G2Projective};
+        use algebra::curves::models::mnt4::MNT4Parameters;
+        use rand::SeedableRng;
+        use rand_xorshift::XorShiftRng;
+
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+        let mut cs = TestConstraintSystem::<Fq>::new();
+
+        let a = G1Projective::rand(&mut rng);
+        let b = G2Projective::rand(&mut rng);
+
+        let a_prep = a.into_affine().prepare();
+        let b_prep = b.into_affine().prepare();
+
+        let a_g = G1Gadget::alloc(&mut cs.ns(|| "a"), || Ok(a)).unwrap();
+        let b_g = G2Gadget::alloc(&mut cs.ns(|| "b"), || Ok(b)).unwrap();
+        let a_prep_g = G1PreparedGadget::from_affine(&mut cs.ns(|| "a_prep"), &a_g).unwrap();
+        let b_prep_g = G2PreparedGadget::from_affine(&mut cs.ns(|| "b_prep"), &b_g).unwrap();
+        let neg_q_y = b_prep_g.q.y.negate(cs.ns(|| "- q.y")).unwrap();
+
+        let mut idx: usize = 0;
+        for (j, &n) in <MNT4_753Parameters as MNT4Parameters>::WNAF.iter().rev().enumerate() {
+            let mut cs = cs.ns(|| format!("Iteration_{}", j));
+
+            //Doubling step
+            let (c, c_g) = (&b_prep.coeffs[idx], &b_prep_g.coeffs[idx]);
+            idx += 1;
+            let line = MNT4::line_evaluation(&a_prep, c, &c.r_y);
+            let line_g = PairingGadget::evaluate_line(cs.ns(|| "double"), &a_prep_g, c_g, &c_g.r_y).unwrap();
+            assert_eq!(line_g.get_value().unwrap(), line, "Doubling line evaluations differ at iteration {}", j);
+
+            //Addition step
+            if n != 0 {
+                let (c, c_g) = (&b_prep.coeffs[idx], &b_prep_g.coeffs[idx]);
+                idx += 1;
+                let q_y = if n > 0 { b_prep.q.y } else { -b_prep.q.y };
+                let q_y_g = if n > 0 { &b_prep_g.q.y } else { &neg_q_y };
+                let line = MNT4::line_evaluation(&a_prep, c, &q_y);
+                let line_g = PairingGadget::evaluate_line(cs.ns(|| "add"), &a_prep_g, c_g, q_y_g).unwrap();
+                assert_eq!(line_g.get_value().unwrap(), line, "Addition line evaluations differ at iteration {}", j);
+            }
+        }
+        assert_eq!(idx, b_prep.coeffs.len());
+
+        if !cs.is_satisfied() {
+            println!("Unsatisfied: {:?}", cs.which_is_unsatisfied());
+        }
+        assert!(cs.is_satisfied(), "cs is not satisfied");
+    }
+
+    #[test]
+    fn mnt6_753_line_evaluation_test() {
+        use algebra::{
+            fields::mnt6753::Fq,
+            PairingCurve, ProjectiveCurve, UniformRand,
+        };
+
+        use super::mnt6753::MNT6753PairingGadget as PairingGadget;
+        use crate::{
+            groups::curves::short_weierstrass::mnt::mnt6::mnt6753::{
+                MNT6G1Gadget as G1Gadget, MNT6G1PreparedGadget as G1PreparedGadget,
+                MNT6G2Gadget as G2Gadget, MNT6G2PreparedGadget as G2PreparedGadget,
+            },
+            prelude::*,
+        };
+        use algebra::curves::mnt6753::{MNT6, MNT6_753Parameters, G1Projective, G2Projective};
+        use algebra::curves::models::mnt6::MNT6Parameters;
+        use rand::SeedableRng;
+        use rand_xorshift::XorShiftRng;
+
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+        let mut cs = TestConstraintSystem::<Fq>::new();
+
+        let a = G1Projective::rand(&mut rng);
+        let b = G2Projective::rand(&mut rng);
+
+        let a_prep = a.into_affine().prepare();
+        let b_prep = b.into_affine().prepare();
+
+        let a_g = G1Gadget::alloc(&mut cs.ns(|| "a"), || Ok(a)).unwrap();
+        let b_g = G2Gadget::alloc(&mut cs.ns(|| "b"), || Ok(b)).unwrap();
+        let a_prep_g = G1PreparedGadget::from_affine(&mut cs.ns(|| "a_prep"), &a_g).unwrap();
+        let b_prep_g = G2PreparedGadget::from_affine(&mut cs.ns(|| "b_prep"), &b_g).unwrap();
+        let neg_q_y = b_prep_g.q.y.negate(cs.ns(|| "- q.y")).unwrap();
+
+        let mut idx: usize = 0;
+        for (j, &n) in <MNT6_753Parameters as MNT6Parameters>::WNAF.iter().rev().enumerate() {
+            let mut cs = cs.ns(|| format!("Iteration_{}", j));
+
+            //Doubling step
+            let (c, c_g) = (&b_prep.coeffs[idx], &b_prep_g.coeffs[idx]);
+            idx += 1;
+            let line = MNT6::line_evaluation(&a_prep, c, &c.r_y);
+            let line_g = PairingGadget::evaluate_line(cs.ns(|| "double"), &a_prep_g, c_g, &c_g.r_y).unwrap();
+            assert_eq!(line_g.get_value().unwrap(), line, "Doubling line evaluations differ at iteration {}", j);
+
+            //Addition step
+            if n != 0 {
+                let (c, c_g) = (&b_prep.coeffs[idx], &b_prep_g.coeffs[idx]);
+                idx += 1;
+                let q_y = if n > 0 { b_prep.q.y } else { -b_prep.q.y };
+                let q_y_g = if n > 0 { &b_prep_g.q.y } else { &neg_q_y };
+                let line = MNT6::line_evaluation(&a_prep, c, &q_y);
+                let line_g = PairingGadget::evaluate_line(cs.ns(|| "add"), &a_prep_g, c_g, q_y_g).unwrap();
+                assert_eq!(line_g.get_value().unwrap(), line, "Addition line evaluations differ at iteration {}", j);
+            }
+        }
+        assert_eq!(idx, b_prep.coeffs.len());
+
+        if !cs.is_satisfied() {
+            println!("Unsatisfied: {:?}", cs.which_is_unsatisfied());
+        }
+        assert!(cs.is_satisfied(), "cs is not satisfied");
+    }
+
+    #[test]
+    fn bls12_377_prepared_gadget_cache_test() {
+        use algebra::{fields::bls12_377::fq::Fq, PairingEngine, ProjectiveCurve, UniformRand};
+        use rand::SeedableRng;
+        use rand_xorshift::XorShiftRng;
+
+        use super::{bls12_377::PairingGadget, PreparedGadgetCache};
+        use crate::{
+            groups::bls12::bls12_377::{G1Gadget, G2Gadget},
+            pairing::PairingGadget as _,
+            prelude::*,
+        };
+        use algebra::curves::bls12_377::{Bls12_377, G1Projective, G2Projective};
+
+        let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+        let mut cs = TestConstraintSystem::<Fq>::new();
+
+        let a = G1Projective::rand(rng);
+        let b = G2Projective::rand(rng);
+        let a_g = G1Gadget::alloc(&mut cs.ns(|| "a"), || Ok(a)).unwrap();
+        let b_g = G2Gadget::alloc(&mut cs.ns(|| "b"), || Ok(b)).unwrap();
+        // Same witness as `b_g`, but different variables.
+        let b_g_other = G2Gadget::alloc(&mut cs.ns(|| "b other"), || Ok(b)).unwrap();
+
+        let mut cache = PreparedGadgetCache::<Bls12_377, Fq, PairingGadget>::new();
+
+        let a_prep = cache.prepare_g1(cs.ns(|| "prepare a"), &a_g).unwrap();
+        let num_constraints = cs.num_constraints();
+        let a_prep_cached = cache.prepare_g1(cs.ns(|| "prepare a again"), &a_g).unwrap();
+        assert_eq!(cs.num_constraints(), num_constraints);
+        assert_eq!(
+            a_prep.get_value().unwrap(),
+            a_prep_cached.get_value().unwrap()
+        );
+
+        let b_prep = cache.prepare_g2(cs.ns(|| "prepare b"), &b_g).unwrap();
+        let num_constraints = cs.num_constraints();
+        let b_prep_cached = cache.prepare_g2(cs.ns(|| "prepare b again"), &b_g).unwrap();
+        assert_eq!(cs.num_constraints(), num_constraints);
+        let b_prep_bytes = b_prep.to_bytes(cs.ns(|| "b_prep to bytes")).unwrap();
+        let b_prep_cached_bytes = b_prep_cached.to_bytes(cs.ns(|| "b_prep_cached to bytes")).unwrap();
+        assert_eq!(
+            b_prep_bytes.iter().map(|b| b.get_value()).collect::<Vec<_>>(),
+            b_prep_cached_bytes.iter().map(|b| b.get_value()).collect::<Vec<_>>()
+        );
+
+        // Equal values in distinct variables must be prepared again.
+        let num_constraints = cs.num_constraints();
+        let _ = cache.prepare_g2(cs.ns(|| "prepare other b"), &b_g_other).unwrap();
+        assert!(cs.num_constraints() > num_constraints);
+
+        // The cached result behaves like a freshly prepared one in a pairing.
+        let ans = PairingGadget::pairing(cs.ns(|| "pairing"), a_prep_cached, b_prep_cached).unwrap();
+        assert_eq!(ans.get_value().unwrap(), Bls12_377::pairing(a.into_affine(), b.into_affine()));
+        assert!(cs.is_satisfied());
+    }
+}
@@ -17,6 +17,31 @@ where
     fn cost() -> usize;
 }
 
+/// Selects between two slices of gadgets element-wise: if `cond` is `true`, returns
+/// `first`, else `second`. Fails with `Unsatisfiable` if the slices differ in length.
+/// Selecting slices of length `n` costs `n * T::cost()` constraints.
+pub fn conditionally_select_slice<ConstraintF, T, CS>(
+    mut cs: CS,
+    cond: &Boolean,
+    first: &[T],
+    second: &[T],
+) -> Result<Vec<T>, SynthesisError>
+where
+    ConstraintF: Field,
+    T: CondSelectGadget<ConstraintF>,
+    CS: ConstraintSystem<ConstraintF>,
+{
+    if first.len() != second.len() {
+        return Err(SynthesisError::Unsatisfiable);
+    }
+    first
+        .iter()
+        .zip(second.iter())
+        .enumerate()
+        .map(|(i, (a, b))| T::conditionally_select(cs.ns(|| format!("select_{}", i)), cond, a, b))
+        .collect()
+}
+
 /// Uses two bits to perform a lookup into a table
 pub trait TwoBitLookupGadget<ConstraintF: Field>
 where
@@ -93,6 +93,10 @@ impl<ConstraintF: Field> TestConstraintSystem<ConstraintF> {
         self.constraints.len()
     }
 
+    pub fn num_aux(&self) -> usize {
+        self.aux.len()
+    }
+
     pub fn set(&mut self, path: &str, to: ConstraintF) {
         match self.named_objects.get(path) {
             Some(&NamedObject::Var(ref v)) => match v.get_unchecked() {
@@ -228,3 +232,36 @@ impl<ConstraintF: Field> ConstraintSystem<ConstraintF> for TestConstraintSystem<
         self.constraints.len()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::TestConstraintSystem;
+    use algebra::fields::bls12_381::Fr;
+    use algebra::Field;
+    use r1cs_core::ConstraintSystem;
+
+    #[test]
+    fn which_is_unsatisfied_reports_namespace_path() {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        {
+            let mut cs = cs.ns(|| "Iteration 42");
+            let mut cs = cs.ns(|| "add");
+
+            let a = cs.alloc(|| "a", || Ok(Fr::one())).unwrap();
+            let b = cs.alloc(|| "b", || Ok(Fr::one())).unwrap();
+            let c = cs.alloc(|| "c", || Ok(Fr::one().double())).unwrap();
+
+            // 1 * 1 != 2
+            cs.enforce(|| "Check gamma", |lc| lc + a, |lc| lc + b, |lc| lc + c);
+        }
+
+        assert!(!cs.is_satisfied());
+        assert_eq!(cs.which_is_unsatisfied(), Some("Iteration 42/add/Check gamma"));
+
+        // Fixing the witness makes the constraint system satisfied again.
+        cs.set("Iteration 42/add/c", Fr::one());
+        assert!(cs.is_satisfied());
+        assert_eq!(cs.which_is_unsatisfied(), None);
+    }
+}
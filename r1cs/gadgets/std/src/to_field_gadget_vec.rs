@@ -1,10 +1,12 @@
 use algebra::{
     curves::models::SWModelParameters,
     curves::models::TEModelParameters,
-    PrimeField,
+    FpParameters, PrimeField,
 };
+use r1cs_core::{ConstraintVar, Index, LinearCombination, Variable};
 
 use crate::{
+    bits::{boolean::Boolean, uint8::UInt8},
     fields::FieldGadget,
     groups::curves::short_weierstrass::short_weierstrass_projective::AffineGadget as SWPAffineGadget,
     groups::curves::twisted_edwards::AffineGadget as TEAffineGadget,
@@ -47,6 +49,60 @@ impl<ConstraintF: PrimeField> ToConstraintFieldGadget<ConstraintF> for () {
     }
 }
 
+/// Packs `bits`, given in *little-endian* form, into the linear combination
+/// `sum_i 2^i * bits[i]`. No constraint is needed, hence no constraint system.
+fn pack_bits_le<ConstraintF: PrimeField>(bits: &[Boolean]) -> FpGadget<ConstraintF> {
+    // The same as `ConstraintSystem::one()`
+    let one = Variable::new_unchecked(Index::Input(0));
+
+    let mut lc = LinearCombination::<ConstraintF>::zero();
+    let mut value = Some(ConstraintF::zero());
+    let mut coeff = ConstraintF::one();
+    for bit in bits.iter() {
+        lc = &lc + &bit.lc(one, coeff);
+        value = match (value, bit.get_value()) {
+            (Some(v), Some(b)) => Some(if b { v + &coeff } else { v }),
+            _ => None,
+        };
+        coeff.double_in_place();
+    }
+    FpGadget { value, variable: ConstraintVar::LC(lc) }
+}
+
+/// Consistent with the native `ToConstraintField` for `[u8]`: chunks of `CAPACITY / 8`
+/// bytes are read as little-endian integers.
+impl<ConstraintF: PrimeField> ToConstraintFieldGadget<ConstraintF> for [UInt8] {
+    type FieldGadget = FpGadget<ConstraintF>;
+
+    #[inline]
+    fn to_field_gadget_elements(&self) -> Result<Vec<Self::FieldGadget>, Error> {
+        let max_size = (ConstraintF::Params::CAPACITY / 8) as usize;
+        let bits = self.iter().flat_map(|byte| byte.into_bits_le()).collect::<Vec<_>>();
+        Ok(bits.chunks(max_size * 8).map(pack_bits_le).collect())
+    }
+}
+
+/// Consistent with the native `ToConstraintField` for `[bool]`: chunks of `CAPACITY`
+/// bits are padded with zeros up to `CAPACITY` bits and read as big-endian integers.
+/// Each element packs at most `CAPACITY` bits, hence it can't wrap around the modulus.
+impl<ConstraintF: PrimeField> ToConstraintFieldGadget<ConstraintF> for [Boolean] {
+    type FieldGadget = FpGadget<ConstraintF>;
+
+    #[inline]
+    fn to_field_gadget_elements(&self) -> Result<Vec<Self::FieldGadget>, Error> {
+        let max_size = ConstraintF::Params::CAPACITY as usize;
+        Ok(self
+            .chunks(max_size)
+            .map(|chunk| {
+                let mut chunk = chunk.to_vec();
+                chunk.resize(max_size, Boolean::constant(false));
+                chunk.reverse();
+                pack_bits_le(&chunk)
+            })
+            .collect())
+    }
+}
+
 impl<M, ConstraintF, FG> ToConstraintFieldGadget<ConstraintF> for SWPAffineGadget<M, ConstraintF, FG>
     where
         M:              SWModelParameters,
@@ -81,4 +137,44 @@ impl<M, ConstraintF, FG> ToConstraintFieldGadget<ConstraintF> for TEAffineGadget
         x_fe.extend_from_slice(&y_fe);
         Ok(x_fe)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod test {
+    use super::ToConstraintFieldGadget;
+    use crate::{fields::fp::FpGadget, prelude::*, test_constraint_system::TestConstraintSystem};
+    use algebra::{fields::mnt4753::Fr, ToConstraintField};
+    use r1cs_core::ConstraintSystem;
+    use rand::{Rng, SeedableRng};
+    use rand_xorshift::XorShiftRng;
+
+    #[test]
+    fn bytes_and_bits_to_field_gadget_elements() {
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        // Not a multiple of the chunk sizes
+        let bytes = (0..250).map(|_| rng.gen()).collect::<Vec<u8>>();
+        let bytes_g = UInt8::alloc_vec(cs.ns(|| "alloc bytes"), &bytes).unwrap();
+        let expected: Vec<Fr> = bytes.as_slice().to_field_elements().unwrap();
+        let packed: Vec<FpGadget<Fr>> = bytes_g.as_slice().to_field_gadget_elements().unwrap();
+        assert_eq!(packed.len(), expected.len());
+        for (fe_g, fe) in packed.iter().zip(expected.iter()) {
+            assert_eq!(fe_g.get_value().unwrap(), *fe);
+        }
+
+        let bits = (0..1000).map(|_| rng.gen()).collect::<Vec<bool>>();
+        let bits_g = Vec::<Boolean>::alloc(cs.ns(|| "alloc bits"), || Ok(bits.clone())).unwrap();
+        let expected: Vec<Fr> = bits.as_slice().to_field_elements().unwrap();
+        let packed: Vec<FpGadget<Fr>> = bits_g.as_slice().to_field_gadget_elements().unwrap();
+        assert_eq!(packed.len(), expected.len());
+        for (i, (fe_g, fe)) in packed.iter().zip(expected.iter()).enumerate() {
+            assert_eq!(fe_g.get_value().unwrap(), *fe);
+
+            // The linear combinations are the ones of the values
+            let fe_alloc = FpGadget::alloc(cs.ns(|| format!("alloc fe {}", i)), || Ok(*fe)).unwrap();
+            fe_g.enforce_equal(cs.ns(|| format!("packing {}", i)), &fe_alloc).unwrap();
+        }
+        assert!(cs.is_satisfied());
+    }
+}